@@ -33,7 +33,7 @@ struct KeyData {
 // =============================================================================
 
 #[wasm_bindgen(inspectable)]
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 pub struct KeyCollection(pub(crate) KeyCollection_);
 
 #[wasm_bindgen]
@@ -23,7 +23,7 @@ struct JsonData {
 // =============================================================================
 
 #[wasm_bindgen(inspectable)]
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 pub struct KeyPair(pub(crate) KeyPair_);
 
 #[wasm_bindgen]
@@ -75,3 +75,10 @@ impl KeyPair {
     Self::from_base58(data.type_, &data.public, &data.secret)
   }
 }
+
+impl KeyPair {
+  // Returns a copy of the `KeyPair`, including a duplicate of the secret key.
+  pub(crate) fn duplicate(&self) -> Self {
+    Self((self.0.type_(), self.0.public().clone(), self.0.secret().duplicate()).into())
+  }
+}
@@ -10,6 +10,14 @@ use wasm_bindgen::prelude::*;
 pub enum KeyType {
   #[serde(rename = "ed25519")]
   Ed25519 = 1,
+  #[serde(rename = "secp256k1")]
+  Secp256k1 = 2,
+  #[serde(rename = "p256")]
+  P256 = 3,
+  #[serde(rename = "bls12381g2")]
+  Bls12381G2 = 4,
+  #[serde(rename = "x25519")]
+  X25519 = 5,
 }
 
 impl Default for KeyType {
@@ -22,6 +30,10 @@ impl From<KeyType> for KeyType_ {
   fn from(other: KeyType) -> Self {
     match other {
       KeyType::Ed25519 => KeyType_::Ed25519,
+      KeyType::Secp256k1 => KeyType_::Secp256k1,
+      KeyType::P256 => KeyType_::P256,
+      KeyType::Bls12381G2 => KeyType_::Bls12381G2,
+      KeyType::X25519 => KeyType_::X25519,
     }
   }
 }
@@ -30,6 +42,10 @@ impl From<KeyType_> for KeyType {
   fn from(other: KeyType_) -> Self {
     match other {
       KeyType_::Ed25519 => KeyType::Ed25519,
+      KeyType_::Secp256k1 => KeyType::Secp256k1,
+      KeyType_::P256 => KeyType::P256,
+      KeyType_::Bls12381G2 => KeyType::Bls12381G2,
+      KeyType_::X25519 => KeyType::X25519,
     }
   }
 }
@@ -41,7 +41,7 @@ pub struct NewDocument {
 impl NewDocument {
   #[wasm_bindgen(getter)]
   pub fn key(&self) -> KeyPair {
-    self.key.clone()
+    self.key.duplicate()
   }
 
   #[wasm_bindgen(getter)]
@@ -146,9 +146,15 @@ impl WasmDocument {
   }
 
   /// Returns the default Verification Method of the DID Document.
+  ///
+  /// Throws if the DID Document is deactivated.
   #[wasm_bindgen]
-  pub fn authentication(&self) -> WasmVerificationMethod {
-    WasmVerificationMethod(self.0.authentication().clone())
+  pub fn authentication(&self) -> Result<WasmVerificationMethod> {
+    self
+      .0
+      .authentication()
+      .map(|method| WasmVerificationMethod(method.clone()))
+      .wasm_result()
   }
 
   /// Get the message_id of the DID Document.
@@ -270,7 +276,12 @@ impl WasmDocument {
         let merkle_key: Vec<u8> = self
           .0
           .try_resolve(&*method)
-          .and_then(|method| method.key_data().try_decode().map_err(Error::InvalidDoc))
+          .and_then(|method| {
+            method
+              .key_data()
+              .try_decode_key(method.key_type())
+              .map_err(Error::InvalidDoc)
+          })
           .wasm_result()?;
 
         let public: PublicKey = decode_b58(&public).map(Into::into).wasm_result()?;
@@ -62,7 +62,7 @@ async fn main() -> Result<()> {
   // The resolved resource should be the DID Document authentication method.
   match output.content.unwrap() {
     Resource::Secondary(SecondaryResource::VerificationKey(method)) => {
-      assert_eq!(method, **document.authentication());
+      assert_eq!(method, **document.authentication()?);
     }
     resource => {
       panic!("Invalid Resource Dereference > {:#?}", resource);
@@ -30,6 +30,7 @@ use crate::events::Commit;
 use crate::events::Context;
 use crate::events::Event;
 use crate::events::EventData;
+use crate::events::UpdateError;
 use crate::identity::IdentityCreate;
 use crate::identity::IdentityId;
 use crate::identity::IdentityIndex;
@@ -129,7 +130,7 @@ impl Account {
     let command: Command = Command::CreateIdentity {
       network: input.network,
       method_secret: input.method_secret,
-      authentication: Self::key_to_method(input.key_type),
+      authentication: Self::key_to_method(input.key_type)?,
     };
 
     // Process the command
@@ -203,7 +204,7 @@ impl Account {
   pub async fn sign<K, U>(&self, key: K, fragment: &str, target: &mut U) -> Result<()>
   where
     K: IdentityKey,
-    U: Serialize + SetSignature,
+    U: Serialize + SetSignature + Send,
   {
     let identity: IdentityId = self.try_resolve_id(&key).await?;
     let snapshot: IdentitySnapshot = self.load_snapshot(identity).await?;
@@ -495,10 +496,18 @@ impl Account {
     Ok(())
   }
 
-  fn key_to_method(type_: KeyType) -> MethodType {
-    match type_ {
-      KeyType::Ed25519 => MethodType::Ed25519VerificationKey2018,
-    }
+  fn key_to_method(type_: KeyType) -> Result<MethodType> {
+    let method: MethodType = match type_ {
+      KeyType::Ed25519 => return Ok(MethodType::Ed25519VerificationKey2018),
+      // The account storages can neither generate nor sign with these keys.
+      KeyType::Secp256k1 => MethodType::EcdsaSecp256k1VerificationKey2019,
+      KeyType::P256 => MethodType::EcdsaSecp256r1VerificationKey2019,
+      KeyType::Bls12381G2 => MethodType::Bls12381G2Key2020,
+      // X25519 keys cannot sign and are never valid authentication methods.
+      KeyType::X25519 => MethodType::X25519KeyAgreementKey2019,
+    };
+
+    Err(Error::UpdateError(UpdateError::InvalidMethodType(method)))
   }
 }
 
@@ -1,9 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use core::marker::PhantomData;
-use futures::executor;
-use identity_core::crypto::Sign;
+use identity_core::crypto::ExternalSigner;
 use identity_core::error::Error;
 use identity_core::error::Result;
 
@@ -27,28 +25,22 @@ impl<'a, T> RemoteKey<'a, T> {
 }
 
 // =============================================================================
-// RemoteSign
+// ExternalSigner
 // =============================================================================
 
-/// A remote signature that delegates to a storage implementation.
+/// Delegates the signature operation to the storage implementation.
 ///
-/// Note: The signature implementation is specified by the associated `RemoteKey`.
-#[derive(Clone, Copy, Debug)]
-pub struct RemoteSign<'a, T> {
-  marker: PhantomData<RemoteKey<'a, T>>,
-}
-
-impl<'a, T> Sign for RemoteSign<'a, T>
+/// Note: The signature implementation is specified by the key location.
+#[async_trait::async_trait]
+impl<T> ExternalSigner for RemoteKey<'_, T>
 where
   T: Storage,
 {
-  type Secret = RemoteKey<'a, T>;
-  type Output = Vec<u8>;
-
-  fn sign(message: &[u8], key: &Self::Secret) -> Result<Self::Output> {
-    let future: _ = key.store.key_sign(key.id, key.location, message.to_vec());
-
-    executor::block_on(future)
+  async fn sign(&self, message: &[u8]) -> Result<Vec<u8>> {
+    self
+      .store
+      .key_sign(self.id, self.location, message.to_vec())
+      .await
       .map_err(|_| Error::InvalidProofValue("remote sign"))
       .map(|signature| signature.data)
   }
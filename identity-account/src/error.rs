@@ -88,6 +88,9 @@ pub enum Error {
   /// Caused by attempting to find a service that does not exist.
   #[error("Service not found")]
   ServiceNotFound,
+  /// Caused by attempting to use a verification method type that is not supported by the storage.
+  #[error("Verification Method type not supported: {}", .0.as_str())]
+  MethodTypeNotSupported(identity_did::verification::MethodType),
   /// Caused by attempting to perform an upate in an invalid context.
   #[error("Update Error: {0}")]
   UpdateError(#[from] crate::events::UpdateError),
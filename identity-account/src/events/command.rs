@@ -30,7 +30,14 @@ use crate::types::MethodSecret;
 // Supported authentication method types.
 const AUTH_TYPES: &[MethodType] = &[MethodType::Ed25519VerificationKey2018];
 
-#[derive(Clone, Debug)]
+// The method types of keys the account storages can generate or import.
+const METHOD_TYPES: &[MethodType] = &[
+  MethodType::Ed25519VerificationKey2018,
+  MethodType::MerkleKeyCollection2021,
+  MethodType::X25519KeyAgreementKey2019,
+];
+
+#[derive(Debug)]
 pub(crate) enum Command {
   CreateIdentity {
     network: Option<String>,
@@ -105,7 +112,7 @@ impl Command {
           store.key_new(state.id(), &location).await
         }?;
 
-        let data: MethodData = MethodData::new_b58(public.as_ref());
+        let data: MethodData = MethodData::new_multicodec(location.method(), public.as_ref());
         let method: TinyMethod = TinyMethod::new(location, data, None);
 
         // Generate a new DID URL from the public key
@@ -127,6 +134,15 @@ impl Command {
         // The state must be initialized
         ensure!(state.did().is_some(), UpdateError::DocumentNotFound);
 
+        // The method type must be supported by the account
+        ensure!(METHOD_TYPES.contains(&type_), UpdateError::InvalidMethodType(type_));
+
+        // X25519 keys can only be used for key agreement
+        ensure!(
+          type_ != MethodType::X25519KeyAgreementKey2019 || scope == MethodScope::KeyAgreement,
+          UpdateError::InvalidMethodScope(scope)
+        );
+
         let location: KeyLocation = state.key_location(type_, fragment)?;
 
         // The key location must not be an authentication location
@@ -154,7 +170,7 @@ impl Command {
           store.key_new(state.id(), &location).await
         }?;
 
-        let data: MethodData = MethodData::new_b58(public.as_ref());
+        let data: MethodData = MethodData::new_multicodec(location.method(), public.as_ref());
         let method: TinyMethod = TinyMethod::new(location, data, None);
 
         Ok(Some(vec![Event::new(EventData::MethodCreated(scope, method))]))
@@ -191,6 +207,13 @@ impl Command {
         // The verification method must exist
         ensure!(state.methods().contains(fragment.name()), UpdateError::MethodNotFound);
 
+        // X25519 keys can only be used for key agreement
+        if state.methods().fetch(fragment.name())?.location().method() == MethodType::X25519KeyAgreementKey2019 {
+          if let Some(scope) = scopes.iter().find(|scope| **scope != MethodScope::KeyAgreement) {
+            return Err(UpdateError::InvalidMethodScope(*scope).into());
+          }
+        }
+
         Ok(Some(vec![Event::new(EventData::MethodAttached(fragment, scopes))]))
       }
       Self::DetachMethod { fragment, scopes } => {
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_core::common::Fragment;
+use identity_did::verification::MethodScope;
 use identity_did::verification::MethodType;
 
 use crate::types::KeyLocation;
@@ -19,6 +20,8 @@ pub enum UpdateError {
   ServiceNotFound,
   #[error("invalid method type - {}", .0.as_str())]
   InvalidMethodType(MethodType),
+  #[error("invalid method scope - {}", .0.as_str())]
+  InvalidMethodScope(MethodScope),
   #[error("invalid method fragment - {0}")]
   InvalidMethodFragment(&'static str),
   #[error("invalid method secret: {0}")]
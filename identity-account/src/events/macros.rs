@@ -33,7 +33,7 @@ macro_rules! impl_command_builder {
   ($(#[$doc:meta])* $ident:ident { $(@ $requirement:ident $field:ident $ty:ty $(= $value:expr)?),* $(,)* }) => {
     paste::paste! {
       $(#[$doc])*
-      #[derive(Clone, Debug)]
+      #[derive(Debug)]
       pub struct [<$ident Builder>]<'account, 'key, K: $crate::identity::IdentityKey> {
         account: &'account Account,
         key: &'key K,
@@ -6,7 +6,7 @@ use identity_core::crypto::KeyType;
 use crate::types::MethodSecret;
 
 /// Configuration used to create a new Identity.
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 pub struct IdentityCreate {
   pub(crate) key_type: KeyType,
   pub(crate) name: Option<String>,
@@ -7,9 +7,11 @@ use identity_core::common::Fragment;
 use identity_core::common::Object;
 use identity_core::common::UnixTimestamp;
 use identity_core::common::Url;
+use identity_core::crypto::Ed25519;
+use identity_core::crypto::ExternalSign;
 use identity_core::crypto::JcsEd25519;
 use identity_core::crypto::SetSignature;
-use identity_core::crypto::Signer;
+use identity_core::crypto::SignatureOptions;
 use identity_did::document::CoreDocument;
 use identity_did::document::DocumentBuilder;
 use identity_did::service::Service as CoreService;
@@ -28,10 +30,10 @@ use identity_iota::tangle::TangleRef;
 use serde::Serialize;
 
 use crate::crypto::RemoteKey;
-use crate::crypto::RemoteSign;
 use crate::error::Error;
 use crate::error::Result;
 use crate::identity::IdentityId;
+use crate::storage::unsupported_method;
 use crate::storage::Storage;
 use crate::types::Generation;
 use crate::types::KeyLocation;
@@ -39,8 +41,6 @@ use crate::types::KeyLocation;
 type Properties = VerifiableProperties<BaseProperties>;
 type BaseDocument = CoreDocument<Properties, Object, Object>;
 
-pub type RemoteEd25519<'a, T> = JcsEd25519<RemoteSign<'a, T>>;
-
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct IdentityState {
   // =========== //
@@ -325,7 +325,7 @@ impl IdentityState {
   pub async fn sign_data<T, U>(&self, store: &T, location: &KeyLocation, target: &mut U) -> Result<()>
   where
     T: Storage,
-    U: Serialize + SetSignature,
+    U: Serialize + SetSignature + Send,
   {
     // Create a secret key suitable for identity_core::crypto
     let secret: RemoteKey<'_, T> = RemoteKey::new(self.id, location, store);
@@ -336,11 +336,13 @@ impl IdentityState {
 
     match location.method() {
       MethodType::Ed25519VerificationKey2018 => {
-        RemoteEd25519::create_signature(target, method.as_str(), &secret)?;
+        JcsEd25519::<Ed25519>::create_signature_external(target, method.as_str(), &secret, SignatureOptions::new())
+          .await?;
       }
       MethodType::MerkleKeyCollection2021 => {
         todo!("Handle MerkleKeyCollection2021")
       }
+      _ => return unsupported_method(location),
     }
 
     Ok(())
@@ -27,6 +27,7 @@ use crate::events::Commit;
 use crate::identity::IdentityId;
 use crate::identity::IdentityIndex;
 use crate::identity::IdentitySnapshot;
+use crate::storage::unsupported_method;
 use crate::storage::Storage;
 use crate::types::Generation;
 use crate::types::KeyLocation;
@@ -35,10 +36,12 @@ use crate::utils::EncryptionKey;
 use crate::utils::Shared;
 
 type MemVault = HashMap<KeyLocation, KeyPair>;
+type PublicVault = HashMap<KeyLocation, PublicKey>;
 
 type Events = HashMap<IdentityId, Vec<Commit>>;
 type States = HashMap<IdentityId, IdentitySnapshot>;
 type Vaults = HashMap<IdentityId, MemVault>;
+type PublicVaults = HashMap<IdentityId, PublicVault>;
 type PublishedGenerations = HashMap<IdentityId, Generation>;
 
 pub struct MemStore {
@@ -82,8 +85,21 @@ impl MemStore {
     self.states.read().map(|data| data.clone())
   }
 
-  pub fn vaults(&self) -> Result<Vaults> {
-    self.vaults.read().map(|data| data.clone())
+  /// Returns the public keys of every vault - secret keys are never exported.
+  pub fn vaults(&self) -> Result<PublicVaults> {
+    self.vaults.read().map(|data| {
+      data
+        .iter()
+        .map(|(id, vault)| {
+          let keys: PublicVault = vault
+            .iter()
+            .map(|(location, keypair)| (location.clone(), keypair.public().clone()))
+            .collect();
+
+          (*id, keys)
+        })
+        .collect()
+    })
   }
 }
 
@@ -113,6 +129,15 @@ impl Storage for MemStore {
       MethodType::MerkleKeyCollection2021 => {
         todo!("[MemStore::key_new] Handle MerkleKeyCollection2021")
       }
+      MethodType::X25519KeyAgreementKey2019 => {
+        let keypair: KeyPair = KeyPair::new(KeyType::X25519)?;
+        let public: PublicKey = keypair.public().clone();
+
+        vault.insert(location.clone(), keypair);
+
+        Ok(public)
+      }
+      _ => unsupported_method(location),
     }
   }
 
@@ -141,6 +166,7 @@ impl Storage for MemStore {
       MethodType::MerkleKeyCollection2021 => {
         todo!("[MemStore::key_insert] Handle MerkleKeyCollection2021")
       }
+      _ => unsupported_method(location),
     }
   }
 
@@ -189,6 +215,7 @@ impl Storage for MemStore {
       MethodType::MerkleKeyCollection2021 => {
         todo!("[MemStore::key_sign] Handle MerkleKeyCollection2021")
       }
+      _ => unsupported_method(location),
     }
   }
 
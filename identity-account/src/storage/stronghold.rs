@@ -29,6 +29,7 @@ use crate::events::EventData;
 use crate::identity::IdentityId;
 use crate::identity::IdentityIndex;
 use crate::identity::IdentitySnapshot;
+use crate::storage::unsupported_method;
 use crate::storage::Storage;
 use crate::stronghold::default_hint;
 use crate::stronghold::Snapshot;
@@ -96,6 +97,7 @@ impl Storage for Stronghold {
     let public: PublicKey = match location.method() {
       MethodType::Ed25519VerificationKey2018 => generate_ed25519(&vault, location).await?,
       MethodType::MerkleKeyCollection2021 => todo!("[Stronghold::key_new] Handle MerkleKeyCollection2021"),
+      _ => return unsupported_method(location),
     };
 
     Ok(public)
@@ -111,6 +113,7 @@ impl Storage for Stronghold {
     match location.method() {
       MethodType::Ed25519VerificationKey2018 => retrieve_ed25519(&vault, location).await,
       MethodType::MerkleKeyCollection2021 => todo!("[Stronghold::key_insert] Handle MerkleKeyCollection2021"),
+      _ => unsupported_method(location),
     }
   }
 
@@ -120,6 +123,7 @@ impl Storage for Stronghold {
     match location.method() {
       MethodType::Ed25519VerificationKey2018 => retrieve_ed25519(&vault, location).await,
       MethodType::MerkleKeyCollection2021 => todo!("[Stronghold::key_get] Handle MerkleKeyCollection2021"),
+      _ => unsupported_method(location),
     }
   }
 
@@ -134,6 +138,7 @@ impl Storage for Stronghold {
         // TODO: Garbage Collection (?)
       }
       MethodType::MerkleKeyCollection2021 => todo!("[Stronghold::key_del] Handle MerkleKeyCollection2021"),
+      _ => return unsupported_method(location),
     }
 
     Ok(())
@@ -145,6 +150,7 @@ impl Storage for Stronghold {
     match location.method() {
       MethodType::Ed25519VerificationKey2018 => sign_ed25519(&vault, data, location).await,
       MethodType::MerkleKeyCollection2021 => todo!("[Stronghold::key_sign] Handle MerkleKeyCollection2021"),
+      _ => unsupported_method(location),
     }
   }
 
@@ -154,6 +160,7 @@ impl Storage for Stronghold {
     match location.method() {
       MethodType::Ed25519VerificationKey2018 => vault.exists(location_skey(location)).await,
       MethodType::MerkleKeyCollection2021 => todo!("[Stronghold::key_exists] Handle MerkleKeyCollection2021"),
+      _ => unsupported_method(location),
     }
   }
 
@@ -327,6 +334,8 @@ impl Storage for Stronghold {
         MethodType::MerkleKeyCollection2021 => {
           todo!("[Stronghold::purge] Handle MerkleKeyCollection2021")
         }
+        // Keys of unsupported method types are never created.
+        _ => {}
       }
     }
 
@@ -7,6 +7,7 @@ use futures::TryStreamExt;
 use identity_core::crypto::PublicKey;
 use identity_core::crypto::SecretKey;
 
+use crate::error::Error;
 use crate::error::Result;
 use crate::events::Commit;
 use crate::identity::IdentityId;
@@ -153,3 +154,9 @@ impl Storage for Box<dyn Storage> {
     (**self).set_published_generation(id, index).await
   }
 }
+
+/// Returns the error for key operations on a method type that is not supported
+/// by the storage.
+pub(crate) fn unsupported_method<T>(location: &KeyLocation) -> Result<T> {
+  Err(Error::MethodTypeNotSupported(location.method()))
+}
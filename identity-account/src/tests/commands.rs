@@ -137,20 +137,20 @@ async fn test_create_identity_already_exists() -> Result<()> {
   // initial snapshot version = 0
   assert_eq!(snapshot.sequence(), Generation::new());
 
-  let command: Command = Command::CreateIdentity {
+  let command = || Command::CreateIdentity {
     network: None,
     method_secret: None,
     authentication: MethodType::Ed25519VerificationKey2018,
   };
 
-  account.process(identity, command.clone(), false).await?;
+  account.process(identity, command(), false).await?;
 
   let snapshot: IdentitySnapshot = account.load_snapshot(identity).await?;
 
   // version is now 3
   assert_eq!(snapshot.sequence(), Generation::from(3));
 
-  let output: Result<()> = account.process(identity, command, false).await;
+  let output: Result<()> = account.process(identity, command(), false).await;
 
   assert!(matches!(
     output.unwrap_err(),
@@ -172,14 +172,16 @@ async fn test_create_identity_from_secret_key() -> Result<()> {
 
   let identity: IdentityId = IdentityId::from_u32(1);
 
-  let secret_key = KeyPair::new_ed25519()?.secret().clone();
+  let keypair: KeyPair = KeyPair::new_ed25519()?;
 
-  let id_create = IdentityCreate::new()
-    .key_type(KeyType::Ed25519)
-    .method_secret(MethodSecret::Ed25519(secret_key));
+  let id_create = || {
+    IdentityCreate::new()
+      .key_type(KeyType::Ed25519)
+      .method_secret(MethodSecret::Ed25519(keypair.secret().duplicate()))
+  };
 
-  account.create_identity(id_create.clone()).await?;
-  account2.create_identity(id_create).await?;
+  account.create_identity(id_create()).await?;
+  account2.create_identity(id_create()).await?;
 
   let ident = account.find_identity(identity).await.unwrap().unwrap();
   let ident2 = account.find_identity(identity).await.unwrap().unwrap();
@@ -301,7 +303,7 @@ async fn test_create_method_duplicate_fragment() -> Result<()> {
 
   account.process(identity, command, false).await?;
 
-  let command: Command = Command::CreateMethod {
+  let command = || Command::CreateMethod {
     scope: MethodScope::default(),
     method_secret: None,
     type_: MethodType::Ed25519VerificationKey2018,
@@ -311,12 +313,12 @@ async fn test_create_method_duplicate_fragment() -> Result<()> {
   let snapshot: IdentitySnapshot = account.load_snapshot(identity).await?;
   assert_eq!(snapshot.sequence(), Generation::from_u32(3));
 
-  account.process(identity, command.clone(), false).await?;
+  account.process(identity, command(), false).await?;
 
   let snapshot: IdentitySnapshot = account.load_snapshot(identity).await?;
   assert_eq!(snapshot.sequence(), Generation::from_u32(5));
 
-  let output: _ = account.process(identity, command, false).await;
+  let output: _ = account.process(identity, command(), false).await;
 
   assert!(matches!(
     output.unwrap_err(),
@@ -346,7 +348,7 @@ async fn test_create_method_from_secret_key() -> Result<()> {
 
   let command: Command = Command::CreateMethod {
     scope: MethodScope::default(),
-    method_secret: Some(MethodSecret::Ed25519(keypair.secret().clone())),
+    method_secret: Some(MethodSecret::Ed25519(keypair.secret().duplicate())),
     type_: MethodType::Ed25519VerificationKey2018,
     fragment: "key-1".to_owned(),
   };
@@ -437,6 +439,131 @@ async fn test_create_method_with_type_secret_mismatch() -> Result<()> {
   Ok(())
 }
 
+#[tokio::test]
+async fn test_create_method_unsupported_type() -> Result<()> {
+  const TYPES: &[MethodType] = &[
+    MethodType::EcdsaSecp256k1VerificationKey2019,
+    MethodType::EcdsaSecp256r1VerificationKey2019,
+    MethodType::Bls12381G2Key2020,
+    MethodType::JsonWebKey2020,
+  ];
+
+  let account: Account = new_account().await?;
+  let identity: IdentityId = IdentityId::from_u32(1);
+
+  let command: Command = Command::CreateIdentity {
+    network: None,
+    method_secret: None,
+    authentication: MethodType::Ed25519VerificationKey2018,
+  };
+
+  account.process(identity, command, false).await?;
+
+  for type_ in TYPES.iter().copied() {
+    let command: Command = Command::CreateMethod {
+      scope: MethodScope::default(),
+      method_secret: None,
+      type_,
+      fragment: "key-1".to_owned(),
+    };
+
+    let output: Result<()> = account.process(identity, command, false).await;
+
+    assert!(matches!(
+      output.unwrap_err(),
+      Error::UpdateError(UpdateError::InvalidMethodType(_))
+    ));
+
+    let snapshot: IdentitySnapshot = account.load_snapshot(identity).await?;
+
+    // version is still 3, no new events have been committed
+    assert_eq!(snapshot.sequence(), Generation::from_u32(3));
+  }
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn test_create_method_key_agreement() -> Result<()> {
+  let account: Account = new_account().await?;
+  let identity: IdentityId = IdentityId::from_u32(1);
+
+  let command: Command = Command::CreateIdentity {
+    network: None,
+    method_secret: None,
+    authentication: MethodType::Ed25519VerificationKey2018,
+  };
+
+  account.process(identity, command, false).await?;
+
+  // X25519 keys cannot be used outside of key agreement
+  let command: Command = Command::CreateMethod {
+    scope: MethodScope::default(),
+    method_secret: None,
+    type_: MethodType::X25519KeyAgreementKey2019,
+    fragment: "key-1".to_owned(),
+  };
+
+  let output: Result<()> = account.process(identity, command, false).await;
+
+  assert!(matches!(
+    output.unwrap_err(),
+    Error::UpdateError(UpdateError::InvalidMethodScope(_))
+  ));
+
+  let command: Command = Command::CreateMethod {
+    scope: MethodScope::KeyAgreement,
+    method_secret: None,
+    type_: MethodType::X25519KeyAgreementKey2019,
+    fragment: "key-1".to_owned(),
+  };
+
+  account.process(identity, command, false).await?;
+
+  let snapshot: IdentitySnapshot = account.load_snapshot(identity).await?;
+  let method: &TinyMethod = snapshot.identity().methods().fetch("key-1")?;
+
+  assert_eq!(snapshot.sequence(), Generation::from_u32(5));
+  assert_eq!(method.location().method(), MethodType::X25519KeyAgreementKey2019);
+
+  let command: Command = Command::AttachMethod {
+    fragment: "key-1".to_owned(),
+    scopes: vec![MethodScope::Authentication],
+  };
+
+  let output: Result<()> = account.process(identity, command, false).await;
+
+  assert!(matches!(
+    output.unwrap_err(),
+    Error::UpdateError(UpdateError::InvalidMethodScope(MethodScope::Authentication))
+  ));
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn test_create_identity_unsupported_key_type() -> Result<()> {
+  const TYPES: &[(KeyType, MethodType)] = &[
+    (KeyType::Secp256k1, MethodType::EcdsaSecp256k1VerificationKey2019),
+    (KeyType::P256, MethodType::EcdsaSecp256r1VerificationKey2019),
+    (KeyType::Bls12381G2, MethodType::Bls12381G2Key2020),
+    (KeyType::X25519, MethodType::X25519KeyAgreementKey2019),
+  ];
+
+  let account: Account = new_account().await?;
+
+  for (key_type, method_type) in TYPES.iter().copied() {
+    let output: Result<IdentitySnapshot> = account.create_identity(IdentityCreate::new().key_type(key_type)).await;
+
+    assert!(matches!(
+      output.unwrap_err(),
+      Error::UpdateError(UpdateError::InvalidMethodType(type_)) if type_ == method_type
+    ));
+  }
+
+  Ok(())
+}
+
 #[tokio::test]
 async fn test_delete_method() -> Result<()> {
   let account: Account = new_account().await?;
@@ -3,7 +3,7 @@
 
 use identity_core::crypto::{KeyCollection, SecretKey};
 
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 pub enum MethodSecret {
   Ed25519(SecretKey),
   MerkleKeyCollection(KeyCollection),
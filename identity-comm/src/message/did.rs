@@ -0,0 +1,132 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::TryFrom;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::str::FromStr;
+use identity_did::did::DID;
+use identity_did::methods::PeerMethod;
+use identity_iota::did::IotaDID;
+
+use crate::error::Error;
+use crate::error::Result;
+
+/// The DID of a party in a DIDComm message.
+///
+/// Either a published [`IotaDID`], or a `did:peer` DID for pairwise
+/// relationships that are not recorded on the Tangle.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(into = "DID", try_from = "DID")]
+pub enum MessageDID {
+  /// A DID adhering to the IOTA DID method specification.
+  Iota(IotaDID),
+  /// A numalgo 0 or numalgo 2 `did:peer` DID.
+  Peer(DID),
+}
+
+impl MessageDID {
+  /// Returns the `MessageDID` as a string slice.
+  pub fn as_str(&self) -> &str {
+    self.as_did().as_str()
+  }
+
+  /// Returns a reference to the underlying [`DID`].
+  pub fn as_did(&self) -> &DID {
+    match self {
+      Self::Iota(did) => did.as_ref(),
+      Self::Peer(did) => did,
+    }
+  }
+
+  /// Returns the [`IotaDID`], if this is a published DID.
+  pub fn as_iota(&self) -> Option<&IotaDID> {
+    match self {
+      Self::Iota(did) => Some(did),
+      Self::Peer(_) => None,
+    }
+  }
+
+  /// Returns `true` if this is a `did:peer` DID.
+  pub fn is_peer(&self) -> bool {
+    matches!(self, Self::Peer(_))
+  }
+}
+
+impl Display for MessageDID {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    Display::fmt(self.as_did(), f)
+  }
+}
+
+impl From<IotaDID> for MessageDID {
+  fn from(other: IotaDID) -> Self {
+    Self::Iota(other)
+  }
+}
+
+impl From<MessageDID> for DID {
+  fn from(other: MessageDID) -> Self {
+    match other {
+      MessageDID::Iota(did) => did.into(),
+      MessageDID::Peer(did) => did,
+    }
+  }
+}
+
+impl TryFrom<DID> for MessageDID {
+  type Error = Error;
+
+  fn try_from(other: DID) -> Result<Self, Self::Error> {
+    if other.method() == PeerMethod::NAME {
+      PeerMethod::expand(&other)?;
+
+      Ok(Self::Peer(other))
+    } else {
+      IotaDID::try_from_owned(other).map(Self::Iota).map_err(Into::into)
+    }
+  }
+}
+
+impl FromStr for MessageDID {
+  type Err = Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    Self::try_from(string.parse::<DID>().map_err(identity_did::Error::from)?)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+  use identity_core::crypto::KeyPair;
+  use identity_did::methods::PeerMethod;
+  use identity_iota::did::IotaDID;
+
+  use crate::message::MessageDID;
+  use crate::message::TrustPing;
+
+  #[test]
+  fn test_message_did() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let iota: IotaDID = IotaDID::new(keypair.public().as_ref()).unwrap();
+    let peer: MessageDID = MessageDID::Peer(PeerMethod::from_keypair(&keypair).unwrap());
+
+    for did in [MessageDID::from(iota), peer] {
+      let mut message: TrustPing = TrustPing::new("trust-ping/1.0/ping".to_string());
+
+      message.set_id(did.clone());
+
+      let message: TrustPing = TrustPing::from_json(&message.to_json().unwrap()).unwrap();
+
+      assert_eq!(message.id(), &Some(did));
+    }
+
+    assert!("did:example:1234".parse::<MessageDID>().is_err());
+    assert!("did:peer:2.Xz6MkqRYqQiSgvZQdnBytw86Qbs2ZWUkGv22od935YF4s8M7V"
+      .parse::<MessageDID>()
+      .is_err());
+  }
+}
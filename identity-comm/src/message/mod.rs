@@ -4,11 +4,13 @@
 #[macro_use]
 mod macros;
 
+mod did;
 mod report;
 mod timing;
 mod traits;
 mod types;
 
+pub use self::did::*;
 pub use self::report::*;
 pub use self::timing::*;
 pub use self::traits::*;
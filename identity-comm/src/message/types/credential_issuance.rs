@@ -2,9 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_core::common::Url;
-use identity_iota::did::IotaDID;
 use uuid::Uuid;
 
+use crate::message::MessageDID;
 use crate::message::Timing;
 
 /// A DIDComm `credential-issuance` Request.
@@ -21,7 +21,7 @@ pub struct CredentialSelection {
   #[serde(rename = "responseRequested", skip_serializing_if = "Option::is_none")]
   response_requested: Option<bool>,
   #[serde(skip_serializing_if = "Option::is_none")]
-  id: Option<IotaDID>,
+  id: Option<MessageDID>,
   #[serde(skip_serializing_if = "Option::is_none")]
   timing: Option<Timing>,
 }
@@ -45,7 +45,7 @@ impl CredentialSelection {
   impl_message_accessor!(callback_url => Url);
   impl_message_accessor!(credential_types => Vec<String>);
   impl_message_accessor!(response_requested => Option<bool>);
-  impl_message_accessor!(id => Option<IotaDID>);
+  impl_message_accessor!(id => Option<MessageDID>);
   impl_message_accessor!(timing => Option<Timing>);
 }
 
@@ -62,7 +62,7 @@ pub struct CredentialIssuance {
   #[serde(rename = "responseRequested", skip_serializing_if = "Option::is_none")]
   response_requested: Option<bool>,
   #[serde(skip_serializing_if = "Option::is_none")]
-  id: Option<IotaDID>,
+  id: Option<MessageDID>,
   #[serde(skip_serializing_if = "Option::is_none")]
   timing: Option<Timing>,
 }
@@ -86,6 +86,6 @@ impl CredentialIssuance {
   impl_message_accessor!(credentials => Vec<String>);
   impl_message_accessor!(callback_url => Option<Url>);
   impl_message_accessor!(response_requested => Option<bool>);
-  impl_message_accessor!(id => Option<IotaDID>);
+  impl_message_accessor!(id => Option<MessageDID>);
   impl_message_accessor!(timing => Option<Timing>);
 }
@@ -2,9 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_core::common::Url;
-use identity_iota::did::IotaDID;
 use uuid::Uuid;
 
+use crate::message::MessageDID;
 use crate::message::Timing;
 
 /// A DIDComm `credential-options` Request.
@@ -19,7 +19,7 @@ pub struct CredentialOptionRequest {
   #[serde(rename = "responseRequested", skip_serializing_if = "Option::is_none")]
   response_requested: Option<bool>,
   #[serde(skip_serializing_if = "Option::is_none")]
-  id: Option<IotaDID>,
+  id: Option<MessageDID>,
   #[serde(skip_serializing_if = "Option::is_none")]
   timing: Option<Timing>,
 }
@@ -41,7 +41,7 @@ impl CredentialOptionRequest {
   impl_message_accessor!(thread => Uuid);
   impl_message_accessor!(callback_url => Url);
   impl_message_accessor!(response_requested => Option<bool>);
-  impl_message_accessor!(id => Option<IotaDID>);
+  impl_message_accessor!(id => Option<MessageDID>);
   impl_message_accessor!(timing => Option<Timing>);
 }
 
@@ -59,7 +59,7 @@ pub struct CredentialOptionResponse {
   #[serde(rename = "responseRequested", skip_serializing_if = "Option::is_none")]
   response_requested: Option<bool>,
   #[serde(skip_serializing_if = "Option::is_none")]
-  id: Option<IotaDID>,
+  id: Option<MessageDID>,
   #[serde(skip_serializing_if = "Option::is_none")]
   timing: Option<Timing>,
 }
@@ -83,6 +83,6 @@ impl CredentialOptionResponse {
   impl_message_accessor!(credential_types => Vec<String>);
   impl_message_accessor!(callback_url => Option<Url>);
   impl_message_accessor!(response_requested => Option<bool>);
-  impl_message_accessor!(id => Option<IotaDID>);
+  impl_message_accessor!(id => Option<MessageDID>);
   impl_message_accessor!(timing => Option<Timing>);
 }
@@ -2,9 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_core::common::Url;
-use identity_iota::did::IotaDID;
 use uuid::Uuid;
 
+use crate::message::MessageDID;
 use crate::message::Timing;
 
 /// A DIDComm `did-introduction` Request.
@@ -19,7 +19,7 @@ pub struct IntroductionProposal {
   #[serde(rename = "responseRequested", skip_serializing_if = "Option::is_none")]
   response_requested: Option<bool>,
   #[serde(skip_serializing_if = "Option::is_none")]
-  id: Option<IotaDID>,
+  id: Option<MessageDID>,
   #[serde(skip_serializing_if = "Option::is_none")]
   comment: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -44,7 +44,7 @@ impl IntroductionProposal {
   impl_message_accessor!(thread => Uuid);
   impl_message_accessor!(callback_url => Url);
   impl_message_accessor!(response_requested => Option<bool>);
-  impl_message_accessor!(id => Option<IotaDID>);
+  impl_message_accessor!(id => Option<MessageDID>);
   impl_message_accessor!(comment => Option<String>);
   impl_message_accessor!(timing => Option<Timing>);
 }
@@ -62,7 +62,7 @@ pub struct IntroductionResponse {
   #[serde(rename = "responseRequested", skip_serializing_if = "Option::is_none")]
   response_requested: Option<bool>,
   #[serde(skip_serializing_if = "Option::is_none")]
-  id: Option<IotaDID>,
+  id: Option<MessageDID>,
   #[serde(skip_serializing_if = "Option::is_none")]
   comment: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -89,7 +89,7 @@ impl IntroductionResponse {
   impl_message_accessor!(consent => bool);
   impl_message_accessor!(callback_url => Option<Url>);
   impl_message_accessor!(response_requested => Option<bool>);
-  impl_message_accessor!(id => Option<IotaDID>);
+  impl_message_accessor!(id => Option<MessageDID>);
   impl_message_accessor!(comment => Option<String>);
   impl_message_accessor!(timing => Option<Timing>);
 }
@@ -101,13 +101,13 @@ impl IntroductionResponse {
 pub struct Introduction {
   context: String,
   thread: Uuid,
-  ids: Vec<IotaDID>,
+  ids: Vec<MessageDID>,
   #[serde(rename = "callbackURL", skip_serializing_if = "Option::is_none")]
   callback_url: Option<Url>,
   #[serde(rename = "responseRequested", skip_serializing_if = "Option::is_none")]
   response_requested: Option<bool>,
   #[serde(skip_serializing_if = "Option::is_none")]
-  id: Option<IotaDID>,
+  id: Option<MessageDID>,
   #[serde(skip_serializing_if = "Option::is_none")]
   comment: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -116,7 +116,7 @@ pub struct Introduction {
 
 impl Introduction {
   /// Creates a new `Introduction`.
-  pub fn new(context: String, thread: Uuid, ids: Vec<IotaDID>) -> Self {
+  pub fn new(context: String, thread: Uuid, ids: Vec<MessageDID>) -> Self {
     Self {
       context,
       thread,
@@ -131,10 +131,10 @@ impl Introduction {
 
   impl_message_accessor!(context => String);
   impl_message_accessor!(thread => Uuid);
-  impl_message_accessor!(ids => Vec<IotaDID>);
+  impl_message_accessor!(ids => Vec<MessageDID>);
   impl_message_accessor!(callback_url => Option<Url>);
   impl_message_accessor!(response_requested => Option<bool>);
-  impl_message_accessor!(id => Option<IotaDID>);
+  impl_message_accessor!(id => Option<MessageDID>);
   impl_message_accessor!(comment => Option<String>);
   impl_message_accessor!(timing => Option<Timing>);
 }
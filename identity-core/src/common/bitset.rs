@@ -1,8 +1,15 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use core::convert::TryFrom;
 use core::fmt::Formatter;
 use core::fmt::Result as FmtResult;
+#[cfg(feature = "status-list")]
+use flate2::read::GzDecoder;
+#[cfg(feature = "status-list")]
+use flate2::write::GzEncoder;
+#[cfg(feature = "status-list")]
+use flate2::Compression;
 use roaring::RoaringBitmap;
 use serde::de;
 use serde::de::Deserializer;
@@ -11,6 +18,10 @@ use serde::ser::Error as _;
 use serde::ser::Serializer;
 use serde::Deserialize;
 use serde::Serialize;
+#[cfg(feature = "status-list")]
+use std::io::Read;
+#[cfg(feature = "status-list")]
+use std::io::Write;
 
 use crate::error::Error;
 use crate::error::Result;
@@ -22,6 +33,13 @@ use crate::utils::encode_b64;
 pub struct BitSet(RoaringBitmap);
 
 impl BitSet {
+  /// The minimum length, in bits, of a status list bitstring (16KB).
+  pub const STATUS_LIST_MIN_LENGTH: usize = 16 * 1024 * 8;
+
+  /// The maximum length, in bytes, of a bitstring addressable by `u32` indices.
+  #[cfg(feature = "status-list")]
+  const BITSTRING_MAX_SIZE: u64 = (u32::MAX as u64 + 1) / 8;
+
   /// Creates a new [`BitSet`].
   pub fn new() -> Self {
     Self(RoaringBitmap::new())
@@ -92,6 +110,108 @@ impl BitSet {
       .map_err(Error::DecodeBitmap)
       .map(Self)
   }
+
+  /// Serializes the [`BitSet`] as a [Status List 2021](https://w3c-ccg.github.io/vc-status-list-2021/)
+  /// `encodedList`: a GZIP-compressed, base64url-encoded bitstring.
+  #[cfg(feature = "status-list")]
+  pub fn serialize_status_list(&self) -> Result<String> {
+    let mut encoder: GzEncoder<Vec<u8>> = GzEncoder::new(Vec::new(), Compression::default());
+
+    encoder
+      .write_all(&self.to_bitstring())
+      .map_err(Error::EncodeStatusList)?;
+
+    encoder
+      .finish()
+      .map_err(Error::EncodeStatusList)
+      .map(|data| base64::encode_config(data, base64::URL_SAFE_NO_PAD))
+  }
+
+  /// Deserializes a [`BitSet`] from a [Status List 2021](https://w3c-ccg.github.io/vc-status-list-2021/)
+  /// `encodedList`.
+  ///
+  /// Fails if the decompressed bitstring is shorter than
+  /// [`STATUS_LIST_MIN_LENGTH`][Self::STATUS_LIST_MIN_LENGTH] bits.
+  #[cfg(feature = "status-list")]
+  pub fn deserialize_status_list(data: &str) -> Result<Self> {
+    let data: Vec<u8> = base64::decode_config(data.trim_end_matches('='), base64::URL_SAFE_NO_PAD)?;
+    let mut output: Vec<u8> = Vec::new();
+
+    GzDecoder::new(data.as_slice())
+      .take(Self::BITSTRING_MAX_SIZE + 1)
+      .read_to_end(&mut output)
+      .map_err(|_| Error::DecodeStatusList("invalid gzip data"))?;
+
+    if output.len() as u64 > Self::BITSTRING_MAX_SIZE {
+      return Err(Error::DecodeStatusList("bitstring too long"));
+    }
+
+    if output.len() < Self::STATUS_LIST_MIN_LENGTH / 8 {
+      return Err(Error::DecodeStatusList("bitstring too short"));
+    }
+
+    Ok(Self::from_bitstring(&output))
+  }
+
+  /// Serializes the [`BitSet`] as a Status List 2021 `encodedList`.
+  ///
+  /// Always fails since the `status-list` feature is disabled.
+  #[cfg(not(feature = "status-list"))]
+  pub fn serialize_status_list(&self) -> Result<String> {
+    Err(Error::MissingFeature("status-list"))
+  }
+
+  /// Deserializes a [`BitSet`] from a Status List 2021 `encodedList`.
+  ///
+  /// Always fails since the `status-list` feature is disabled.
+  #[cfg(not(feature = "status-list"))]
+  pub fn deserialize_status_list(_: &str) -> Result<Self> {
+    Err(Error::MissingFeature("status-list"))
+  }
+
+  /// Returns the [`BitSet`] as an uncompressed bitstring, padded to at least
+  /// [`STATUS_LIST_MIN_LENGTH`][Self::STATUS_LIST_MIN_LENGTH] bits.
+  ///
+  /// Index `0` is the most significant bit of the first byte.
+  pub fn to_bitstring(&self) -> Vec<u8> {
+    let length: usize = self
+      .0
+      .max()
+      .map(|index| index as usize / 8 + 1)
+      .unwrap_or_default()
+      .max(Self::STATUS_LIST_MIN_LENGTH / 8);
+
+    let mut output: Vec<u8> = vec![0; length];
+
+    for index in self.0.iter() {
+      output[index as usize / 8] |= 0x80 >> (index % 8);
+    }
+
+    output
+  }
+
+  /// Creates a [`BitSet`] from an uncompressed bitstring.
+  ///
+  /// Index `0` is the most significant bit of the first byte; bits beyond
+  /// the range of `u32` indices are ignored.
+  pub fn from_bitstring(data: &[u8]) -> Self {
+    let mut this: Self = Self::new();
+
+    for (offset, byte) in data.iter().enumerate().filter(|(_, byte)| **byte != 0) {
+      let offset: u32 = match u32::try_from(offset).ok().and_then(|offset| offset.checked_mul(8)) {
+        Some(offset) => offset,
+        None => break,
+      };
+
+      for bit in 0..8 {
+        if byte & (0x80 >> bit) != 0 {
+          this.0.insert(offset + bit);
+        }
+      }
+    }
+
+    this
+  }
 }
 
 impl Default for BitSet {
@@ -207,4 +327,58 @@ mod tests {
       set
     );
   }
+
+  #[cfg(feature = "status-list")]
+  #[test]
+  fn test_status_list_round_trip() {
+    let mut set = BitSet::new();
+    set.insert_all([0, 7, 8, 1000, 131_071, 200_000].iter().copied());
+
+    let encoded: String = set.serialize_status_list().unwrap();
+
+    assert!(!encoded.contains('='));
+    assert_eq!(BitSet::deserialize_status_list(&encoded).unwrap(), set);
+  }
+
+  #[test]
+  fn test_status_list_bitstring() {
+    let mut set = BitSet::new();
+
+    assert_eq!(set.to_bitstring().len(), 16 * 1024);
+
+    set.insert_all([0, 9, 15].iter().copied());
+
+    let bitstring: Vec<u8> = set.to_bitstring();
+
+    assert_eq!(bitstring.len(), 16 * 1024);
+    assert_eq!(bitstring[..3], [0b1000_0000, 0b0100_0001, 0]);
+    assert_eq!(BitSet::from_bitstring(&bitstring), set);
+
+    // The bitstring grows beyond the minimum length when needed
+    set.insert(BitSet::STATUS_LIST_MIN_LENGTH as u32);
+
+    assert_eq!(set.to_bitstring().len(), 16 * 1024 + 1);
+  }
+
+  #[cfg(feature = "status-list")]
+  #[test]
+  fn test_status_list_spec_example() {
+    // An empty status list from the Status List 2021 specification
+    let encoded: &str = "H4sIAAAAAAAAA-3BMQEAAADCoPVPbQwfoAAAAAAAAAAAAAAAAAAAAIC3AYbSVKsAQAAA";
+
+    assert!(BitSet::deserialize_status_list(encoded).unwrap().is_empty());
+  }
+
+  #[cfg(feature = "status-list")]
+  #[test]
+  fn test_status_list_invalid() {
+    let mut encoder: GzEncoder<Vec<u8>> = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&[0xFF; 1024]).unwrap();
+
+    let short: String = base64::encode_config(encoder.finish().unwrap(), base64::URL_SAFE_NO_PAD);
+
+    assert!(BitSet::deserialize_status_list(&short).is_err());
+    assert!(BitSet::deserialize_status_list("not a status list").is_err());
+    assert!(BitSet::deserialize_status_list(&BitSet::new().serialize_b64().unwrap()).is_err());
+  }
 }
@@ -0,0 +1,166 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(feature = "cbor")]
+use ciborium::value::Value;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::error::Error;
+use crate::error::Result;
+
+/// A convenience-trait for types that can be serialized as CBOR.
+pub trait ToCbor: Serialize + Sized {
+  /// Serialize `self` as a CBOR byte vector, using the [deterministic
+  /// encoding][SPEC] of RFC 8949.
+  ///
+  /// Map keys are sorted by their encoded form, and integers, floats, and
+  /// lengths use their shortest form.
+  ///
+  /// [SPEC]: https://www.rfc-editor.org/rfc/rfc8949.html#section-4.2
+  #[cfg(feature = "cbor")]
+  fn to_cbor(&self) -> Result<Vec<u8>> {
+    let value: Value = Value::serialized(self).map_err(|error| Error::EncodeCBOR(error.to_string()))?;
+
+    encode(&deterministic(value)?)
+  }
+
+  /// Serialize `self` as a CBOR byte vector.
+  ///
+  /// Always fails since the `cbor` feature is disabled.
+  #[cfg(not(feature = "cbor"))]
+  fn to_cbor(&self) -> Result<Vec<u8>> {
+    Err(Error::MissingFeature("cbor"))
+  }
+}
+
+impl<T> ToCbor for T where T: Serialize {}
+
+// =============================================================================
+// =============================================================================
+
+/// A convenience-trait for types that can be deserialized from CBOR.
+pub trait FromCbor: for<'de> Deserialize<'de> + Sized {
+  /// Deserialize `Self` from a CBOR byte vector.
+  #[cfg(feature = "cbor")]
+  fn from_cbor(cbor: &(impl AsRef<[u8]> + ?Sized)) -> Result<Self> {
+    ciborium::de::from_reader(cbor.as_ref()).map_err(|error| Error::DecodeCBOR(error.to_string()))
+  }
+
+  /// Deserialize `Self` from a CBOR byte vector.
+  ///
+  /// Always fails since the `cbor` feature is disabled.
+  #[cfg(not(feature = "cbor"))]
+  fn from_cbor(_: &(impl AsRef<[u8]> + ?Sized)) -> Result<Self> {
+    Err(Error::MissingFeature("cbor"))
+  }
+}
+
+impl<T> FromCbor for T where T: for<'de> Deserialize<'de> + Sized {}
+
+// =============================================================================
+// =============================================================================
+
+#[cfg(feature = "cbor")]
+fn encode(value: &Value) -> Result<Vec<u8>> {
+  let mut output: Vec<u8> = Vec::new();
+
+  ciborium::ser::into_writer(value, &mut output).map_err(|error| Error::EncodeCBOR(error.to_string()))?;
+
+  Ok(output)
+}
+
+// Sorts the keys of every map in `value` by their encoded form.
+//
+// The encoder already writes the shortest form of integers, floats, and
+// lengths, and never uses indefinite lengths for a `Value`.
+#[cfg(feature = "cbor")]
+fn deterministic(value: Value) -> Result<Value> {
+  match value {
+    Value::Array(items) => items
+      .into_iter()
+      .map(deterministic)
+      .collect::<Result<_>>()
+      .map(Value::Array),
+    Value::Map(entries) => {
+      let mut entries: Vec<(Vec<u8>, Value, Value)> = entries
+        .into_iter()
+        .map(|(key, value)| {
+          let key: Value = deterministic(key)?;
+          let value: Value = deterministic(value)?;
+
+          Ok((encode(&key)?, key, value))
+        })
+        .collect::<Result<_>>()?;
+
+      entries.sort_by(|lhs, rhs| lhs.0.cmp(&rhs.0));
+
+      if entries.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+        return Err(Error::EncodeCBOR("duplicate map key".into()));
+      }
+
+      Ok(Value::Map(
+        entries.into_iter().map(|(_, key, value)| (key, value)).collect(),
+      ))
+    }
+    Value::Tag(tag, inner) => deterministic(*inner).map(|inner| Value::Tag(tag, Box::new(inner))),
+    value => Ok(value),
+  }
+}
+
+#[cfg(all(test, feature = "cbor"))]
+mod tests {
+  use std::collections::HashMap;
+
+  use super::*;
+  use crate::common::Object;
+  use crate::common::Value;
+  use crate::convert::FromJson;
+  use crate::json;
+
+  #[test]
+  fn test_deterministic_map_keys() {
+    let value: Value = json!({ "b": 1, "aa": [1.5, -3], "a": { "z": null, "y": true } });
+    let cbor: Vec<u8> = value.to_cbor().unwrap();
+
+    // Shorter keys come first, then keys are compared bytewise
+    assert_eq!(cbor[..3], [0xa3, 0x61, b'a']);
+    assert_eq!(Value::from_cbor(&cbor).unwrap(), value);
+
+    let forward: HashMap<String, u32> = (0..50).map(|index| (format!("key-{}", index), index)).collect();
+    let reverse: HashMap<String, u32> = (0..50).rev().map(|index| (format!("key-{}", index), index)).collect();
+
+    assert_eq!(forward.to_cbor().unwrap(), reverse.to_cbor().unwrap());
+  }
+
+  #[test]
+  fn test_shortest_form() {
+    assert_eq!(1.5_f64.to_cbor().unwrap(), [0xf9, 0x3e, 0x00]);
+    assert_eq!(23_u64.to_cbor().unwrap(), [0x17]);
+    assert_eq!(500_u64.to_cbor().unwrap(), [0x19, 0x01, 0xf4]);
+    assert_eq!((-1_i64).to_cbor().unwrap(), [0x20]);
+  }
+
+  #[test]
+  fn test_roundtrip_smaller_than_json() {
+    let object: Object = Object::from_json(
+      r#"{
+        "id": "did:example:1234",
+        "created": "2021-01-01T00:00:00Z",
+        "values": [1, 2.5, -3, true, null, { "nested": "value" }]
+      }"#,
+    )
+    .unwrap();
+
+    let cbor: Vec<u8> = object.to_cbor().unwrap();
+
+    assert_eq!(Object::from_cbor(&cbor).unwrap(), object);
+    assert!(cbor.len() < serde_json::to_vec(&object).unwrap().len());
+  }
+
+  #[test]
+  fn test_decode_invalid() {
+    assert!(Object::from_cbor(b"").is_err());
+    assert!(Object::from_cbor(b"{}").is_err());
+  }
+}
@@ -1,11 +1,14 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-//! Traits for JSON conversions between types.
+//! Traits for JSON and CBOR conversions between types.
 
+mod cbor;
 mod json;
 mod serde_into;
 
+pub use self::cbor::FromCbor;
+pub use self::cbor::ToCbor;
 pub use self::json::FromJson;
 pub use self::json::ToJson;
 pub use self::serde_into::SerdeInto;
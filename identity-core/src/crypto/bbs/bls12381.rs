@@ -0,0 +1,595 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(feature = "bbs")]
+use bls12_381::hash_to_curve::ExpandMsgXmd;
+#[cfg(feature = "bbs")]
+use bls12_381::hash_to_curve::HashToCurve;
+#[cfg(feature = "bbs")]
+use bls12_381::multi_miller_loop;
+#[cfg(feature = "bbs")]
+use bls12_381::G1Affine;
+#[cfg(feature = "bbs")]
+use bls12_381::G1Projective;
+#[cfg(feature = "bbs")]
+use bls12_381::G2Affine;
+#[cfg(feature = "bbs")]
+use bls12_381::G2Prepared;
+#[cfg(feature = "bbs")]
+use bls12_381::G2Projective;
+#[cfg(feature = "bbs")]
+use bls12_381::Gt;
+#[cfg(feature = "bbs")]
+use bls12_381::Scalar;
+#[cfg(feature = "bbs")]
+use core::convert::TryInto;
+#[cfg(feature = "bbs")]
+use core::iter::once;
+use core::marker::PhantomData;
+#[cfg(feature = "bbs")]
+use sha2::Digest;
+#[cfg(feature = "bbs")]
+use sha2::Sha256;
+#[cfg(feature = "bbs")]
+use sha2::Sha512;
+
+use crate::crypto::Sign;
+use crate::crypto::Verify;
+use crate::error::Error;
+use crate::error::Result;
+
+/// The length of a compressed BLS12-381 G2 public key.
+pub const BLS12381_PUBLIC_KEY_LENGTH: usize = 96;
+
+/// The length of a BLS12-381 secret key.
+pub const BLS12381_SECRET_KEY_LENGTH: usize = 32;
+
+/// The length of a BBS+ signature.
+pub const BBS_SIGNATURE_LENGTH: usize = G1_LENGTH + 2 * SCALAR_LENGTH;
+
+const G1_LENGTH: usize = 48;
+const SCALAR_LENGTH: usize = 32;
+
+// The fixed-size part of a proof of knowledge: (A', Abar, d, c, z_e, z_r2, z_r3, z_s)
+#[cfg(feature = "bbs")]
+const PROOF_FIXED_LENGTH: usize = 3 * G1_LENGTH + 5 * SCALAR_LENGTH;
+
+// The domain separation tags are specific to this library, so the outputs are
+// never mistaken for those of a standard BBS+ implementation.
+#[cfg(feature = "bbs")]
+const DST_GENERATOR: &[u8] = b"IOTA_IDENTITY_BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_";
+#[cfg(feature = "bbs")]
+const DST_MESSAGE: &[u8] = b"IOTA_IDENTITY_BBS_BLS12381_MESSAGE_";
+#[cfg(feature = "bbs")]
+const DST_CHALLENGE: &[u8] = b"IOTA_IDENTITY_BBS_BLS12381_CHALLENGE_";
+
+/// An implementation of [BBS+][SPEC] signatures over the BLS12-381 curve.
+///
+/// BBS+ signs an ordered list of messages with a single signature and allows
+/// the holder of a signature to create zero-knowledge proofs that disclose
+/// only a subset of the signed messages.
+///
+/// The [`Sign`]/[`Verify`] implementations treat the input as a single
+/// message; use [`sign_messages`][Self::sign_messages] and friends to work
+/// with multiple messages.
+///
+/// The message generators and domain separation tags are specific to this
+/// library and don't follow the draft specification, so signatures and proofs
+/// are not interoperable with other BBS+ implementations.
+///
+/// [SPEC]: https://mattrglobal.github.io/bbs-signatures-spec/
+#[derive(Clone, Copy, Debug)]
+pub struct BbsBls12381<T: ?Sized = [u8]>(PhantomData<T>);
+
+#[cfg(feature = "bbs")]
+impl<T: ?Sized> BbsBls12381<T> {
+  /// Signs the given list of `messages` with the `secret` key.
+  pub fn sign_messages<M>(messages: &[M], secret: &[u8]) -> Result<Vec<u8>>
+  where
+    M: AsRef<[u8]>,
+  {
+    let secret: Scalar = parse_secret(secret)?;
+    let public: G2Affine = G2Affine::from(G2Projective::generator() * secret);
+    let messages: Vec<Scalar> = hash_messages(messages);
+    let generators: Vec<G1Projective> = generators(&public, messages.len());
+
+    let e: Scalar = random_scalar()?;
+    let s: Scalar = random_scalar()?;
+    let b: G1Projective = commitment(&generators, &s, &messages);
+
+    let exp: Scalar = Option::from((secret + e).invert()).ok_or(Error::InvalidKeyFormat)?;
+    let a: G1Affine = G1Affine::from(b * exp);
+
+    Ok(encode_signature(&a, &e, &s))
+  }
+
+  /// Verifies a BBS+ `signature` over the list of `messages` with the `public` key.
+  pub fn verify_messages<M>(messages: &[M], signature: &[u8], public: &[u8]) -> Result<()>
+  where
+    M: AsRef<[u8]>,
+  {
+    let public: G2Affine = parse_public(public)?;
+    let (a, e, s): (G1Affine, Scalar, Scalar) = decode_signature(signature)?;
+    let messages: Vec<Scalar> = hash_messages(messages);
+    let generators: Vec<G1Projective> = generators(&public, messages.len());
+
+    let b: G1Projective = commitment(&generators, &s, &messages);
+    let q: G2Affine = G2Affine::from(G2Projective::generator() * e + public);
+
+    // e(A, w * g2^e) == e(B, g2)
+    if bool::from(a.is_identity()) || !pairing_eq(&a, &q, &G1Affine::from(b), &G2Affine::generator()) {
+      return Err(Error::InvalidProofValue("bbs+"));
+    }
+
+    Ok(())
+  }
+
+  /// Creates a zero-knowledge proof of knowledge of a BBS+ `signature` that
+  /// discloses only the `messages` at the given `revealed` indices.
+  ///
+  /// The `nonce` binds the proof to a specific verification session.
+  pub fn create_proof<M>(
+    messages: &[M],
+    signature: &[u8],
+    public: &[u8],
+    revealed: &[usize],
+    nonce: &[u8],
+  ) -> Result<Vec<u8>>
+  where
+    M: AsRef<[u8]>,
+  {
+    let public: G2Affine = parse_public(public)?;
+    let (a, e, s): (G1Affine, Scalar, Scalar) = decode_signature(signature)?;
+    let messages: Vec<Scalar> = hash_messages(messages);
+    let generators: Vec<G1Projective> = generators(&public, messages.len());
+
+    if revealed.iter().any(|index| *index >= messages.len()) {
+      return Err(Error::InvalidProofFormat);
+    }
+
+    let hidden: Vec<usize> = (0..messages.len()).filter(|index| !revealed.contains(index)).collect();
+    let disclosed: Vec<(usize, Scalar)> = revealed.iter().map(|index| (*index, messages[*index])).collect();
+
+    let b: G1Projective = commitment(&generators, &s, &messages);
+
+    let r1: Scalar = random_scalar()?;
+    let r2: Scalar = random_scalar()?;
+    let r3: Scalar = Option::from(r1.invert()).ok_or(Error::InvalidProofFormat)?;
+
+    let a_prime: G1Projective = a * r1;
+    let a_bar: G1Projective = a_prime * (-e) + b * r1;
+    let d: G1Projective = b * r1 + generators[0] * (-r2);
+    let s_prime: Scalar = s - r2 * r3;
+
+    // Blinding factors for the Schnorr proofs of knowledge
+    let e_blind: Scalar = random_scalar()?;
+    let r2_blind: Scalar = random_scalar()?;
+    let r3_blind: Scalar = random_scalar()?;
+    let s_blind: Scalar = random_scalar()?;
+    let m_blind: Vec<Scalar> = hidden.iter().map(|_| random_scalar()).collect::<Result<_>>()?;
+
+    // Abar / d == A'^-e * h0^r2
+    let t1: G1Projective = a_prime * e_blind + generators[0] * r2_blind;
+
+    // g1 * h_i^m_i (revealed) == d^r3 * h0^-s' * h_j^-m_j (hidden)
+    let mut t2: G1Projective = d * r3_blind + generators[0] * s_blind;
+
+    for (index, blind) in hidden.iter().zip(m_blind.iter()) {
+      t2 += generators[index + 1] * blind;
+    }
+
+    let a_prime: G1Affine = G1Affine::from(a_prime);
+    let a_bar: G1Affine = G1Affine::from(a_bar);
+    let d: G1Affine = G1Affine::from(d);
+
+    let c: Scalar = challenge(
+      &[a_prime, a_bar, d, G1Affine::from(t1), G1Affine::from(t2)],
+      &disclosed,
+      nonce,
+    );
+
+    let mut output: Vec<u8> = Vec::with_capacity(PROOF_FIXED_LENGTH + hidden.len() * SCALAR_LENGTH);
+
+    output.extend_from_slice(&a_prime.to_compressed());
+    output.extend_from_slice(&a_bar.to_compressed());
+    output.extend_from_slice(&d.to_compressed());
+    output.extend_from_slice(&c.to_bytes());
+    output.extend_from_slice(&(e_blind - c * e).to_bytes());
+    output.extend_from_slice(&(r2_blind + c * r2).to_bytes());
+    output.extend_from_slice(&(r3_blind + c * r3).to_bytes());
+    output.extend_from_slice(&(s_blind - c * s_prime).to_bytes());
+
+    for (index, blind) in hidden.iter().zip(m_blind.iter()) {
+      output.extend_from_slice(&(blind - c * messages[*index]).to_bytes());
+    }
+
+    Ok(output)
+  }
+
+  /// Verifies a zero-knowledge `proof` created with [`create_proof`][Self::create_proof].
+  ///
+  /// `revealed` contains the disclosed messages and their indices in the
+  /// originally signed list of `total` messages.
+  pub fn verify_proof<M>(revealed: &[(usize, M)], total: usize, proof: &[u8], public: &[u8], nonce: &[u8]) -> Result<()>
+  where
+    M: AsRef<[u8]>,
+  {
+    let public: G2Affine = parse_public(public)?;
+    let generators: Vec<G1Projective> = generators(&public, total);
+
+    if revealed.iter().any(|(index, _)| *index >= total) {
+      return Err(Error::InvalidProofFormat);
+    }
+
+    let disclosed: Vec<(usize, Scalar)> = revealed
+      .iter()
+      .map(|(index, message)| (*index, hash_message(message.as_ref())))
+      .collect();
+
+    let hidden: Vec<usize> = (0..total)
+      .filter(|index| !disclosed.iter().any(|(revealed, _)| revealed == index))
+      .collect();
+
+    if proof.len() != PROOF_FIXED_LENGTH + hidden.len() * SCALAR_LENGTH {
+      return Err(Error::InvalidProofFormat);
+    }
+
+    let mut reader: Reader<'_> = Reader::new(proof);
+
+    let a_prime: G1Affine = reader.g1()?;
+    let a_bar: G1Affine = reader.g1()?;
+    let d: G1Affine = reader.g1()?;
+    let c: Scalar = reader.scalar()?;
+    let z_e: Scalar = reader.scalar()?;
+    let z_r2: Scalar = reader.scalar()?;
+    let z_r3: Scalar = reader.scalar()?;
+    let z_s: Scalar = reader.scalar()?;
+    let z_m: Vec<Scalar> = hidden.iter().map(|_| reader.scalar()).collect::<Result<_>>()?;
+
+    if bool::from(a_prime.is_identity()) {
+      return Err(Error::InvalidProofValue("bbs+"));
+    }
+
+    let t1: G1Projective = a_prime * z_e + generators[0] * z_r2 - (G1Projective::from(a_bar) - d) * c;
+
+    let mut base: G1Projective = G1Projective::generator();
+
+    for (index, message) in disclosed.iter() {
+      base += generators[index + 1] * message;
+    }
+
+    let mut t2: G1Projective = d * z_r3 + generators[0] * z_s - base * c;
+
+    for (index, response) in hidden.iter().zip(z_m.iter()) {
+      t2 += generators[index + 1] * response;
+    }
+
+    let expected: Scalar = challenge(
+      &[a_prime, a_bar, d, G1Affine::from(t1), G1Affine::from(t2)],
+      &disclosed,
+      nonce,
+    );
+
+    if expected != c {
+      return Err(Error::InvalidProofValue("bbs+"));
+    }
+
+    // e(A', w) == e(Abar, g2)
+    if !pairing_eq(&a_prime, &public, &a_bar, &G2Affine::generator()) {
+      return Err(Error::InvalidProofValue("bbs+"));
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(not(feature = "bbs"))]
+impl<T: ?Sized> BbsBls12381<T> {
+  /// Signs the given list of `messages` with the `secret` key.
+  ///
+  /// Always fails since the `bbs` feature is disabled.
+  pub fn sign_messages<M>(_: &[M], _: &[u8]) -> Result<Vec<u8>>
+  where
+    M: AsRef<[u8]>,
+  {
+    Err(Error::MissingFeature("bbs"))
+  }
+
+  /// Verifies a BBS+ `signature` over the list of `messages` with the `public` key.
+  ///
+  /// Always fails since the `bbs` feature is disabled.
+  pub fn verify_messages<M>(_: &[M], _: &[u8], _: &[u8]) -> Result<()>
+  where
+    M: AsRef<[u8]>,
+  {
+    Err(Error::MissingFeature("bbs"))
+  }
+
+  /// Creates a zero-knowledge proof of knowledge of a BBS+ `signature`.
+  ///
+  /// Always fails since the `bbs` feature is disabled.
+  pub fn create_proof<M>(_: &[M], _: &[u8], _: &[u8], _: &[usize], _: &[u8]) -> Result<Vec<u8>>
+  where
+    M: AsRef<[u8]>,
+  {
+    Err(Error::MissingFeature("bbs"))
+  }
+
+  /// Verifies a zero-knowledge `proof` of knowledge of a BBS+ signature.
+  ///
+  /// Always fails since the `bbs` feature is disabled.
+  pub fn verify_proof<M>(_: &[(usize, M)], _: usize, _: &[u8], _: &[u8], _: &[u8]) -> Result<()>
+  where
+    M: AsRef<[u8]>,
+  {
+    Err(Error::MissingFeature("bbs"))
+  }
+}
+
+impl<T> Sign for BbsBls12381<T>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  type Secret = T;
+  type Output = Vec<u8>;
+
+  fn sign(message: &[u8], key: &Self::Secret) -> Result<Self::Output> {
+    Self::sign_messages(&[message], key.as_ref())
+  }
+}
+
+impl<T> Verify for BbsBls12381<T>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  type Public = T;
+
+  fn verify(message: &[u8], signature: &[u8], key: &Self::Public) -> Result<()> {
+    Self::verify_messages(&[message], signature, key.as_ref())
+  }
+}
+
+// =============================================================================
+// =============================================================================
+
+#[cfg(feature = "bbs")]
+fn random_scalar() -> Result<Scalar> {
+  let mut bytes: [u8; 64] = [0; 64];
+  crypto::utils::rand::fill(&mut bytes)?;
+  Ok(Scalar::from_bytes_wide(&bytes))
+}
+
+// Hashes the inputs to a scalar under the domain separation tag `dst`.
+//
+// The tag and every input are prefixed with their length, so different splits
+// of the same bytes never produce the same scalar.
+#[cfg(feature = "bbs")]
+fn hash_to_scalar(dst: &[u8], data: &[&[u8]]) -> Scalar {
+  let mut digest: Sha512 = Sha512::new();
+
+  for input in once(dst).chain(data.iter().copied()) {
+    digest.update(&(input.len() as u64).to_be_bytes());
+    digest.update(input);
+  }
+
+  let mut bytes: [u8; 64] = [0; 64];
+  bytes.copy_from_slice(&digest.finalize());
+
+  Scalar::from_bytes_wide(&bytes)
+}
+
+#[cfg(feature = "bbs")]
+fn hash_message(message: &[u8]) -> Scalar {
+  hash_to_scalar(DST_MESSAGE, &[message])
+}
+
+#[cfg(feature = "bbs")]
+fn hash_messages<M>(messages: &[M]) -> Vec<Scalar>
+where
+  M: AsRef<[u8]>,
+{
+  messages.iter().map(|message| hash_message(message.as_ref())).collect()
+}
+
+// Deterministically derives the message generators `h0..hL` from the public key.
+#[cfg(feature = "bbs")]
+fn generators(public: &G2Affine, count: usize) -> Vec<G1Projective> {
+  let public: [u8; BLS12381_PUBLIC_KEY_LENGTH] = public.to_compressed();
+
+  (0..=count)
+    .map(|index| {
+      let mut data: Vec<u8> = Vec::with_capacity(BLS12381_PUBLIC_KEY_LENGTH + 8);
+      data.extend_from_slice(&public);
+      data.extend_from_slice(&(index as u32).to_be_bytes());
+      data.extend_from_slice(&(count as u32).to_be_bytes());
+
+      <G1Projective as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(&data, DST_GENERATOR)
+    })
+    .collect()
+}
+
+// Computes `B = g1 * h0^s * h1^m1 * ... * hL^mL`.
+#[cfg(feature = "bbs")]
+fn commitment(generators: &[G1Projective], s: &Scalar, messages: &[Scalar]) -> G1Projective {
+  let mut output: G1Projective = G1Projective::generator() + generators[0] * s;
+
+  for (generator, message) in generators[1..].iter().zip(messages.iter()) {
+    output += generator * message;
+  }
+
+  output
+}
+
+#[cfg(feature = "bbs")]
+fn challenge(points: &[G1Affine], disclosed: &[(usize, Scalar)], nonce: &[u8]) -> Scalar {
+  let mut commitments: Vec<u8> = Vec::with_capacity(points.len() * G1_LENGTH);
+
+  for point in points {
+    commitments.extend_from_slice(&point.to_compressed());
+  }
+
+  let mut messages: Vec<u8> = Vec::with_capacity(disclosed.len() * (4 + SCALAR_LENGTH));
+
+  for (index, message) in disclosed {
+    messages.extend_from_slice(&(*index as u32).to_be_bytes());
+    messages.extend_from_slice(&message.to_bytes());
+  }
+
+  hash_to_scalar(DST_CHALLENGE, &[&commitments, &messages, nonce])
+}
+
+// Checks `e(p1, q1) == e(p2, q2)`.
+#[cfg(feature = "bbs")]
+fn pairing_eq(p1: &G1Affine, q1: &G2Affine, p2: &G1Affine, q2: &G2Affine) -> bool {
+  let q1: G2Prepared = G2Prepared::from(*q1);
+  let q2: G2Prepared = G2Prepared::from(*q2);
+
+  multi_miller_loop(&[(p1, &q1), (&-p2, &q2)]).final_exponentiation() == Gt::identity()
+}
+
+#[cfg(feature = "bbs")]
+fn encode_signature(a: &G1Affine, e: &Scalar, s: &Scalar) -> Vec<u8> {
+  let mut output: Vec<u8> = Vec::with_capacity(BBS_SIGNATURE_LENGTH);
+  output.extend_from_slice(&a.to_compressed());
+  output.extend_from_slice(&e.to_bytes());
+  output.extend_from_slice(&s.to_bytes());
+  output
+}
+
+#[cfg(feature = "bbs")]
+fn decode_signature(slice: &[u8]) -> Result<(G1Affine, Scalar, Scalar)> {
+  if slice.len() != BBS_SIGNATURE_LENGTH {
+    return Err(Error::InvalidSigLength(slice.len(), BBS_SIGNATURE_LENGTH));
+  }
+
+  let mut reader: Reader<'_> = Reader::new(slice);
+
+  Ok((reader.g1()?, reader.scalar()?, reader.scalar()?))
+}
+
+#[cfg(feature = "bbs")]
+fn parse_public(slice: &[u8]) -> Result<G2Affine> {
+  let bytes: [u8; BLS12381_PUBLIC_KEY_LENGTH] = slice
+    .try_into()
+    .map_err(|_| Error::InvalidKeyLength(slice.len(), BLS12381_PUBLIC_KEY_LENGTH))?;
+
+  Option::from(G2Affine::from_compressed(&bytes)).ok_or(Error::InvalidKeyFormat)
+}
+
+#[cfg(feature = "bbs")]
+fn parse_secret(slice: &[u8]) -> Result<Scalar> {
+  let bytes: [u8; BLS12381_SECRET_KEY_LENGTH] = slice
+    .try_into()
+    .map_err(|_| Error::InvalidKeyLength(slice.len(), BLS12381_SECRET_KEY_LENGTH))?;
+
+  Option::from(Scalar::from_bytes(&bytes)).ok_or(Error::InvalidKeyFormat)
+}
+
+#[cfg(feature = "bbs")]
+struct Reader<'a> {
+  data: &'a [u8],
+}
+
+#[cfg(feature = "bbs")]
+impl<'a> Reader<'a> {
+  fn new(data: &'a [u8]) -> Self {
+    Self { data }
+  }
+
+  fn take(&mut self, size: usize) -> Result<&'a [u8]> {
+    if self.data.len() < size {
+      return Err(Error::InvalidProofFormat);
+    }
+
+    let (head, tail): (&[u8], &[u8]) = self.data.split_at(size);
+    self.data = tail;
+    Ok(head)
+  }
+
+  fn g1(&mut self) -> Result<G1Affine> {
+    let bytes: [u8; G1_LENGTH] = self
+      .take(G1_LENGTH)?
+      .try_into()
+      .map_err(|_| Error::InvalidProofFormat)?;
+
+    Option::from(G1Affine::from_compressed(&bytes)).ok_or(Error::InvalidProofFormat)
+  }
+
+  fn scalar(&mut self) -> Result<Scalar> {
+    let bytes: [u8; SCALAR_LENGTH] = self
+      .take(SCALAR_LENGTH)?
+      .try_into()
+      .map_err(|_| Error::InvalidProofFormat)?;
+
+    Option::from(Scalar::from_bytes(&bytes)).ok_or(Error::InvalidProofFormat)
+  }
+}
+
+#[cfg(all(test, feature = "bbs"))]
+mod tests {
+  use bls12_381::Scalar;
+
+  use super::hash_to_scalar;
+  use super::DST_MESSAGE;
+  use crate::crypto::bbs::BbsBls12381;
+  use crate::crypto::KeyPair;
+  use crate::crypto::KeyType;
+  use crate::crypto::Sign;
+  use crate::crypto::Verify;
+
+  const MESSAGES: &[&[u8]] = &[b"name", b"birthdate", b"age", b"nationality", b"address"];
+
+  #[test]
+  fn test_sign_verify_single() {
+    let keypair: KeyPair = KeyPair::new(KeyType::Bls12381G2).unwrap();
+    let signature: Vec<u8> = BbsBls12381::sign(b"hello", keypair.secret()).unwrap();
+
+    assert!(BbsBls12381::verify(b"hello", &signature, keypair.public()).is_ok());
+    assert!(BbsBls12381::verify(b"world", &signature, keypair.public()).is_err());
+  }
+
+  #[test]
+  fn test_sign_verify_messages() {
+    let keypair: KeyPair = KeyPair::new(KeyType::Bls12381G2).unwrap();
+    let badpair: KeyPair = KeyPair::new(KeyType::Bls12381G2).unwrap();
+
+    let signature: Vec<u8> = BbsBls12381::<[u8]>::sign_messages(MESSAGES, keypair.secret()).unwrap();
+
+    assert!(BbsBls12381::<[u8]>::verify_messages(MESSAGES, &signature, keypair.public().as_ref()).is_ok());
+    assert!(BbsBls12381::<[u8]>::verify_messages(MESSAGES, &signature, badpair.public().as_ref()).is_err());
+    assert!(BbsBls12381::<[u8]>::verify_messages(&MESSAGES[1..], &signature, keypair.public().as_ref()).is_err());
+  }
+
+  #[test]
+  fn test_create_verify_proof() {
+    let keypair: KeyPair = KeyPair::new(KeyType::Bls12381G2).unwrap();
+    let public: &[u8] = keypair.public().as_ref();
+
+    let signature: Vec<u8> = BbsBls12381::<[u8]>::sign_messages(MESSAGES, keypair.secret()).unwrap();
+    let proof: Vec<u8> = BbsBls12381::<[u8]>::create_proof(MESSAGES, &signature, public, &[0, 2], b"nonce").unwrap();
+
+    let revealed: &[(usize, &[u8])] = &[(0, MESSAGES[0]), (2, MESSAGES[2])];
+    let mutated: &[(usize, &[u8])] = &[(0, MESSAGES[0]), (2, MESSAGES[1])];
+
+    assert!(BbsBls12381::<[u8]>::verify_proof(revealed, MESSAGES.len(), &proof, public, b"nonce").is_ok());
+
+    // Fails with a different nonce
+    assert!(BbsBls12381::<[u8]>::verify_proof(revealed, MESSAGES.len(), &proof, public, b"other").is_err());
+
+    // Fails when a revealed message is mutated
+    assert!(BbsBls12381::<[u8]>::verify_proof(mutated, MESSAGES.len(), &proof, public, b"nonce").is_err());
+
+    // Fails when the number of messages does not match
+    assert!(BbsBls12381::<[u8]>::verify_proof(revealed, MESSAGES.len() + 1, &proof, public, b"nonce").is_err());
+  }
+
+  #[test]
+  fn test_hash_to_scalar_framing() {
+    let scalar: Scalar = hash_to_scalar(DST_MESSAGE, &[b"ab", b"c"]);
+
+    assert_eq!(scalar, hash_to_scalar(DST_MESSAGE, &[b"ab", b"c"]));
+    assert_ne!(scalar, hash_to_scalar(DST_MESSAGE, &[b"a", b"bc"]));
+    assert_ne!(scalar, hash_to_scalar(DST_MESSAGE, &[b"abc"]));
+    assert_ne!(scalar, hash_to_scalar(b"", &[DST_MESSAGE, b"ab", b"c"]));
+  }
+}
@@ -0,0 +1,195 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde_json::Map;
+
+use crate::common::Object;
+use crate::common::Value;
+use crate::convert::ToJson;
+use crate::error::Error;
+use crate::error::Result;
+
+// The top-level property holding the proof - signed as a single message.
+const PROOF: &str = "proof";
+
+// The properties holding the signature value - never part of the signed messages.
+const VALUES: &[&str] = &["jws", "proofValue", "signatureValue"];
+
+/// A single claim of a JSON document identified by a JSON Pointer.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Claim {
+  pub(crate) pointer: String,
+  pub(crate) value: Value,
+}
+
+impl Claim {
+  /// Returns the BBS+ message encoding of the claim.
+  pub(crate) fn to_message(&self) -> Result<Vec<u8>> {
+    let mut output: Vec<u8> = self.pointer.as_bytes().to_vec();
+    output.push(b'=');
+    output.extend(self.value.to_jcs()?);
+    Ok(output)
+  }
+
+  /// Returns `true` if the claim is covered by the given JSON Pointer.
+  pub(crate) fn matches(&self, pointer: &str) -> bool {
+    self.pointer == pointer || (self.pointer.starts_with(pointer) && self.pointer[pointer.len()..].starts_with('/'))
+  }
+}
+
+/// Flattens a JSON object into an ordered list of claims.
+///
+/// Every non-object value (and every empty object) is a claim - arrays are
+/// treated as a single claim. The top-level `proof` property is ignored.
+pub(crate) fn flatten(value: &Value) -> Result<Vec<Claim>> {
+  let object: &Map<String, Value> = match value {
+    Value::Object(object) => object,
+    _ => return Err(Error::InvalidProofFormat),
+  };
+
+  let mut output: Vec<Claim> = Vec::new();
+
+  for (key, value) in object.iter().filter(|(key, _)| *key != PROOF) {
+    flatten_into(&mut output, format!("/{}", escape(key)), value);
+  }
+
+  Ok(output)
+}
+
+fn flatten_into(output: &mut Vec<Claim>, pointer: String, value: &Value) {
+  match value {
+    Value::Object(object) if !object.is_empty() => {
+      for (key, value) in object.iter() {
+        flatten_into(output, format!("{}/{}", pointer, escape(key)), value);
+      }
+    }
+    _ => {
+      output.push(Claim {
+        pointer,
+        value: value.clone(),
+      });
+    }
+  }
+}
+
+/// Returns the proof options of a JSON object as a single claim.
+///
+/// The signature value is removed and the proof `type` is replaced with
+/// `type_`, so a derived proof resolves to the options of the original
+/// signature. For a proof chain, only the last proof is normalized.
+pub(crate) fn proof_options(value: &Value, type_: &str) -> Result<Claim> {
+  let mut proof: Value = value.get(PROOF).cloned().ok_or(Error::MissingSignature)?;
+
+  let options: Option<&mut Value> = match &mut proof {
+    Value::Array(proofs) => proofs.last_mut(),
+    options => Some(options),
+  };
+
+  let options: &mut Map<String, Value> = options
+    .and_then(Value::as_object_mut)
+    .ok_or(Error::InvalidProofFormat)?;
+
+  for key in VALUES {
+    options.remove(*key);
+  }
+
+  options.insert("type".into(), type_.into());
+
+  Ok(Claim {
+    pointer: format!("/{}", PROOF),
+    value: proof,
+  })
+}
+
+/// Rebuilds a JSON object from a list of claims.
+pub(crate) fn unflatten<'a>(claims: impl IntoIterator<Item = &'a Claim>) -> Result<Object> {
+  let mut output: Value = Value::Object(Map::new());
+
+  for claim in claims {
+    let tokens: Vec<String> = claim.pointer.split('/').skip(1).map(unescape).collect();
+    let (last, path): (&String, &[String]) = tokens.split_last().ok_or(Error::InvalidProofFormat)?;
+
+    let mut target: &mut Value = &mut output;
+
+    for token in path {
+      target = target
+        .as_object_mut()
+        .ok_or(Error::InvalidProofFormat)?
+        .entry(token.clone())
+        .or_insert_with(|| Value::Object(Map::new()));
+    }
+
+    target
+      .as_object_mut()
+      .ok_or(Error::InvalidProofFormat)?
+      .insert(last.clone(), claim.value.clone());
+  }
+
+  match output {
+    Value::Object(object) => Ok(object.into_iter().collect()),
+    _ => Err(Error::InvalidProofFormat),
+  }
+}
+
+// Escapes a JSON Pointer reference token (RFC 6901).
+fn escape(token: &str) -> String {
+  token.replace('~', "~0").replace('/', "~1")
+}
+
+// Unescapes a JSON Pointer reference token (RFC 6901).
+fn unescape(token: &str) -> String {
+  token.replace("~1", "/").replace("~0", "~")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::json;
+
+  #[test]
+  fn test_flatten_unflatten() {
+    let value: Value = json!({
+      "a/b": 1,
+      "nested": { "x": [1, 2], "y": { "z": null }, "empty": {} },
+      "proof": { "type": "Foo" },
+    });
+
+    let claims: Vec<Claim> = flatten(&value).unwrap();
+    let pointers: Vec<&str> = claims.iter().map(|claim| claim.pointer.as_str()).collect();
+
+    assert_eq!(pointers, ["/a~1b", "/nested/empty", "/nested/x", "/nested/y/z"]);
+
+    let mut expected: Value = value.clone();
+    expected.as_object_mut().unwrap().remove("proof");
+
+    assert_eq!(unflatten(&claims).unwrap().to_json_value().unwrap(), expected);
+  }
+
+  #[test]
+  fn test_proof_options() {
+    let value: Value = json!({
+      "issuer": "did:example:issuer",
+      "proof": { "type": "Foo", "domain": "example.com", "proofValue": "abc" },
+    });
+
+    let claim: Claim = proof_options(&value, "Bar").unwrap();
+
+    assert_eq!(claim.pointer, "/proof");
+    assert_eq!(claim.value, json!({ "type": "Bar", "domain": "example.com" }));
+
+    assert!(proof_options(&json!({ "issuer": "did:example:issuer" }), "Bar").is_err());
+  }
+
+  #[test]
+  fn test_claim_matches() {
+    let claim: Claim = Claim {
+      pointer: "/credentialSubject/degree/name".into(),
+      value: Value::Null,
+    };
+
+    assert!(claim.matches("/credentialSubject"));
+    assert!(claim.matches("/credentialSubject/degree/name"));
+    assert!(!claim.matches("/credentialSubject/deg"));
+    assert!(!claim.matches("/issuer"));
+  }
+}
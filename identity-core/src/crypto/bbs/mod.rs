@@ -0,0 +1,19 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! An implementation of BBS+ Signatures over BLS12-381 with support for
+//! selective disclosure proofs.
+
+mod bls12381;
+mod messages;
+mod suite;
+
+pub use self::bls12381::BbsBls12381;
+pub use self::bls12381::BBS_SIGNATURE_LENGTH;
+pub use self::bls12381::BLS12381_PUBLIC_KEY_LENGTH;
+pub use self::bls12381::BLS12381_SECRET_KEY_LENGTH;
+pub use self::suite::JcsBbsBls12381Signature2021;
+pub use self::suite::JcsBbsBls12381SignatureProof2021;
+
+#[cfg(all(test, feature = "bbs"))]
+mod tests;
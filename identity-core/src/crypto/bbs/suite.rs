@@ -0,0 +1,354 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::TryInto;
+use core::marker::PhantomData;
+use serde::Serialize;
+use subtle::ConstantTimeEq;
+
+use serde_json::Map;
+
+use crate::common::Object;
+use crate::common::Value;
+use crate::convert::ToJson;
+use crate::crypto::bbs::messages;
+use crate::crypto::bbs::messages::Claim;
+use crate::crypto::bbs::BbsBls12381;
+use crate::crypto::Named;
+use crate::crypto::Signature;
+use crate::crypto::SignatureSet;
+use crate::crypto::SignatureValue;
+use crate::crypto::SignatureView;
+use crate::crypto::Signer;
+use crate::crypto::TrySignature;
+use crate::crypto::Verifier;
+use crate::crypto::VerifierOptions;
+use crate::error::Error;
+use crate::error::Result;
+use crate::utils::decode_b58;
+use crate::utils::encode_b58;
+
+/// A [BBS+][`BbsBls12381`] signature suite for [Linked Data Proofs][SPEC2],
+/// modelled after the [BBS+ Signature 2020][SPEC1] suite.
+///
+/// The generators and domain separation tags of [`BbsBls12381`] are specific
+/// to this library and claims are encoded with [JCS][SPEC4] instead of
+/// URDNA2015, so signatures are not interoperable with `BbsBlsSignature2020`
+/// and use a distinct suite name.
+///
+/// The input is split into one message per claim, where every claim is a
+/// leaf of the JSON document identified by its [JSON Pointer][SPEC3]. The
+/// proof options (without the signature value) are signed as an additional
+/// first message. The resulting signature is stored as a base58-encoded
+/// `signatureValue`.
+///
+/// Signed documents can be turned into selective disclosure proofs with
+/// [`JcsBbsBls12381SignatureProof2021::derive`].
+///
+/// [SPEC1]: https://w3c-ccg.github.io/ldp-bbs2020/
+/// [SPEC2]: https://w3c-ccg.github.io/ld-proofs/
+/// [SPEC3]: https://tools.ietf.org/html/rfc6901
+/// [SPEC4]: https://tools.ietf.org/html/rfc8785
+pub struct JcsBbsBls12381Signature2021<T: ?Sized = [u8]>(PhantomData<T>);
+
+impl<T: ?Sized> Named for JcsBbsBls12381Signature2021<T> {
+  const NAME: &'static str = "JcsBbsBls12381Signature2021";
+}
+
+impl<T> Signer<T> for JcsBbsBls12381Signature2021<T>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  fn sign<X>(data: &X, secret: &T) -> Result<SignatureValue>
+  where
+    X: Serialize,
+  {
+    let messages: Vec<Vec<u8>> = to_messages(&claims(&data.to_json_value()?)?)?;
+    let signature: Vec<u8> = BbsBls12381::<[u8]>::sign_messages(&messages, secret.as_ref())?;
+
+    Ok(SignatureValue::Signature(encode_b58(&signature)))
+  }
+}
+
+impl<T> Verifier<T> for JcsBbsBls12381Signature2021<T>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  fn verify<X>(data: &X, signature: &SignatureValue, public: &T) -> Result<()>
+  where
+    X: Serialize,
+  {
+    let signature: &str = signature.as_signature().ok_or(Error::InvalidProofValue("bbs+"))?;
+    let signature: Vec<u8> = decode_b58(signature)?;
+    let messages: Vec<Vec<u8>> = to_messages(&claims(&data.to_json_value()?)?)?;
+
+    BbsBls12381::<[u8]>::verify_messages(&messages, &signature, public.as_ref())
+  }
+}
+
+// =============================================================================
+// =============================================================================
+
+/// A selective disclosure proof suite for [`JcsBbsBls12381Signature2021`],
+/// modelled after the [BBS+ Signature Proof 2020][SPEC] suite.
+///
+/// A `JcsBbsBls12381SignatureProof2021` is a zero-knowledge proof of a
+/// [`JcsBbsBls12381Signature2021`] that discloses only a subset of the signed claims.
+/// The verifier-provided nonce is embedded in the base58-encoded `proofValue`
+/// and must match the nonce expected by the verifier, see
+/// [`VerifierOptions::nonce`]. The proof options of the original signature
+/// are always disclosed.
+///
+/// Proofs are not interoperable with `BbsBlsSignatureProof2020`.
+///
+/// [SPEC]: https://w3c-ccg.github.io/ldp-bbs2020/
+pub struct JcsBbsBls12381SignatureProof2021<T: ?Sized = [u8]>(PhantomData<T>);
+
+impl<T: ?Sized> Named for JcsBbsBls12381SignatureProof2021<T> {
+  const NAME: &'static str = "JcsBbsBls12381SignatureProof2021";
+}
+
+impl<T> JcsBbsBls12381SignatureProof2021<T>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  /// Derives a new document from `data` that contains only the claims
+  /// selected by the `reveal` JSON Pointers and a proof of the original
+  /// [`JcsBbsBls12381Signature2021`] signature.
+  ///
+  /// A pointer reveals the claim it identifies and all claims nested below it.
+  /// The proof options of the original signature are always revealed and
+  /// copied to the derived proof.
+  pub fn derive<X>(data: &X, public: &T, reveal: &[&str], nonce: &[u8]) -> Result<Object>
+  where
+    X: Serialize + TrySignature,
+  {
+    let signature: &Signature = data.try_signature()?;
+
+    if signature.type_() != JcsBbsBls12381Signature2021::<T>::NAME {
+      return Err(Error::InvalidProofValue("signature name"));
+    }
+
+    let value: &str = signature
+      .value()
+      .as_signature()
+      .ok_or(Error::InvalidProofValue("bbs+"))?;
+
+    let value: Vec<u8> = decode_b58(value)?;
+    let claims: Vec<Claim> = claims(&data.to_json_value()?)?;
+    let encoded: Vec<Vec<u8>> = to_messages(&claims)?;
+
+    let revealed: Vec<usize> = claims
+      .iter()
+      .enumerate()
+      .filter(|(index, claim)| *index == OPTIONS || reveal.iter().any(|pointer| claim.matches(pointer)))
+      .map(|(index, _)| index)
+      .collect();
+
+    let proof: Vec<u8> = BbsBls12381::<[u8]>::create_proof(&encoded, &value, public.as_ref(), &revealed, nonce)?;
+
+    let proof: DerivedProof = DerivedProof {
+      total: claims.len(),
+      revealed,
+      nonce: nonce.to_vec(),
+      proof,
+    };
+
+    let mut output: Object = messages::unflatten(
+      proof
+        .revealed
+        .iter()
+        .filter(|index| **index != OPTIONS)
+        .map(|index| &claims[*index]),
+    )?;
+    let mut options: Value = claims[OPTIONS].value.clone();
+
+    // A chained signature is preceded by the signature it was created over.
+    let signature: Option<&mut Value> = match &mut options {
+      Value::Array(proofs) => proofs.last_mut(),
+      options => Some(options),
+    };
+
+    let signature: &mut Map<String, Value> = signature
+      .and_then(Value::as_object_mut)
+      .ok_or(Error::InvalidProofFormat)?;
+
+    signature.insert("type".into(), Self::NAME.into());
+    signature.insert("proofValue".into(), encode_b58(&proof.encode()).into());
+    output.insert("proof".into(), options);
+
+    Ok(output)
+  }
+
+  /// Derives a new document from `data` with a proof of the signature at the
+  /// specified `index` of the signature set of `data`.
+  ///
+  /// The derived document holds only the selected signature, preceded by the
+  /// signature it was chained to (if any). See [`derive`][Self::derive].
+  pub fn derive_at<X>(data: &X, index: usize, public: &T, reveal: &[&str], nonce: &[u8]) -> Result<Object>
+  where
+    X: Serialize + SignatureSet,
+  {
+    Self::derive(&SignatureView::new(data, index), public, reveal, nonce)
+  }
+
+  /// Returns the nonce embedded in a `JcsBbsBls12381SignatureProof2021` signature value.
+  pub fn nonce(signature: &SignatureValue) -> Result<Vec<u8>> {
+    let proof: &str = signature.as_proof().ok_or(Error::InvalidProofValue("bbs+"))?;
+
+    DerivedProof::decode(&decode_b58(proof)?).map(|proof| proof.nonce)
+  }
+}
+
+impl<T> Verifier<T> for JcsBbsBls12381SignatureProof2021<T>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  // A proof is only valid for the nonce issued by the verifier, which is not
+  // known without options - see `verify_signature_with_options`.
+  fn verify<X>(_data: &X, _signature: &SignatureValue, _public: &T) -> Result<()>
+  where
+    X: Serialize,
+  {
+    Err(Error::InvalidProofValue("nonce"))
+  }
+
+  fn verify_signature_with_options<X>(data: &X, public: &T, options: &VerifierOptions) -> Result<()>
+  where
+    X: Serialize + TrySignature,
+  {
+    let nonce: &[u8] = options.nonce.as_deref().ok_or(Error::InvalidProofValue("nonce"))?;
+    let signature: &Signature = data.try_signature()?;
+
+    if signature.type_() != Self::NAME {
+      return Err(Error::InvalidProofValue("signature name"));
+    }
+
+    signature.verify_options(options)?;
+
+    signature.hide_value();
+
+    let result: Result<()> = verify_proof(&data, signature.value(), public.as_ref(), nonce);
+
+    signature.show_value();
+
+    result
+  }
+}
+
+fn verify_proof<X>(data: &X, signature: &SignatureValue, public: &[u8], nonce: &[u8]) -> Result<()>
+where
+  X: Serialize,
+{
+  let proof: &str = signature.as_proof().ok_or(Error::InvalidProofValue("bbs+"))?;
+  let proof: DerivedProof = DerivedProof::decode(&decode_b58(proof)?)?;
+
+  if !bool::from(proof.nonce.ct_eq(nonce)) {
+    return Err(Error::InvalidProofValue("nonce"));
+  }
+
+  let claims: Vec<Claim> = claims(&data.to_json_value()?)?;
+
+  // The proof options must always be disclosed
+  if claims.len() != proof.revealed.len() || proof.revealed.first() != Some(&OPTIONS) {
+    return Err(Error::InvalidProofValue("bbs+"));
+  }
+
+  let revealed: Vec<(usize, Vec<u8>)> = proof.revealed.iter().copied().zip(to_messages(&claims)?).collect();
+
+  BbsBls12381::<[u8]>::verify_proof(&revealed, proof.total, &proof.proof, public, nonce)
+}
+
+// The index of the message holding the proof options.
+const OPTIONS: usize = 0;
+
+// Returns the signed claims of `data` - the proof options followed by the
+// claims of the document.
+fn claims(data: &Value) -> Result<Vec<Claim>> {
+  let mut output: Vec<Claim> = vec![messages::proof_options(
+    data,
+    JcsBbsBls12381Signature2021::<[u8]>::NAME,
+  )?];
+
+  output.extend(messages::flatten(data)?);
+
+  Ok(output)
+}
+
+fn to_messages(claims: &[Claim]) -> Result<Vec<Vec<u8>>> {
+  claims.iter().map(Claim::to_message).collect()
+}
+
+// The decoded `proofValue` of a `JcsBbsBls12381SignatureProof2021`.
+//
+// Layout (integers are big-endian u32):
+//   total || count || index * count || nonce length || nonce || proof
+#[derive(Debug)]
+struct DerivedProof {
+  total: usize,
+  revealed: Vec<usize>,
+  nonce: Vec<u8>,
+  proof: Vec<u8>,
+}
+
+impl DerivedProof {
+  fn encode(&self) -> Vec<u8> {
+    let mut output: Vec<u8> = Vec::new();
+
+    output.extend_from_slice(&(self.total as u32).to_be_bytes());
+    output.extend_from_slice(&(self.revealed.len() as u32).to_be_bytes());
+
+    for index in self.revealed.iter() {
+      output.extend_from_slice(&(*index as u32).to_be_bytes());
+    }
+
+    output.extend_from_slice(&(self.nonce.len() as u32).to_be_bytes());
+    output.extend_from_slice(&self.nonce);
+    output.extend_from_slice(&self.proof);
+    output
+  }
+
+  fn decode(mut data: &[u8]) -> Result<Self> {
+    let total: usize = read_u32(&mut data)?;
+    let count: usize = read_u32(&mut data)?;
+
+    if count > total {
+      return Err(Error::InvalidProofFormat);
+    }
+
+    let revealed: Vec<usize> = (0..count).map(|_| read_u32(&mut data)).collect::<Result<_>>()?;
+
+    // Indices must be unique and in the same order as the disclosed claims.
+    if revealed.windows(2).any(|pair| pair[0] >= pair[1]) {
+      return Err(Error::InvalidProofFormat);
+    }
+
+    let length: usize = read_u32(&mut data)?;
+
+    if data.len() < length {
+      return Err(Error::InvalidProofFormat);
+    }
+
+    let (nonce, proof): (&[u8], &[u8]) = data.split_at(length);
+
+    Ok(Self {
+      total,
+      revealed,
+      nonce: nonce.to_vec(),
+      proof: proof.to_vec(),
+    })
+  }
+}
+
+fn read_u32(data: &mut &[u8]) -> Result<usize> {
+  if data.len() < 4 {
+    return Err(Error::InvalidProofFormat);
+  }
+
+  let (head, tail): (&[u8], &[u8]) = data.split_at(4);
+  let value: [u8; 4] = head.try_into().map_err(|_| Error::InvalidProofFormat)?;
+
+  *data = tail;
+
+  Ok(u32::from_be_bytes(value) as usize)
+}
@@ -0,0 +1,255 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::Object;
+use crate::common::OneOrMany;
+use crate::common::Value;
+use crate::convert::FromJson;
+use crate::convert::ToJson;
+use crate::crypto::bbs::JcsBbsBls12381Signature2021;
+use crate::crypto::bbs::JcsBbsBls12381SignatureProof2021;
+use crate::crypto::KeyPair;
+use crate::crypto::KeyType;
+use crate::crypto::ProofPurpose;
+use crate::crypto::SetSignature;
+use crate::crypto::Signature;
+use crate::crypto::SignatureOptions;
+use crate::crypto::SignatureSet;
+use crate::crypto::SignatureView;
+use crate::crypto::Signer as _;
+use crate::crypto::TrySignature;
+use crate::crypto::TrySignatureMut;
+use crate::crypto::Verifier as _;
+use crate::crypto::VerifierOptions;
+use crate::json;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct Document {
+  #[serde(flatten)]
+  properties: Object,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  proof: Option<Signature>,
+}
+
+impl TrySignature for Document {
+  fn signature(&self) -> Option<&Signature> {
+    self.proof.as_ref()
+  }
+}
+
+impl TrySignatureMut for Document {
+  fn signature_mut(&mut self) -> Option<&mut Signature> {
+    self.proof.as_mut()
+  }
+}
+
+impl SetSignature for Document {
+  fn set_signature(&mut self, signature: Signature) {
+    self.proof = Some(signature);
+  }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct Proofs {
+  #[serde(flatten)]
+  properties: Object,
+  #[serde(skip_serializing_if = "OneOrMany::is_empty")]
+  proof: OneOrMany<Signature>,
+}
+
+impl SignatureSet for Proofs {
+  fn signatures(&self) -> &OneOrMany<Signature> {
+    &self.proof
+  }
+
+  fn signatures_mut(&mut self) -> &mut OneOrMany<Signature> {
+    &mut self.proof
+  }
+}
+
+fn document() -> Document {
+  let value: Value = json!({
+    "id": "did:example:credential",
+    "issuer": "did:example:issuer",
+    "credentialSubject": {
+      "id": "did:example:holder",
+      "name": "Alice",
+      "birthDate": "1990-01-01",
+      "degree": { "type": "BachelorDegree", "name": "Bachelor of Science" },
+    },
+  });
+
+  Document::from_json_value(value).unwrap()
+}
+
+fn signed(keypair: &KeyPair) -> Document {
+  let mut document: Document = document();
+
+  JcsBbsBls12381Signature2021::create_signature(&mut document, "did:example:issuer#key-1", keypair.secret()).unwrap();
+
+  document
+}
+
+#[test]
+fn test_sign_verify_signature() {
+  let keypair: KeyPair = KeyPair::new(KeyType::Bls12381G2).unwrap();
+  let badpair: KeyPair = KeyPair::new(KeyType::Bls12381G2).unwrap();
+  let mut document: Document = signed(&keypair);
+
+  assert!(JcsBbsBls12381Signature2021::verify_signature(&document, keypair.public()).is_ok());
+  assert!(JcsBbsBls12381Signature2021::verify_signature(&document, badpair.public()).is_err());
+
+  document.properties.insert("issuer".into(), "did:example:other".into());
+
+  assert!(JcsBbsBls12381Signature2021::verify_signature(&document, keypair.public()).is_err());
+}
+
+#[test]
+fn test_derive_verify_proof() {
+  let keypair: KeyPair = KeyPair::new(KeyType::Bls12381G2).unwrap();
+  let document: Document = signed(&keypair);
+  let reveal: &[&str] = &["/issuer", "/credentialSubject/degree"];
+
+  let derived: Object =
+    JcsBbsBls12381SignatureProof2021::derive(&document, keypair.public(), reveal, b"nonce").unwrap();
+  let derived: Document = Document::from_json_value(derived.to_json_value().unwrap()).unwrap();
+
+  let expected: Value = json!({
+    "issuer": "did:example:issuer",
+    "credentialSubject": {
+      "degree": { "type": "BachelorDegree", "name": "Bachelor of Science" },
+    },
+  });
+
+  assert_eq!(derived.properties.to_json_value().unwrap(), expected);
+  assert_eq!(
+    derived.proof.as_ref().unwrap().type_(),
+    "JcsBbsBls12381SignatureProof2021"
+  );
+  assert_eq!(
+    JcsBbsBls12381SignatureProof2021::<[u8]>::nonce(derived.proof.as_ref().unwrap().value()).unwrap(),
+    b"nonce"
+  );
+
+  let options: VerifierOptions = VerifierOptions::new().nonce(b"nonce".to_vec());
+
+  assert!(
+    JcsBbsBls12381SignatureProof2021::verify_signature_with_options(&derived, keypair.public(), &options).is_ok()
+  );
+
+  // A proof must be verified with the expected nonce
+  let other: VerifierOptions = VerifierOptions::new().nonce(b"other".to_vec());
+  assert!(JcsBbsBls12381SignatureProof2021::verify_signature(&derived, keypair.public()).is_err());
+  assert!(JcsBbsBls12381SignatureProof2021::verify_signature_with_options(&derived, keypair.public(), &other).is_err());
+
+  // A modified disclosed claim should be invalid
+  let mut modified: Document = derived.clone();
+  modified.properties.insert("issuer".into(), "did:example:other".into());
+  assert!(
+    JcsBbsBls12381SignatureProof2021::verify_signature_with_options(&modified, keypair.public(), &options).is_err()
+  );
+
+  // An additional claim should be invalid
+  let mut modified: Document = derived;
+  modified.properties.insert("id".into(), "did:example:credential".into());
+  assert!(
+    JcsBbsBls12381SignatureProof2021::verify_signature_with_options(&modified, keypair.public(), &options).is_err()
+  );
+}
+
+#[test]
+fn test_proof_options_are_signed() {
+  let keypair: KeyPair = KeyPair::new(KeyType::Bls12381G2).unwrap();
+  let mut document: Document = document();
+  let options: SignatureOptions = SignatureOptions::new()
+    .purpose(ProofPurpose::AssertionMethod)
+    .domain("example.com");
+
+  JcsBbsBls12381Signature2021::create_signature_with_options(
+    &mut document,
+    "did:example:issuer#key-1",
+    keypair.secret(),
+    options,
+  )
+  .unwrap();
+
+  assert!(JcsBbsBls12381Signature2021::verify_signature(&document, keypair.public()).is_ok());
+
+  // Stripping an option of the signature should be invalid
+  let mut modified: Value = document.to_json_value().unwrap();
+  modified["proof"].as_object_mut().unwrap().remove("domain");
+  let modified: Document = Document::from_json_value(modified).unwrap();
+  assert!(JcsBbsBls12381Signature2021::verify_signature(&modified, keypair.public()).is_err());
+
+  let derived: Object =
+    JcsBbsBls12381SignatureProof2021::derive(&document, keypair.public(), &["/issuer"], b"nonce").unwrap();
+  let mut derived: Value = derived.to_json_value().unwrap();
+
+  assert_eq!(derived["proof"]["domain"], "example.com");
+  assert_eq!(derived["proof"]["proofPurpose"], "assertionMethod");
+
+  let options: VerifierOptions = VerifierOptions::new().nonce(b"nonce".to_vec());
+  let verified: Document = Document::from_json_value(derived.clone()).unwrap();
+
+  assert!(
+    JcsBbsBls12381SignatureProof2021::verify_signature_with_options(&verified, keypair.public(), &options).is_ok()
+  );
+
+  // Rewriting an option of the derived proof should be invalid
+  derived["proof"]["domain"] = "example.org".into();
+  let modified: Document = Document::from_json_value(derived).unwrap();
+  assert!(
+    JcsBbsBls12381SignatureProof2021::verify_signature_with_options(&modified, keypair.public(), &options).is_err()
+  );
+}
+
+#[test]
+fn test_derive_requires_signature() {
+  let keypair: KeyPair = KeyPair::new(KeyType::Bls12381G2).unwrap();
+
+  assert!(JcsBbsBls12381SignatureProof2021::derive(&document(), keypair.public(), &["/issuer"], b"").is_err());
+}
+
+#[test]
+fn test_derive_proof_set() {
+  let keypair: KeyPair = KeyPair::new(KeyType::Bls12381G2).unwrap();
+  let document: Document = document();
+  let mut document: Proofs = Proofs::from_json_value(document.to_json_value().unwrap()).unwrap();
+
+  JcsBbsBls12381Signature2021::append_signature(&mut document, "did:example:issuer#key-1", keypair.secret()).unwrap();
+  JcsBbsBls12381Signature2021::append_signature(&mut document, "did:example:issuer#key-2", keypair.secret()).unwrap();
+  JcsBbsBls12381Signature2021::chain_signature(&mut document, "did:example:issuer#key-3", keypair.secret()).unwrap();
+
+  assert_eq!(document.proof.len(), 3);
+
+  let options: VerifierOptions = VerifierOptions::new().nonce(b"nonce".to_vec());
+
+  // An independent signature of the set derives a single proof
+  let derived: Object =
+    JcsBbsBls12381SignatureProof2021::derive_at(&document, 1, keypair.public(), &["/issuer"], b"nonce").unwrap();
+  let derived: Proofs = Proofs::from_json_value(derived.to_json_value().unwrap()).unwrap();
+
+  assert_eq!(derived.proof.len(), 1);
+  assert_eq!(derived.proof[0].type_(), "JcsBbsBls12381SignatureProof2021");
+  assert!(JcsBbsBls12381SignatureProof2021::verify_signature_with_options(
+    &SignatureView::new(&derived, 0),
+    keypair.public(),
+    &options
+  )
+  .is_ok());
+
+  // A chained signature derives a proof preceded by the signature it covers
+  let derived: Object =
+    JcsBbsBls12381SignatureProof2021::derive_at(&document, 2, keypair.public(), &["/issuer"], b"nonce").unwrap();
+  let derived: Proofs = Proofs::from_json_value(derived.to_json_value().unwrap()).unwrap();
+
+  assert_eq!(derived.proof.len(), 2);
+  assert_eq!(derived.proof[0].type_(), "JcsBbsBls12381Signature2021");
+  assert_eq!(derived.proof[1].type_(), "JcsBbsBls12381SignatureProof2021");
+  assert!(JcsBbsBls12381SignatureProof2021::verify_signature_with_options(
+    &SignatureView::new(&derived, 1),
+    keypair.public(),
+    &options
+  )
+  .is_ok());
+}
@@ -10,22 +10,28 @@ use std::vec::IntoIter;
 
 use crate::crypto::merkle_key::MerkleDigest;
 use crate::crypto::merkle_key::SigningKey;
-use crate::crypto::merkle_tree::compute_merkle_proof;
-use crate::crypto::merkle_tree::compute_merkle_root;
+use crate::crypto::merkle_tree::compute_padded_merkle_proof;
+use crate::crypto::merkle_tree::compute_padded_merkle_root;
 use crate::crypto::merkle_tree::DigestExt;
 use crate::crypto::merkle_tree::Hash;
 use crate::crypto::merkle_tree::Proof;
+use crate::crypto::DerivationPath;
 use crate::crypto::KeyPair;
 use crate::crypto::KeyRef;
 use crate::crypto::KeyType;
 use crate::crypto::PublicKey;
 use crate::crypto::SecretKey;
+use crate::crypto::Seed;
 use crate::error::Error;
 use crate::error::Result;
+use crate::utils::derive_ed25519_keypairs;
+use crate::utils::generate_bls12381_keypairs;
 use crate::utils::generate_ed25519_keypairs;
+use crate::utils::generate_p256_keypairs;
+use crate::utils::generate_secp256k1_keypairs;
 
 /// A collection of cryptographic keys.
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 pub struct KeyCollection {
   type_: KeyType,
   public: Box<[PublicKey]>,
@@ -35,10 +41,20 @@ pub struct KeyCollection {
 impl KeyCollection {
   /// Creates a new [`KeyCollection`] from an iterator of
   /// [`PublicKey`]/[`SecretKey`] pairs.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the iterator is empty or the key type is not a signature
+  /// algorithm.
   pub fn from_iterator<I>(type_: KeyType, iter: I) -> Result<Self>
   where
     I: IntoIterator<Item = (PublicKey, SecretKey)>,
   {
+    // X25519 keys cannot sign and are never part of a Merkle Key Collection.
+    if type_ == KeyType::X25519 {
+      return Err(Error::InvalidKeyCollectionType(type_));
+    }
+
     let (public, secret): (Vec<_>, Vec<_>) = iter.into_iter().unzip();
 
     if public.is_empty() {
@@ -65,11 +81,23 @@ impl KeyCollection {
   pub fn new(type_: KeyType, count: usize) -> Result<Self> {
     let keys: Vec<(PublicKey, SecretKey)> = match type_ {
       KeyType::Ed25519 => generate_ed25519_keypairs(count)?,
+      KeyType::Secp256k1 => generate_secp256k1_keypairs(count)?,
+      KeyType::P256 => generate_p256_keypairs(count)?,
+      KeyType::Bls12381G2 => generate_bls12381_keypairs(count)?,
+      KeyType::X25519 => return Err(Error::InvalidKeyCollectionType(type_)),
     };
 
     Self::from_iterator(type_, keys.into_iter())
   }
 
+  /// Derives a [`KeyCollection`] of `count` [`Ed25519`][`KeyType::Ed25519`]
+  /// keys from the given `seed`.
+  ///
+  /// The key at index `i` is derived with the child path `path/i'`.
+  pub fn derive_ed25519(seed: &Seed, path: &DerivationPath, count: usize) -> Result<Self> {
+    Self::from_iterator(KeyType::Ed25519, derive_ed25519_keypairs(seed, path, count)?)
+  }
+
   /// Returns the [`type`][`KeyType`] of the `KeyCollection` object.
   pub const fn type_(&self) -> KeyType {
     self.type_
@@ -106,9 +134,11 @@ impl KeyCollection {
   }
 
   /// Returns a [`KeyPair`] object for the keys at the specified `index`.
+  ///
+  /// The `KeyPair` holds a [duplicate][SecretKey::duplicate] of the secret key.
   pub fn keypair(&self, index: usize) -> Option<KeyPair> {
     if let (Some(public), Some(secret)) = (self.public.get(index), self.secret.get(index)) {
-      Some((self.type_, public.clone(), secret.clone()).into())
+      Some((self.type_, public.clone(), secret.duplicate()).into())
     } else {
       None
     }
@@ -130,11 +160,15 @@ impl KeyCollection {
   }
 
   /// Returns the Merkle root hash of the public keys in the collection.
+  ///
+  /// The keys are stored in a padded Merkle tree, so collections of any size
+  /// have a proof-of-inclusion for every key. The root is equal to the root
+  /// of the original layout if the size of the collection is a power of two.
   pub fn merkle_root<D>(&self) -> Hash<D>
   where
     D: DigestExt,
   {
-    compute_merkle_root(&self.public)
+    compute_padded_merkle_root(&self.public)
   }
 
   /// Returns a proof-of-inclusion for the public key at the specified index.
@@ -142,7 +176,7 @@ impl KeyCollection {
   where
     D: DigestExt,
   {
-    compute_merkle_proof(&self.public, index)
+    compute_padded_merkle_proof(&self.public, index)
   }
 
   /// Returns a Merkle Key [`SigningKey`] for the key pair at the
@@ -193,7 +227,11 @@ impl IntoIterator for KeyCollection {
   type IntoIter = Zip<IntoIter<PublicKey>, IntoIter<SecretKey>>;
 
   fn into_iter(self) -> Self::IntoIter {
-    self.public.to_vec().into_iter().zip(self.secret.to_vec().into_iter())
+    self
+      .public
+      .into_vec()
+      .into_iter()
+      .zip(self.secret.into_vec().into_iter())
   }
 }
 
@@ -201,6 +239,32 @@ impl IntoIterator for KeyCollection {
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_derive_ed25519() {
+    use crypto::hashes::sha::Sha256;
+
+    let seed: Seed = Seed::new().unwrap();
+    let path: DerivationPath = "m/44'/4218'/0'".parse().unwrap();
+
+    let keys: KeyCollection = KeyCollection::derive_ed25519(&seed, &path, 8).unwrap();
+    let same: KeyCollection = KeyCollection::derive_ed25519(&seed, &path, 8).unwrap();
+
+    assert_eq!(keys.type_(), KeyType::Ed25519);
+    assert_eq!(keys.len(), 8);
+    assert_eq!(keys.merkle_root::<Sha256>(), same.merkle_root::<Sha256>());
+
+    for index in 0..keys.len() {
+      let keypair: KeyPair = KeyPair::derive_ed25519(&seed, &path.child(index as u32).unwrap()).unwrap();
+
+      assert_eq!(keys.public(index).unwrap().as_ref(), keypair.public().as_ref());
+      assert_eq!(keys.secret(index).unwrap().as_ref(), keypair.secret().as_ref());
+    }
+
+    let other: KeyCollection = KeyCollection::derive_ed25519(&Seed::new().unwrap(), &path, 8).unwrap();
+
+    assert_ne!(keys.merkle_root::<Sha256>(), other.merkle_root::<Sha256>());
+  }
+
   #[test]
   fn test_ed25519() {
     let keys: KeyCollection = KeyCollection::new_ed25519(100).unwrap();
@@ -209,7 +273,7 @@ mod tests {
     assert!(!keys.is_empty());
 
     let public: Vec<_> = keys.iter_public().cloned().collect();
-    let secret: Vec<_> = keys.iter_secret().cloned().collect();
+    let secret: Vec<_> = keys.iter_secret().map(SecretKey::duplicate).collect();
 
     assert_eq!(public.len(), keys.len());
     assert_eq!(secret.len(), keys.len());
@@ -225,11 +289,41 @@ mod tests {
     assert_eq!(next.len(), keys.len());
 
     let public: Vec<_> = next.iter_public().cloned().collect();
-    let secret: Vec<_> = next.iter_secret().cloned().collect();
+    let secret: Vec<_> = next.iter_secret().map(SecretKey::duplicate).collect();
 
     for (index, (public, secret)) in public.iter().zip(secret.iter()).enumerate() {
       assert_eq!(public.as_ref(), keys.public(index).unwrap().as_ref());
       assert_eq!(secret.as_ref(), keys.secret(index).unwrap().as_ref());
     }
   }
+
+  #[test]
+  fn test_merkle_proof_odd_size() {
+    use crypto::hashes::sha::Sha256;
+
+    let keys: KeyCollection = KeyCollection::new_ed25519(100).unwrap();
+    let root: Hash<Sha256> = keys.merkle_root();
+
+    for (index, public) in keys.iter_public().enumerate() {
+      let proof: Proof<Sha256> = keys.merkle_proof(index).unwrap();
+
+      assert_eq!(proof.index(), index);
+      assert_eq!(proof.nodes().len(), 7);
+      assert!(proof.verify(&root, public));
+    }
+
+    assert!(keys.merkle_proof::<Sha256>(keys.len()).is_none());
+  }
+
+  #[test]
+  fn test_x25519() {
+    assert!(matches!(
+      KeyCollection::new(KeyType::X25519, 4),
+      Err(Error::InvalidKeyCollectionType(KeyType::X25519))
+    ));
+
+    let keys: KeyCollection = KeyCollection::new_ed25519(4).unwrap();
+
+    assert!(KeyCollection::from_iterator(KeyType::X25519, keys).is_err());
+  }
 }
@@ -6,7 +6,9 @@ use core::fmt::Display;
 use core::fmt::Formatter;
 use core::fmt::Result;
 use std::marker::PhantomData;
+use subtle::ConstantTimeEq;
 use zeroize::Zeroize;
+use zeroize::Zeroizing;
 
 /// A cryptographic key with `Public` components.
 pub type PublicKey = Key<Public>;
@@ -37,12 +39,44 @@ impl private::Sealed for Secret {}
 // =============================================================================
 
 /// A cryptographic key.
-#[derive(Clone)]
+///
+/// The key material is zeroized when dropped. [`SecretKey`]s do not implement
+/// [`Clone`] - use [`SecretKey::duplicate`] or [`SecretKey::export`] to
+/// explicitly copy secret material.
 pub struct Key<V: private::Sealed> {
   key: Box<[u8]>,
   vis: PhantomData<V>,
 }
 
+impl Key<Secret> {
+  /// Returns an explicit copy of the `SecretKey`.
+  ///
+  /// The copy is zeroized when dropped, like the original.
+  pub fn duplicate(&self) -> Self {
+    self.key.to_vec().into()
+  }
+
+  /// Exports the secret key material as bytes that are zeroized when dropped.
+  pub fn export(&self) -> Zeroizing<Vec<u8>> {
+    Zeroizing::new(self.key.to_vec())
+  }
+}
+
+impl Clone for Key<Public> {
+  fn clone(&self) -> Self {
+    self.key.to_vec().into()
+  }
+}
+
+/// Compares the key material in constant time.
+impl<V: private::Sealed> PartialEq for Key<V> {
+  fn eq(&self, other: &Self) -> bool {
+    self.key.ct_eq(&other.key).into()
+  }
+}
+
+impl<V: private::Sealed> Eq for Key<V> {}
+
 impl<V: private::Sealed> Debug for Key<V> {
   fn fmt(&self, f: &mut Formatter<'_>) -> Result {
     f.write_str("Key")
@@ -87,3 +121,33 @@ impl<V: private::Sealed> From<Vec<u8>> for Key<V> {
     other.into_boxed_slice().into()
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_secret_key_eq() {
+    let secret: SecretKey = vec![1, 2, 3].into();
+
+    assert_eq!(secret, secret.duplicate());
+    assert_ne!(secret, SecretKey::from(vec![1, 2, 4]));
+    assert_ne!(secret, SecretKey::from(vec![1, 2]));
+  }
+
+  #[test]
+  fn test_secret_key_export() {
+    let secret: SecretKey = vec![1, 2, 3].into();
+
+    assert_eq!(*secret.export(), [1, 2, 3]);
+    assert_eq!(secret.duplicate().as_ref(), [1, 2, 3]);
+  }
+
+  #[test]
+  fn test_secret_key_redacted() {
+    let secret: SecretKey = vec![0xab; 32].into();
+
+    assert_eq!(format!("{:?}", secret), "Key");
+    assert_eq!(format!("{}", secret), "Key");
+  }
+}
@@ -0,0 +1,125 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::str::FromStr;
+use crypto::keys::bip39::wordlist;
+use zeroize::Zeroize;
+
+use crate::error::Error;
+use crate::error::Result;
+
+/// A [BIP-39] mnemonic phrase using the English wordlist.
+///
+/// [BIP-39]: https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki
+#[derive(Clone, PartialEq, Eq)]
+pub struct Mnemonic(String);
+
+impl Mnemonic {
+  /// The length of the entropy encoded by a new `Mnemonic` (24 words).
+  pub const ENTROPY_LENGTH: usize = 32;
+
+  /// Creates a new random `Mnemonic` of 24 words.
+  pub fn new() -> Result<Self> {
+    let mut entropy: [u8; Self::ENTROPY_LENGTH] = [0; Self::ENTROPY_LENGTH];
+
+    crypto::utils::rand::fill(&mut entropy)?;
+
+    let this: Result<Self> = Self::from_entropy(&entropy);
+
+    entropy.zeroize();
+
+    this
+  }
+
+  /// Creates a new `Mnemonic` encoding the given `entropy`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `entropy` is not 16, 20, 24, 28, or 32 bytes long.
+  pub fn from_entropy(entropy: &[u8]) -> Result<Self> {
+    wordlist::encode(entropy, &wordlist::ENGLISH)
+      .map(Self)
+      .map_err(|_| Error::InvalidMnemonic)
+  }
+
+  /// Returns the mnemonic phrase as a string slice.
+  pub fn as_str(&self) -> &str {
+    &self.0
+  }
+}
+
+impl Debug for Mnemonic {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.write_str("Mnemonic")
+  }
+}
+
+impl Drop for Mnemonic {
+  fn drop(&mut self) {
+    self.0.zeroize();
+  }
+}
+
+impl Zeroize for Mnemonic {
+  fn zeroize(&mut self) {
+    self.0.zeroize();
+  }
+}
+
+impl FromStr for Mnemonic {
+  type Err = Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    // Decoding verifies the words and the checksum of the phrase
+    let mut entropy: Vec<u8> = wordlist::decode(string, &wordlist::ENGLISH).map_err(|_| Error::InvalidMnemonic)?;
+
+    entropy.zeroize();
+
+    Ok(Self(string.to_string()))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_new() {
+    let mnemonic: Mnemonic = Mnemonic::new().unwrap();
+
+    assert_eq!(mnemonic.as_str().split(' ').count(), 24);
+    assert_eq!(mnemonic.as_str().parse::<Mnemonic>().unwrap(), mnemonic);
+  }
+
+  #[test]
+  fn test_from_entropy() {
+    let mnemonic: Mnemonic = Mnemonic::from_entropy(&[0; 16]).unwrap();
+
+    assert_eq!(
+      mnemonic.as_str(),
+      "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+    );
+
+    assert!(Mnemonic::from_entropy(&[0; 15]).is_err());
+  }
+
+  #[test]
+  fn test_parse_invalid() {
+    // Invalid checksum
+    assert!(
+      "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon"
+        .parse::<Mnemonic>()
+        .is_err()
+    );
+
+    // Unknown word
+    assert!(
+      "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon foobar"
+        .parse::<Mnemonic>()
+        .is_err()
+    );
+  }
+}
@@ -5,13 +5,21 @@
 
 mod collection;
 mod key;
+mod mnemonic;
 mod pair;
+mod path;
 mod reference;
+mod seed;
 mod type_;
+mod x25519;
 
 pub use self::collection::KeyCollection;
 pub use self::key::PublicKey;
 pub use self::key::SecretKey;
+pub use self::mnemonic::Mnemonic;
 pub use self::pair::KeyPair;
+pub use self::path::DerivationPath;
 pub use self::reference::KeyRef;
+pub use self::seed::Seed;
 pub use self::type_::KeyType;
+pub use self::x25519::X25519;
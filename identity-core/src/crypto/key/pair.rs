@@ -3,15 +3,24 @@
 
 use zeroize::Zeroize;
 
+use crate::crypto::DerivationPath;
 use crate::crypto::KeyRef;
 use crate::crypto::KeyType;
 use crate::crypto::PublicKey;
 use crate::crypto::SecretKey;
+use crate::crypto::Seed;
 use crate::error::Result;
+use crate::utils::derive_ed25519_keypair;
+use crate::utils::generate_bls12381_keypair;
 use crate::utils::generate_ed25519_keypair;
+use crate::utils::generate_p256_keypair;
+use crate::utils::generate_secp256k1_keypair;
+use crate::utils::generate_x25519_keypair;
 
 /// A convenient type for representing a pair of cryptographic keys.
-#[derive(Clone, Debug)]
+///
+/// The secret key is zeroized when dropped and a `KeyPair` can not be cloned.
+#[derive(Debug)]
 pub struct KeyPair {
   type_: KeyType,
   public: PublicKey,
@@ -28,11 +37,29 @@ impl KeyPair {
   pub fn new(type_: KeyType) -> Result<Self> {
     let (public, secret): (PublicKey, SecretKey) = match type_ {
       KeyType::Ed25519 => generate_ed25519_keypair()?,
+      KeyType::Secp256k1 => generate_secp256k1_keypair()?,
+      KeyType::P256 => generate_p256_keypair()?,
+      KeyType::Bls12381G2 => generate_bls12381_keypair()?,
+      KeyType::X25519 => generate_x25519_keypair()?,
     };
 
     Ok(Self { type_, public, secret })
   }
 
+  /// Derives an [`Ed25519`][`KeyType::Ed25519`] [`KeyPair`] from the given
+  /// `seed` and derivation `path`.
+  ///
+  /// The same `seed` and `path` always derive the same `KeyPair`.
+  pub fn derive_ed25519(seed: &Seed, path: &DerivationPath) -> Result<Self> {
+    let (public, secret): (PublicKey, SecretKey) = derive_ed25519_keypair(seed, path)?;
+
+    Ok(Self {
+      type_: KeyType::Ed25519,
+      public,
+      secret,
+    })
+  }
+
   /// Returns the [`type`][`KeyType`] of the `KeyPair` object.
   pub const fn type_(&self) -> KeyType {
     self.type_
@@ -87,6 +114,33 @@ impl From<(KeyType, PublicKey, SecretKey)> for KeyPair {
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_derive_ed25519() {
+    // https://github.com/satoshilabs/slips/blob/master/slip-0010.md#test-vector-1-for-ed25519
+    let seed: Seed = Seed::from_bytes(&hex::decode("000102030405060708090a0b0c0d0e0f").unwrap()).unwrap();
+
+    let keypair: KeyPair = KeyPair::derive_ed25519(&seed, &DerivationPath::master()).unwrap();
+    assert_eq!(keypair.type_(), KeyType::Ed25519);
+    assert_eq!(
+      hex::encode(keypair.secret()),
+      "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"
+    );
+    assert_eq!(
+      hex::encode(keypair.public()),
+      "a4b2856bfec510abab89753fac1ac0e1112364e7d250545963f135f2a33188ed"
+    );
+
+    let keypair: KeyPair = KeyPair::derive_ed25519(&seed, &"m/0'".parse().unwrap()).unwrap();
+    assert_eq!(
+      hex::encode(keypair.secret()),
+      "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3"
+    );
+    assert_eq!(
+      hex::encode(keypair.public()),
+      "8c8a13df77a28f3445213a0f432fde644acaa215fc72dcdf300d5efaa85d350c"
+    );
+  }
+
   #[test]
   fn test_new_ed25519() {
     let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
@@ -94,4 +148,22 @@ mod tests {
     assert_eq!(keypair.public().as_ref().len(), 32);
     assert_eq!(keypair.secret().as_ref().len(), 32);
   }
+
+  #[cfg(feature = "secp256k1")]
+  #[test]
+  fn test_new_secp256k1() {
+    let keypair: KeyPair = KeyPair::new(KeyType::Secp256k1).unwrap();
+    assert_eq!(keypair.type_(), KeyType::Secp256k1);
+    assert_eq!(keypair.public().as_ref().len(), 33);
+    assert_eq!(keypair.secret().as_ref().len(), 32);
+  }
+
+  #[cfg(feature = "p256")]
+  #[test]
+  fn test_new_p256() {
+    let keypair: KeyPair = KeyPair::new(KeyType::P256).unwrap();
+    assert_eq!(keypair.type_(), KeyType::P256);
+    assert_eq!(keypair.public().as_ref().len(), 33);
+    assert_eq!(keypair.secret().as_ref().len(), 32);
+  }
 }
@@ -0,0 +1,138 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::str::FromStr;
+
+use crate::error::Error;
+use crate::error::Result;
+
+/// A [SLIP-10] derivation path, e.g. `m/44'/4218'/0'/0'`.
+///
+/// Ed25519 keys can only be derived with hardened child indexes - all
+/// indexes of the path are hardened.
+///
+/// [SLIP-10]: https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DerivationPath(Vec<u32>);
+
+impl DerivationPath {
+  /// The index of the first hardened child key.
+  pub const HARDENED: u32 = 1 << 31;
+
+  /// Creates a new `DerivationPath` referencing the master key.
+  pub const fn master() -> Self {
+    Self(Vec::new())
+  }
+
+  /// Creates a new `DerivationPath` from the given (non-hardened) child `indexes`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if any index is not below [`DerivationPath::HARDENED`].
+  pub fn new(indexes: impl IntoIterator<Item = u32>) -> Result<Self> {
+    indexes
+      .into_iter()
+      .try_fold(Self::master(), |path, index| path.child(index))
+  }
+
+  /// Returns a new `DerivationPath` referencing the child key at `index`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `index` is not below [`DerivationPath::HARDENED`].
+  pub fn child(&self, index: u32) -> Result<Self> {
+    if index >= Self::HARDENED {
+      return Err(Error::InvalidDerivationPath);
+    }
+
+    let mut indexes: Vec<u32> = self.0.clone();
+
+    indexes.push(index);
+
+    Ok(Self(indexes))
+  }
+
+  /// Returns the (non-hardened) child indexes of the path.
+  pub fn indexes(&self) -> &[u32] {
+    &self.0
+  }
+}
+
+impl Display for DerivationPath {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.write_str("m")?;
+
+    for index in self.0.iter() {
+      write!(f, "/{}'", index)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl FromStr for DerivationPath {
+  type Err = Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    let mut segments = string.split('/');
+
+    if segments.next() != Some("m") {
+      return Err(Error::InvalidDerivationPath);
+    }
+
+    segments.try_fold(Self::master(), |path, segment| {
+      let index: &str = segment
+        .strip_suffix('\'')
+        .or_else(|| segment.strip_suffix('h'))
+        .ok_or(Error::InvalidDerivationPath)?;
+
+      // Reject signs and whitespace accepted by `u32::from_str`
+      if !index.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(Error::InvalidDerivationPath);
+      }
+
+      path.child(index.parse().map_err(|_| Error::InvalidDerivationPath)?)
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_display() {
+    let path: DerivationPath = "m/44'/4218'/0'/1h".parse().unwrap();
+
+    assert_eq!(path.indexes(), [44, 4218, 0, 1]);
+    assert_eq!(path.to_string(), "m/44'/4218'/0'/1'");
+    assert_eq!(path, DerivationPath::new(vec![44, 4218, 0, 1]).unwrap());
+    assert_eq!("m".parse::<DerivationPath>().unwrap(), DerivationPath::master());
+  }
+
+  #[test]
+  fn test_parse_invalid() {
+    for input in [
+      "",
+      "44'/0'",
+      "m/",
+      "m/0",
+      "m/0'/1",
+      "m/+1'",
+      "m/x'",
+      "m/2147483648'",
+      "M/0'",
+    ] {
+      assert!(input.parse::<DerivationPath>().is_err(), "{}", input);
+    }
+  }
+
+  #[test]
+  fn test_child_hardened() {
+    assert!(DerivationPath::master().child(DerivationPath::HARDENED - 1).is_ok());
+    assert!(DerivationPath::master().child(DerivationPath::HARDENED).is_err());
+  }
+}
@@ -0,0 +1,117 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use crypto::keys::bip39;
+use zeroize::Zeroize;
+
+use crate::crypto::Mnemonic;
+use crate::error::Error;
+use crate::error::Result;
+
+/// A seed used to derive hierarchical deterministic keys with [SLIP-10].
+///
+/// [SLIP-10]: https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+#[derive(Clone)]
+pub struct Seed(Box<[u8]>);
+
+impl Seed {
+  /// The minimum length of a seed in bytes.
+  pub const MIN_LENGTH: usize = 16;
+
+  /// The maximum length of a seed in bytes.
+  pub const MAX_LENGTH: usize = 64;
+
+  /// Creates a new random `Seed` of [`Seed::MAX_LENGTH`] bytes.
+  pub fn new() -> Result<Self> {
+    let mut bytes: Vec<u8> = vec![0; Self::MAX_LENGTH];
+
+    crypto::utils::rand::fill(&mut bytes)?;
+
+    Ok(Self(bytes.into_boxed_slice()))
+  }
+
+  /// Creates a new `Seed` from the given `bytes`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `bytes` is not between [`Seed::MIN_LENGTH`] and
+  /// [`Seed::MAX_LENGTH`] bytes long.
+  pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+    if bytes.len() < Self::MIN_LENGTH || bytes.len() > Self::MAX_LENGTH {
+      return Err(Error::InvalidSeedLength(bytes.len()));
+    }
+
+    Ok(Self(bytes.into()))
+  }
+
+  /// Creates a new `Seed` from a [BIP-39] `mnemonic` and optional `passphrase`.
+  ///
+  /// [BIP-39]: https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki
+  pub fn from_mnemonic(mnemonic: &Mnemonic, passphrase: &str) -> Self {
+    let mut bytes: [u8; Self::MAX_LENGTH] = [0; Self::MAX_LENGTH];
+
+    bip39::mnemonic_to_seed(mnemonic.as_str(), passphrase, &mut bytes);
+
+    let this: Self = Self(bytes.to_vec().into_boxed_slice());
+
+    bytes.zeroize();
+
+    this
+  }
+}
+
+impl Debug for Seed {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.write_str("Seed")
+  }
+}
+
+impl Drop for Seed {
+  fn drop(&mut self) {
+    self.0.zeroize();
+  }
+}
+
+impl Zeroize for Seed {
+  fn zeroize(&mut self) {
+    self.0.zeroize();
+  }
+}
+
+impl AsRef<[u8]> for Seed {
+  fn as_ref(&self) -> &[u8] {
+    &self.0
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_from_bytes() {
+    assert!(Seed::from_bytes(&[0; 15]).is_err());
+    assert!(Seed::from_bytes(&[0; 16]).is_ok());
+    assert!(Seed::from_bytes(&[0; 64]).is_ok());
+    assert!(Seed::from_bytes(&[0; 65]).is_err());
+  }
+
+  #[test]
+  fn test_from_mnemonic() {
+    // https://github.com/trezor/python-mnemonic/blob/master/vectors.json
+    let mnemonic: Mnemonic =
+      "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+        .parse()
+        .unwrap();
+
+    let seed: Seed = Seed::from_mnemonic(&mnemonic, "TREZOR");
+
+    assert_eq!(
+      hex::encode(seed.as_ref()),
+      "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+    );
+  }
+}
@@ -3,10 +3,13 @@
 
 use core::str::FromStr;
 
+use crate::crypto::bbs::BbsBls12381;
 use crate::crypto::merkle_key::MerkleDigest;
 use crate::crypto::merkle_key::MerkleKey;
 use crate::crypto::merkle_tree::Hash;
 use crate::crypto::Ed25519;
+use crate::crypto::Secp256k1;
+use crate::crypto::P256;
 use crate::error::Error;
 use crate::error::Result;
 
@@ -16,6 +19,18 @@ pub enum KeyType {
   /// Identifies an `Ed25519` public/secret key.
   #[serde(rename = "ed25519")]
   Ed25519,
+  /// Identifies a `Secp256k1` public/secret key.
+  #[serde(rename = "secp256k1")]
+  Secp256k1,
+  /// Identifies a `NIST P-256` public/secret key.
+  #[serde(rename = "p256")]
+  P256,
+  /// Identifies a `BLS12-381` G2 public/secret key for `BBS+` signatures.
+  #[serde(rename = "bls12381g2")]
+  Bls12381G2,
+  /// Identifies an `X25519` public/secret key for key agreement.
+  #[serde(rename = "x25519")]
+  X25519,
 }
 
 impl KeyType {
@@ -23,16 +38,30 @@ impl KeyType {
   pub const fn as_str(&self) -> &'static str {
     match self {
       Self::Ed25519 => "ed25519",
+      Self::Secp256k1 => "secp256k1",
+      Self::P256 => "p256",
+      Self::Bls12381G2 => "bls12381g2",
+      Self::X25519 => "x25519",
     }
   }
 
-  /// Creates a DID Document public key value for the given Merkle `root`.
+  /// Creates a DID Document public key value for the given padded Merkle `root`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the key type is [`KeyType::X25519`], which is not a signature
+  /// algorithm. A [`KeyCollection`][crate::crypto::KeyCollection] never holds
+  /// `X25519` keys.
   pub fn encode_merkle_key<D>(&self, root: &Hash<D>) -> Vec<u8>
   where
     D: MerkleDigest,
   {
     match self {
-      Self::Ed25519 => MerkleKey::encode_key::<D, Ed25519>(root),
+      Self::Ed25519 => MerkleKey::encode_padded_key::<D, Ed25519>(root),
+      Self::Secp256k1 => MerkleKey::encode_padded_key::<D, Secp256k1>(root),
+      Self::P256 => MerkleKey::encode_padded_key::<D, P256>(root),
+      Self::Bls12381G2 => MerkleKey::encode_padded_key::<D, BbsBls12381>(root),
+      Self::X25519 => panic!("x25519 keys cannot be used in a merkle key collection"),
     }
   }
 }
@@ -43,6 +72,14 @@ impl FromStr for KeyType {
   fn from_str(string: &str) -> Result<Self, Self::Err> {
     if string.eq_ignore_ascii_case("ed25519") {
       Ok(Self::Ed25519)
+    } else if string.eq_ignore_ascii_case("secp256k1") {
+      Ok(Self::Secp256k1)
+    } else if string.eq_ignore_ascii_case("p256") {
+      Ok(Self::P256)
+    } else if string.eq_ignore_ascii_case("bls12381g2") {
+      Ok(Self::Bls12381G2)
+    } else if string.eq_ignore_ascii_case("x25519") {
+      Ok(Self::X25519)
     } else {
       Err(Error::InvalidKeyFormat)
     }
@@ -0,0 +1,134 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::TryInto;
+use crypto::hashes::sha::Sha512;
+use crypto::hashes::Digest;
+use crypto::hashes::Output;
+use crypto::keys::x25519;
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use zeroize::Zeroize;
+
+use crate::error::Error;
+use crate::error::Result;
+
+/// The length of an `X25519` public key.
+pub(crate) const X25519_PUBLIC_KEY_LENGTH: usize = 32;
+
+/// The length of an `X25519` secret key.
+pub(crate) const X25519_SECRET_KEY_LENGTH: usize = 32;
+
+/// The length of an `X25519` shared secret.
+pub(crate) const X25519_SHARED_SECRET_LENGTH: usize = 32;
+
+// The length of an `Ed25519` public key and secret key (seed).
+const ED25519_KEY_LENGTH: usize = 32;
+
+/// An implementation of `X25519` Elliptic-curve Diffie-Hellman (ECDH) key agreement.
+#[derive(Clone, Copy, Debug)]
+pub struct X25519;
+
+impl X25519 {
+  /// Performs Diffie-Hellman key agreement with the `secret` key of the local
+  /// party and the `public` key of the remote party.
+  pub fn key_exchange<T, U>(secret: &T, public: &U) -> Result<[u8; X25519_SHARED_SECRET_LENGTH]>
+  where
+    T: AsRef<[u8]> + ?Sized,
+    U: AsRef<[u8]> + ?Sized,
+  {
+    let secret: x25519::SecretKey = parse_secret(secret.as_ref())?;
+    let public: x25519::PublicKey = parse_public(public.as_ref())?;
+
+    Ok(*secret.diffie_hellman(&public).as_bytes())
+  }
+
+  /// Converts an `Ed25519` public key to an `X25519` public key.
+  pub fn ed25519_to_x25519_public<T>(public: &T) -> Result<[u8; X25519_PUBLIC_KEY_LENGTH]>
+  where
+    T: AsRef<[u8]> + ?Sized,
+  {
+    let public: &[u8] = public.as_ref();
+
+    let ed25519: [u8; ED25519_KEY_LENGTH] = public
+      .try_into()
+      .map_err(|_| Error::InvalidKeyLength(public.len(), ED25519_KEY_LENGTH))?;
+
+    CompressedEdwardsY(ed25519)
+      .decompress()
+      .map(|edwards| edwards.to_montgomery().0)
+      .ok_or(Error::InvalidKeyFormat)
+  }
+
+  /// Converts an `Ed25519` secret key to an `X25519` secret key.
+  pub fn ed25519_to_x25519_secret<T>(secret: &T) -> Result<[u8; X25519_SECRET_KEY_LENGTH]>
+  where
+    T: AsRef<[u8]> + ?Sized,
+  {
+    let secret: &[u8] = secret.as_ref();
+
+    if secret.len() != ED25519_KEY_LENGTH {
+      return Err(Error::InvalidKeyLength(secret.len(), ED25519_KEY_LENGTH));
+    }
+
+    let mut hash: Output<Sha512> = Sha512::digest(secret);
+    let mut output: [u8; X25519_SECRET_KEY_LENGTH] = [0; X25519_SECRET_KEY_LENGTH];
+
+    output.copy_from_slice(&hash[..X25519_SECRET_KEY_LENGTH]);
+    output[0] &= 248;
+    output[31] &= 127;
+    output[31] |= 64;
+
+    hash.as_mut_slice().zeroize();
+
+    Ok(output)
+  }
+}
+
+fn parse_public(slice: &[u8]) -> Result<x25519::PublicKey> {
+  if slice.len() != X25519_PUBLIC_KEY_LENGTH {
+    return Err(Error::InvalidKeyLength(slice.len(), X25519_PUBLIC_KEY_LENGTH));
+  }
+
+  x25519::PublicKey::from_bytes(slice).map_err(|_| Error::InvalidKeyFormat)
+}
+
+fn parse_secret(slice: &[u8]) -> Result<x25519::SecretKey> {
+  if slice.len() != X25519_SECRET_KEY_LENGTH {
+    return Err(Error::InvalidKeyLength(slice.len(), X25519_SECRET_KEY_LENGTH));
+  }
+
+  x25519::SecretKey::from_bytes(slice).map_err(|_| Error::InvalidKeyFormat)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::crypto::KeyPair;
+  use crate::crypto::KeyType;
+  use crate::crypto::X25519;
+
+  #[test]
+  fn test_key_exchange() {
+    let alice: KeyPair = KeyPair::new(KeyType::X25519).unwrap();
+    let bob: KeyPair = KeyPair::new(KeyType::X25519).unwrap();
+
+    let shared1: [u8; 32] = X25519::key_exchange(alice.secret(), bob.public()).unwrap();
+    let shared2: [u8; 32] = X25519::key_exchange(bob.secret(), alice.public()).unwrap();
+
+    assert_eq!(shared1, shared2);
+  }
+
+  #[test]
+  fn test_ed25519_to_x25519() {
+    let ed25519: KeyPair = KeyPair::new(KeyType::Ed25519).unwrap();
+    let remote: KeyPair = KeyPair::new(KeyType::X25519).unwrap();
+
+    let public: [u8; 32] = X25519::ed25519_to_x25519_public(ed25519.public()).unwrap();
+    let secret: [u8; 32] = X25519::ed25519_to_x25519_secret(ed25519.secret()).unwrap();
+
+    // The converted keys should agree on a shared secret with a remote party
+    let shared1: [u8; 32] = X25519::key_exchange(&secret, remote.public()).unwrap();
+    let shared2: [u8; 32] = X25519::key_exchange(remote.secret(), &public).unwrap();
+
+    assert_eq!(shared1, shared2);
+  }
+}
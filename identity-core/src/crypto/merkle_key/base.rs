@@ -20,7 +20,17 @@ impl MerkleKey {
   /// The `type` value of a Merkle Key Collection Signature.
   pub const TYPE_SIG: &'static str = "MerkleKeySignature2021";
 
+  /// The bit of the digest algorithm tag marking a public key value with the
+  /// roots of padded Merkle trees.
+  ///
+  /// Public key values without the bit hold a root of the original layout.
+  /// Inclusion proofs are verified in the same way for both layouts.
+  pub const TAG_PADDED: u8 = 0x80;
+
   /// Extracts the signature and digest algorithm tags from the public key value.
+  ///
+  /// The layout bit is not part of the returned digest algorithm tag, see
+  /// [`MerkleKey::is_padded`].
   pub fn extract_tags(data: &[u8]) -> Result<(MerkleSignatureTag, MerkleDigestTag)> {
     let tag_s: MerkleSignatureTag = Self::signature_tag(data, 0)?;
     let tag_d: MerkleDigestTag = Self::digest_tag(data, 1)?;
@@ -28,7 +38,19 @@ impl MerkleKey {
     Ok((tag_s, tag_d))
   }
 
-  /// Creates a DID Document public key value for the given Merkle tree `root`.
+  /// Returns `true` if the public key value holds the roots of padded Merkle
+  /// trees.
+  pub fn is_padded(data: &[u8]) -> Result<bool> {
+    data
+      .get(1)
+      .map(|tag| tag & Self::TAG_PADDED != 0)
+      .ok_or(Error::InvalidMerkleDigestKeyTag(None))
+  }
+
+  /// Creates a DID Document public key value for the given Merkle tree `root`
+  /// of the original layout.
+  ///
+  /// See [`MerkleKey::encode_padded_key`] for the roots of padded Merkle trees.
   pub fn encode_key<D, S>(root: &Hash<D>) -> Vec<u8>
   where
     D: MerkleDigest,
@@ -41,10 +63,58 @@ impl MerkleKey {
     output
   }
 
+  /// Creates a DID Document public key value for the given padded Merkle tree
+  /// `root`, e.g. the root of a [`KeyCollection`][crate::crypto::KeyCollection].
+  pub fn encode_padded_key<D, S>(root: &Hash<D>) -> Vec<u8>
+  where
+    D: MerkleDigest,
+    S: MerkleSignature,
+  {
+    Self::encode_forest_key::<D, S>(core::slice::from_ref(root))
+  }
+
+  /// Creates a DID Document public key value for the given padded Merkle tree
+  /// `roots`, e.g. the roots of a growing
+  /// [`Forest`][crate::crypto::merkle_tree::Forest].
+  ///
+  /// A key value with a single root is equivalent to [`MerkleKey::encode_padded_key`].
+  pub fn encode_forest_key<D, S>(roots: &[Hash<D>]) -> Vec<u8>
+  where
+    D: MerkleDigest,
+    S: MerkleSignature,
+  {
+    let mut output: Vec<u8> = Vec::with_capacity(2 + D::OUTPUT_SIZE * roots.len());
+    output.push(S::TAG.into());
+    output.push(u8::from(D::TAG) | Self::TAG_PADDED);
+
+    for root in roots {
+      output.extend_from_slice(root.as_slice());
+    }
+
+    output
+  }
+
+  /// Extracts the Merkle tree root hashes from the public key value.
+  pub fn extract_roots<D>(data: &[u8]) -> Result<Vec<Hash<D>>>
+  where
+    D: MerkleDigest,
+  {
+    let roots: &[u8] = data.get(2..).ok_or(Error::InvalidKeyFormat)?;
+
+    if roots.is_empty() || roots.len() % D::OUTPUT_SIZE != 0 {
+      return Err(Error::InvalidKeyFormat);
+    }
+
+    roots
+      .chunks(D::OUTPUT_SIZE)
+      .map(|root| Hash::from_slice(root).ok_or(Error::InvalidKeyFormat))
+      .collect()
+  }
+
   fn digest_tag(data: &[u8], index: usize) -> Result<MerkleDigestTag> {
     data
       .get(index)
-      .copied()
+      .map(|tag| tag & !Self::TAG_PADDED)
       .map(MerkleDigestTag::new)
       .ok_or(Error::InvalidMerkleDigestKeyTag(None))
   }
@@ -60,6 +130,7 @@ impl MerkleKey {
 
 #[cfg(test)]
 mod tests {
+  use crate::crypto::bbs::BbsBls12381;
   use crate::crypto::merkle_key::Blake2b256;
   use crate::crypto::merkle_key::MerkleDigest;
   use crate::crypto::merkle_key::MerkleDigestTag;
@@ -69,6 +140,8 @@ mod tests {
   use crate::crypto::merkle_key::Sha256;
   use crate::crypto::merkle_tree::Hash;
   use crate::crypto::Ed25519;
+  use crate::crypto::Secp256k1;
+  use crate::crypto::P256;
 
   fn assert_tag<D, S>()
   where
@@ -82,6 +155,19 @@ mod tests {
 
     assert_eq!(tags.0, S::TAG);
     assert_eq!(tags.1, D::TAG);
+    assert!(!MerkleKey::is_padded(&data).unwrap());
+
+    // The layout bit is not part of the digest algorithm tag
+    let data: Vec<u8> = MerkleKey::encode_padded_key::<D, S>(&root);
+    let tags: (MerkleSignatureTag, MerkleDigestTag) = MerkleKey::extract_tags(&data).unwrap();
+
+    assert_eq!(tags.0, S::TAG);
+    assert_eq!(tags.1, D::TAG);
+    assert!(MerkleKey::is_padded(&data).unwrap());
+    assert_eq!(
+      MerkleKey::extract_roots::<D>(&data).unwrap()[0].as_slice(),
+      root.as_slice()
+    );
   }
 
   #[test]
@@ -93,4 +179,22 @@ mod tests {
   fn test_sha256_tag() {
     assert_tag::<Sha256, Ed25519>();
   }
+
+  #[test]
+  fn test_secp256k1_tag() {
+    assert_tag::<Sha256, Secp256k1>();
+    assert_tag::<Blake2b256, Secp256k1>();
+  }
+
+  #[test]
+  fn test_p256_tag() {
+    assert_tag::<Sha256, P256>();
+    assert_tag::<Blake2b256, P256>();
+  }
+
+  #[test]
+  fn test_bbs_bls12381_tag() {
+    assert_tag::<Sha256, BbsBls12381>();
+    assert_tag::<Blake2b256, BbsBls12381>();
+  }
 }
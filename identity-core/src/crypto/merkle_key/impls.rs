@@ -7,11 +7,14 @@ pub use crypto::hashes::sha::Sha256;
 #[doc(inline)]
 pub use crypto::hashes::blake2b::Blake2b256;
 
+use crate::crypto::bbs::BbsBls12381;
 use crate::crypto::merkle_key::MerkleDigest;
 use crate::crypto::merkle_key::MerkleDigestTag;
 use crate::crypto::merkle_key::MerkleSignature;
 use crate::crypto::merkle_key::MerkleSignatureTag;
 use crate::crypto::Ed25519;
+use crate::crypto::Secp256k1;
+use crate::crypto::P256;
 
 // Add support for using SHA-256 as a Merkle Key Collection digest algorithm.
 impl MerkleDigest for Sha256 {
@@ -27,3 +30,18 @@ impl MerkleDigest for Blake2b256 {
 impl<T: ?Sized> MerkleSignature for Ed25519<T> {
   const TAG: MerkleSignatureTag = MerkleSignatureTag::ED25519;
 }
+
+// Add support for using Secp256k1 as a Merkle Key Collection signature algorithm.
+impl<T: ?Sized> MerkleSignature for Secp256k1<T> {
+  const TAG: MerkleSignatureTag = MerkleSignatureTag::SECP256K1;
+}
+
+// Add support for using NIST P-256 as a Merkle Key Collection signature algorithm.
+impl<T: ?Sized> MerkleSignature for P256<T> {
+  const TAG: MerkleSignatureTag = MerkleSignatureTag::P256;
+}
+
+// Add support for using BBS+ as a Merkle Key Collection signature algorithm.
+impl<T: ?Sized> MerkleSignature for BbsBls12381<T> {
+  const TAG: MerkleSignatureTag = MerkleSignatureTag::BBS_BLS12381;
+}
@@ -40,6 +40,15 @@ impl MerkleSignatureTag {
   /// A Merkle Key Collection tag specifying `Ed25519` as the signature algorithm.
   pub const ED25519: Self = Self::new(0x0);
 
+  /// A Merkle Key Collection tag specifying `Secp256k1` as the signature algorithm.
+  pub const SECP256K1: Self = Self::new(0x1);
+
+  /// A Merkle Key Collection tag specifying `NIST P-256` as the signature algorithm.
+  pub const P256: Self = Self::new(0x2);
+
+  /// A Merkle Key Collection tag specifying `BBS+` over `BLS12-381` as the signature algorithm.
+  pub const BBS_BLS12381: Self = Self::new(0x3);
+
   /// Creates a new [`MerkleSignatureTag`]   object.
   pub const fn new(tag: u8) -> Self {
     Self(tag)
@@ -8,6 +8,7 @@ use rand::Rng;
 use crate::common::BitSet;
 use crate::crypto::merkle_key::Blake2b256;
 use crate::crypto::merkle_key::MerkleDigest;
+use crate::crypto::merkle_key::MerkleKey;
 use crate::crypto::merkle_key::MerkleSignature;
 use crate::crypto::merkle_key::MerkleSignatureTag;
 use crate::crypto::merkle_key::MerkleSigner;
@@ -15,6 +16,8 @@ use crate::crypto::merkle_key::MerkleVerifier;
 use crate::crypto::merkle_key::Sha256;
 use crate::crypto::merkle_key::SigningKey;
 use crate::crypto::merkle_key::VerificationKey;
+use crate::crypto::merkle_tree::Forest;
+use crate::crypto::merkle_tree::Proof;
 use crate::crypto::Ed25519;
 use crate::crypto::KeyCollection;
 use crate::crypto::PublicKey;
@@ -46,7 +49,7 @@ where
   assert_eq!(S::TAG, MerkleSignatureTag::ED25519);
 
   let input: &[u8] = b"IOTA Identity";
-  let total: usize = 1 << OsRng.gen_range(6..10);
+  let total: usize = OsRng.gen_range(64..1 << 10);
   let index: usize = OsRng.gen_range(0..total);
 
   let keys: KeyCollection = KeyCollection::new_ed25519(total).unwrap();
@@ -108,3 +111,54 @@ fn test_sign_verify_sha256_ed25519() {
 fn test_sign_verify_blake2b_ed25519() {
   __test_sign_verify::<Blake2b256, Ed25519>();
 }
+
+#[test]
+fn test_sign_verify_forest() {
+  let input: &[u8] = b"IOTA Identity";
+
+  let batch1: KeyCollection = KeyCollection::new_ed25519(5).unwrap();
+  let batch2: KeyCollection = KeyCollection::new_ed25519(8).unwrap();
+
+  let mut forest: Forest<Sha256> = Forest::new(3).unwrap();
+
+  assert_eq!(forest.push(&batch1[..]), Some(0));
+
+  // A forest with a single tree is encoded as the Merkle key of the tree
+  let mkey: Vec<u8> = MerkleKey::encode_forest_key::<Sha256, Ed25519>(&forest.roots());
+
+  assert_eq!(mkey, batch1.encode_merkle_key::<Sha256>());
+
+  let proof1: Proof<Sha256> = forest.proof(2).unwrap();
+  let skey1: SigningKey<'_, Sha256> = SigningKey::from_borrowed(&batch1[2], batch1.secret(2).unwrap(), &proof1);
+  let signature1: SignatureValue = MerkleSigner::<Sha256, Ed25519>::sign(&input, &skey1).unwrap();
+
+  // Grow the forest with a new batch of keys
+  assert_eq!(forest.push(&batch2[..]), Some(8));
+
+  let mkey: Vec<u8> = MerkleKey::encode_forest_key::<Sha256, Ed25519>(&forest.roots());
+
+  let proof2: Proof<Sha256> = forest.proof(8 + 4).unwrap();
+  let skey2: SigningKey<'_, Sha256> = SigningKey::from_borrowed(&batch2[4], batch2.secret(4).unwrap(), &proof2);
+  let signature2: SignatureValue = MerkleSigner::<Sha256, Ed25519>::sign(&input, &skey2).unwrap();
+
+  // Signatures created before the forest grew are still valid
+  let vkey: VerificationKey<'_> = VerificationKey::from_borrowed(&mkey);
+
+  assert!(MerkleVerifier::<Sha256, Ed25519>::verify(&input, &signature1, &vkey).is_ok());
+  assert!(MerkleVerifier::<Sha256, Ed25519>::verify(&input, &signature2, &vkey).is_ok());
+
+  // Keys are revoked by their index in the forest
+  let mut revocation: BitSet = BitSet::new();
+  revocation.insert(8 + 4);
+  let mut vkey: VerificationKey<'_> = VerificationKey::from_borrowed(&mkey);
+  vkey.set_revocation(&revocation);
+
+  assert!(MerkleVerifier::<Sha256, Ed25519>::verify(&input, &signature1, &vkey).is_ok());
+  assert!(MerkleVerifier::<Sha256, Ed25519>::verify(&input, &signature2, &vkey).is_err());
+
+  // Keys of later trees can't be verified with the previous key value
+  let mkey: Vec<u8> = MerkleKey::encode_forest_key::<Sha256, Ed25519>(&forest.roots()[..1]);
+  let vkey: VerificationKey<'_> = VerificationKey::from_borrowed(&mkey);
+
+  assert!(MerkleVerifier::<Sha256, Ed25519>::verify(&input, &signature2, &vkey).is_err());
+}
@@ -1,6 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use core::convert::TryFrom;
 use core::marker::PhantomData;
 use serde::Serialize;
 use std::borrow::Cow;
@@ -12,6 +13,7 @@ use crate::crypto::merkle_key::MerkleDigestTag;
 use crate::crypto::merkle_key::MerkleKey;
 use crate::crypto::merkle_key::MerkleSignature;
 use crate::crypto::merkle_key::MerkleSignatureTag;
+use crate::crypto::merkle_tree::Forest;
 use crate::crypto::merkle_tree::Hash;
 use crate::crypto::merkle_tree::Proof;
 use crate::crypto::Named;
@@ -95,20 +97,21 @@ where
 
     let (target, proof, signature): _ = expand_signature_value(signature)?;
 
-    let merkle_root: Hash<D> = decompose_public_key::<D, S>(public)?;
+    let merkle_roots: Vec<Hash<D>> = decompose_public_key::<D, S>(public)?;
     let merkle_proof: Proof<D> = Proof::decode(&proof).ok_or(Error::InvalidProofFormat)?;
     let target_hash: Hash<D> = digest.hash_leaf(target.as_ref());
 
     // Ensure the target hash of the user-provided public key is part
-    // of the Merkle tree
-    if !merkle_proof.verify(&merkle_root, target_hash) {
-      return Err(Error::InvalidProofValue("merkle key - bad proof"));
-    }
+    // of one of the Merkle trees
+    let index: usize = Forest::verify_hash(&merkle_roots, &merkle_proof, target_hash)
+      .ok_or(Error::InvalidProofValue("merkle key - bad proof"))?;
 
     // If a set of revocation flags was provided, ensure the public key
     // was not revoked
     if let Some(revocation) = public.revocation {
-      if revocation.contains(merkle_proof.index() as u32) {
+      let index: u32 = u32::try_from(index).map_err(|_| Error::InvalidProofValue("merkle key - bad index"))?;
+
+      if revocation.contains(index) {
         return Err(Error::InvalidProofValue("merkle key - revoked"));
       }
     }
@@ -123,7 +126,7 @@ where
 // =============================================================================
 // =============================================================================
 
-fn decompose_public_key<D, S>(key: &VerificationKey<'_>) -> Result<Hash<D>>
+fn decompose_public_key<D, S>(key: &VerificationKey<'_>) -> Result<Vec<Hash<D>>>
 where
   D: MerkleDigest,
   S: MerkleSignature,
@@ -140,12 +143,8 @@ where
     return Err(Error::InvalidMerkleDigestKeyTag(Some(tag_d)));
   }
 
-  // Extract and return the Merkle root hash
-  key
-    .merkle_key
-    .get(2..)
-    .and_then(Hash::from_slice)
-    .ok_or(Error::InvalidKeyFormat)
+  // Extract and return the Merkle root hashes
+  MerkleKey::extract_roots(&key.merkle_key)
 }
 
 fn expand_signature_value(signature: &SignatureValue) -> Result<(PublicKey, Vec<u8>, Vec<u8>)> {
@@ -0,0 +1,233 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::TryFrom;
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::fmt::Result;
+use subtle::Choice;
+use subtle::ConditionallySelectable;
+use subtle::ConstantTimeEq;
+
+use crate::crypto::merkle_tree::merkle::compute_merkle_proof_sized;
+use crate::crypto::merkle_tree::merkle::compute_merkle_root_sized;
+use crate::crypto::merkle_tree::AsLeaf;
+use crate::crypto::merkle_tree::DigestExt;
+use crate::crypto::merkle_tree::Hash;
+use crate::crypto::merkle_tree::Proof;
+
+/// An append-only sequence of fixed-height Merkle trees.
+///
+/// Leaves are appended in batches, each batch forming a new tree with room for
+/// [`capacity`][Forest::capacity] leaves. Trees never change once appended, so
+/// proofs for existing leaves stay valid as the forest grows - at the cost of
+/// one root hash per tree.
+///
+/// The leaf at position `index` of tree `tree` has the global index
+/// `tree * capacity + index`.
+pub struct Forest<D: DigestExt> {
+  height: usize,
+  trees: Vec<Box<[Hash<D>]>>,
+}
+
+impl<D: DigestExt> Forest<D> {
+  /// Creates a new empty `Forest` of trees with the given `height`.
+  ///
+  /// Returns `None` if trees of the given `height` can't be indexed.
+  pub fn new(height: usize) -> Option<Self> {
+    if height >= usize::BITS as usize {
+      return None;
+    }
+
+    Some(Self {
+      height,
+      trees: Vec::new(),
+    })
+  }
+
+  /// Returns the height of each tree in the forest.
+  pub fn height(&self) -> usize {
+    self.height
+  }
+
+  /// Returns the maximum number of leaves in each tree of the forest.
+  pub fn capacity(&self) -> usize {
+    1 << self.height
+  }
+
+  /// Returns the number of trees in the forest.
+  pub fn trees(&self) -> usize {
+    self.trees.len()
+  }
+
+  /// Returns the number of leaves in the forest.
+  pub fn len(&self) -> usize {
+    self.trees.iter().map(|tree| tree.len()).sum()
+  }
+
+  /// Returns `true` if the forest has no leaves.
+  pub fn is_empty(&self) -> bool {
+    self.trees.is_empty()
+  }
+
+  /// Appends a new tree of the given `leaves` to the forest, returning the
+  /// global index of the first leaf.
+  ///
+  /// Returns `None` if `leaves` is empty or exceeds the tree capacity.
+  pub fn push<L>(&mut self, leaves: &[L]) -> Option<usize>
+  where
+    L: AsLeaf<D>,
+  {
+    if leaves.is_empty() || leaves.len() > self.capacity() {
+      return None;
+    }
+
+    let offset: usize = self.trees.len().checked_mul(self.capacity())?;
+    let mut digest: D = D::new();
+
+    self
+      .trees
+      .push(leaves.iter().map(|leaf| leaf.hash(&mut digest)).collect());
+
+    Some(offset)
+  }
+
+  /// Returns the root hash of each tree in the forest.
+  pub fn roots(&self) -> Vec<Hash<D>> {
+    self
+      .trees
+      .iter()
+      .map(|tree| compute_merkle_root_sized(tree, self.capacity()))
+      .collect()
+  }
+
+  /// Generates a proof-of-inclusion for the leaf at the given global `index`.
+  ///
+  /// The proof is verified against the root of the tree containing the leaf.
+  pub fn proof(&self, index: usize) -> Option<Proof<D>> {
+    let tree: &[Hash<D>] = self.trees.get(index >> self.height)?;
+
+    compute_merkle_proof_sized(tree, index & (self.capacity() - 1), self.capacity())
+  }
+
+  /// Verifies `proof` for the target `hash` against the given tree `roots`,
+  /// returning the global index of the proven leaf.
+  ///
+  /// Every root is compared, regardless of which one matches.
+  pub fn verify_hash(roots: &[Hash<D>], proof: &Proof<D>, hash: Hash<D>) -> Option<usize> {
+    let root: Hash<D> = proof.root(hash);
+    let mut found: Choice = Choice::from(0);
+    let mut tree: u64 = 0;
+
+    for (index, candidate) in roots.iter().enumerate() {
+      let matches: Choice = candidate.ct_eq(&root);
+
+      tree.conditional_assign(&(index as u64), matches);
+      found |= matches;
+    }
+
+    if !bool::from(found) {
+      return None;
+    }
+
+    let tree: usize = usize::try_from(tree).ok()?;
+    let height: u32 = u32::try_from(proof.nodes().len()).ok()?;
+
+    tree
+      .checked_mul(1_usize.checked_shl(height)?)?
+      .checked_add(proof.index())
+  }
+}
+
+impl<D: DigestExt> Clone for Forest<D>
+where
+  Hash<D>: Clone,
+{
+  fn clone(&self) -> Self {
+    Self {
+      height: self.height,
+      trees: self.trees.clone(),
+    }
+  }
+}
+
+impl<D: DigestExt> Debug for Forest<D> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    f.debug_struct("Forest")
+      .field("height", &self.height)
+      .field("trees", &self.trees)
+      .finish()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crypto::hashes::sha::Sha256;
+
+  use crate::crypto::merkle_tree::compute_merkle_root;
+  use crate::crypto::merkle_tree::Digest;
+  use crate::crypto::merkle_tree::DigestExt;
+  use crate::crypto::merkle_tree::Forest;
+  use crate::crypto::merkle_tree::Hash;
+  use crate::crypto::merkle_tree::Proof;
+
+  type Sha256Hash = Hash<Sha256>;
+  type Sha256Proof = Proof<Sha256>;
+
+  fn leaves(start: u32, end: u32) -> Vec<[u8; 4]> {
+    (start..end).map(u32::to_be_bytes).collect()
+  }
+
+  #[test]
+  fn test_push() {
+    let mut forest: Forest<Sha256> = Forest::new(3).unwrap();
+
+    assert!(forest.is_empty());
+    assert!(forest.push::<[u8; 4]>(&[]).is_none());
+    assert!(forest.push(&leaves(0, 9)).is_none());
+
+    assert_eq!(forest.push(&leaves(0, 8)), Some(0));
+    assert_eq!(forest.push(&leaves(8, 11)), Some(8));
+    assert_eq!(forest.push(&leaves(11, 12)), Some(16));
+
+    assert_eq!(forest.trees(), 3);
+    assert_eq!(forest.len(), 12);
+
+    // A full tree has the same root as a regular Merkle tree
+    assert_eq!(forest.roots()[0], compute_merkle_root::<Sha256, _>(&leaves(0, 8)));
+
+    assert!(forest.proof(11).is_none());
+    assert!(forest.proof(24).is_none());
+    assert!(Forest::<Sha256>::new(usize::BITS as usize).is_none());
+  }
+
+  #[test]
+  fn test_proofs_stay_valid() {
+    let mut digest: Sha256 = Sha256::new();
+    let mut forest: Forest<Sha256> = Forest::new(4).unwrap();
+
+    forest.push(&leaves(0, 5)).unwrap();
+
+    let proofs: Vec<Sha256Proof> = (0..5).map(|index| forest.proof(index).unwrap()).collect();
+
+    for index in 1..8 {
+      forest.push(&leaves(index * 100, index * 100 + index)).unwrap();
+    }
+
+    let roots: Vec<Sha256Hash> = forest.roots();
+
+    // Proofs created before the forest grew are still valid
+    for (index, (proof, leaf)) in proofs.iter().zip(leaves(0, 5)).enumerate() {
+      assert!(proof.verify(&roots[0], leaf));
+      assert_eq!(Forest::verify_hash(&roots, proof, digest.hash_leaf(&leaf)), Some(index));
+    }
+
+    // Leaves in later trees are indexed after the previous trees
+    let proof: Sha256Proof = forest.proof(16 * 3 + 2).unwrap();
+    let target: Sha256Hash = digest.hash_leaf(&302_u32.to_be_bytes());
+
+    assert_eq!(Forest::verify_hash(&roots, &proof, target), Some(16 * 3 + 2));
+    assert_eq!(Forest::verify_hash(&roots[..3], &proof, target), None);
+    assert_eq!(Forest::verify_hash(&roots, &proof, digest.hash_leaf(b"IOTA")), None);
+  }
+}
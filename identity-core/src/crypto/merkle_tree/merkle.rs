@@ -14,6 +14,9 @@ use crate::crypto::merkle_tree::Proof;
 ///
 /// For types implementing [`AsRef<[u8]>`][`AsRef`], the values will be hashed
 /// according to the [`Digest`][`DigestExt`] implementation, `D`.
+///
+/// See [`compute_padded_merkle_root`] for a layout that supports proofs for
+/// any number of leaves.
 pub fn compute_merkle_root<D, L>(leaves: &[L]) -> Hash<D>
 where
   D: DigestExt,
@@ -43,55 +46,132 @@ where
 }
 
 /// Generate a proof-of-inclusion for the leaf node at the specified `index`.
+///
+/// Returns `None` if the number of leaves is not a power of two, see
+/// [`compute_padded_merkle_proof`] for proofs in a tree of any size.
 pub fn compute_merkle_proof<D, L>(leaves: &[L], index: usize) -> Option<Proof<D>>
 where
   D: DigestExt,
   L: AsLeaf<D>,
 {
+  // For a power of two the padded tree is equal to the tree of `compute_merkle_root`.
+  if leaves.len().is_power_of_two() {
+    compute_padded_merkle_proof(leaves, index)
+  } else {
+    None
+  }
+}
+
+/// Compute the root hash of the padded Merkle tree for the given slice of `leaves`.
+///
+/// If the number of leaves is not a power of two, the tree is padded with
+/// empty subtrees so every leaf has a proof of the same length. The root is
+/// equal to [`compute_merkle_root`] if the number of leaves is a power of two.
+pub fn compute_padded_merkle_root<D, L>(leaves: &[L]) -> Hash<D>
+where
+  D: DigestExt,
+  L: AsLeaf<D>,
+{
+  compute_merkle_root_sized(leaves, __width(leaves.len()))
+}
+
+/// Generate a proof-of-inclusion for the leaf node at the specified `index`
+/// of the padded Merkle tree, see [`compute_padded_merkle_root`].
+pub fn compute_padded_merkle_proof<D, L>(leaves: &[L], index: usize) -> Option<Proof<D>>
+where
+  D: DigestExt,
+  L: AsLeaf<D>,
+{
+  compute_merkle_proof_sized(leaves, index, __width(leaves.len()))
+}
+
+// Computes the root hash of a tree with room for `width` leaves.
+//
+// Note: `width` must be a power of two not less than the number of leaves.
+pub(crate) fn compute_merkle_root_sized<D, L>(leaves: &[L], width: usize) -> Hash<D>
+where
+  D: DigestExt,
+  L: AsLeaf<D>,
+{
+  debug_assert!(width.is_power_of_two() && width >= leaves.len());
+
+  __root::<D, L>(&mut D::new(), leaves, width)
+}
+
+// Generates a proof-of-inclusion in a tree with room for `width` leaves.
+//
+// Note: `width` must be a power of two not less than the number of leaves.
+pub(crate) fn compute_merkle_proof_sized<D, L>(leaves: &[L], index: usize, width: usize) -> Option<Proof<D>>
+where
+  D: DigestExt,
+  L: AsLeaf<D>,
+{
+  debug_assert!(width.is_power_of_two() && width >= leaves.len());
+
   #[inline]
-  fn __generate<D, L>(digest: &mut D, path: &mut Vec<Node<D>>, leaves: &[L], index: usize)
+  fn __generate<D, L>(digest: &mut D, path: &mut Vec<Node<D>>, leaves: &[L], index: usize, width: usize)
   where
     D: DigestExt,
     L: AsLeaf<D>,
   {
-    if leaves.len() > 1 {
-      let k: usize = __pow2(leaves.len() as u32 - 1);
-      let (this, that): _ = leaves.split_at(k);
+    if width > 1 {
+      let k: usize = width / 2;
+      let (this, that): (&[L], &[L]) = __split(leaves, k);
 
       if index < k {
-        __generate::<D, L>(digest, path, this, index);
-        path.push(Node::R(compute_merkle_root::<D, L>(that)));
+        __generate::<D, L>(digest, path, this, index, k);
+        path.push(Node::R(__root::<D, L>(digest, that, k)));
       } else {
-        __generate::<D, L>(digest, path, that, index - k);
-        path.push(Node::L(compute_merkle_root::<D, L>(this)));
+        __generate::<D, L>(digest, path, that, index - k, k);
+        path.push(Node::L(__root::<D, L>(digest, this, k)));
       }
     }
   }
 
-  match (index, leaves.len()) {
-    (_, 0) => None,
-    (0, 1) => Some(Proof::new(Box::new([]))),
-    (_, 1) => None,
-    (index, length) => {
-      if index >= length {
-        return None;
-      }
+  if index >= leaves.len() {
+    return None;
+  }
 
-      // TODO: Support proofs for any number of leaves
-      if !length.is_power_of_two() {
-        return None;
-      }
+  let height: usize = width.trailing_zeros() as usize;
+  let mut path: Vec<Node<D>> = Vec::with_capacity(height);
+
+  __generate(&mut D::new(), &mut path, leaves, index, width);
 
-      let height: usize = __log2c(leaves.len() as u32) as usize;
-      let mut path: Vec<Node<D>> = Vec::with_capacity(height);
+  Some(Proof::new(path.into_boxed_slice()))
+}
 
-      __generate(&mut D::new(), &mut path, leaves, index);
+// Computes the root hash of a subtree with room for `width` leaves.
+#[inline]
+fn __root<D, L>(digest: &mut D, leaves: &[L], width: usize) -> Hash<D>
+where
+  D: DigestExt,
+  L: AsLeaf<D>,
+{
+  match leaves {
+    [] => digest.hash_empty(),
+    [leaf] if width == 1 => leaf.hash(digest),
+    leaves => {
+      let (this, that): (&[L], &[L]) = __split(leaves, width / 2);
 
-      Some(Proof::new(path.into_boxed_slice()))
+      let lhs: Hash<D> = __root(digest, this, width / 2);
+      let rhs: Hash<D> = __root(digest, that, width / 2);
+
+      digest.hash_node(&lhs, &rhs)
     }
   }
 }
 
+// Returns the number of leaves in the (padded) tree.
+#[inline]
+fn __width(length: usize) -> usize {
+  length.next_power_of_two()
+}
+
+#[inline]
+fn __split<T>(slice: &[T], index: usize) -> (&[T], &[T]) {
+  slice.split_at(index.min(slice.len()))
+}
+
 #[inline]
 fn __pow2(value: u32) -> usize {
   1 << __log2c(value)
@@ -113,9 +193,12 @@ mod tests {
 
   use crate::crypto::merkle_tree::compute_merkle_proof;
   use crate::crypto::merkle_tree::compute_merkle_root;
+  use crate::crypto::merkle_tree::compute_padded_merkle_proof;
+  use crate::crypto::merkle_tree::compute_padded_merkle_root;
   use crate::crypto::merkle_tree::Digest;
   use crate::crypto::merkle_tree::DigestExt;
   use crate::crypto::merkle_tree::Hash;
+  use crate::crypto::merkle_tree::Node;
   use crate::crypto::merkle_tree::Proof;
 
   macro_rules! h {
@@ -132,23 +215,37 @@ mod tests {
 
   #[test]
   fn test_compute_proof_and_index() {
-    for exp in 0..6 {
+    for size in 1..70 {
       let mut digest: Sha256 = Sha256::new();
 
-      let nodes: Vec<[u8; 4]> = (0..(1 << exp)).map(u32::to_be_bytes).collect();
+      let nodes: Vec<[u8; 4]> = (0..size).map(u32::to_be_bytes).collect();
       let hashes: Vec<Sha256Hash> = nodes.iter().map(|node| digest.hash_leaf(node.as_ref())).collect();
-      let root: Sha256Hash = compute_merkle_root(&hashes);
+      let root: Sha256Hash = compute_padded_merkle_root(&hashes);
 
       for (index, hash) in hashes.iter().enumerate() {
-        let proof: Sha256Proof = compute_merkle_proof(&hashes, index).unwrap();
+        let proof: Sha256Proof = compute_padded_merkle_proof(&hashes, index).unwrap();
 
         assert_eq!(proof.index(), index);
         assert_eq!(proof.root(*hash), root);
         assert!(proof.verify(&root, &nodes[index]));
         assert!(proof.verify_hash(&root, *hash));
+
+        // Proofs should survive an encoding round-trip
+        let decoded: Sha256Proof = Sha256Proof::decode(&proof.encode()).unwrap();
+
+        assert_eq!(decoded.index(), index);
+        assert!(decoded.verify_hash(&root, *hash));
       }
 
-      assert!(compute_merkle_proof::<Sha256, _>(&hashes, hashes.len()).is_none());
+      assert!(compute_padded_merkle_proof::<Sha256, _>(&hashes, hashes.len()).is_none());
+
+      // Both layouts are equal for a power of two
+      if size.is_power_of_two() {
+        assert_eq!(compute_merkle_root::<Sha256, _>(&hashes), root);
+        assert!(compute_merkle_proof::<Sha256, _>(&hashes, 0).is_some());
+      } else {
+        assert!(compute_merkle_proof::<Sha256, _>(&hashes, 0).is_none());
+      }
     }
   }
 
@@ -183,5 +280,73 @@ mod tests {
     assert_eq!(EFGH, compute_merkle_root(&[E, F, G, H]));
 
     assert_eq!(ABCDEFGH, compute_merkle_root(&[A, B, C, D, E, F, G, H]));
+
+    // Lists that are not a power of two are split at the largest power of two
+    assert_eq!(h!(AB, C), compute_merkle_root(&[A, B, C]));
+    assert_eq!(h!(ABCD, E), compute_merkle_root(&[A, B, C, D, E]));
+    assert_eq!(h!(ABCD, EF), compute_merkle_root(&[A, B, C, D, E, F]));
+  }
+
+  #[test]
+  #[allow(non_snake_case)]
+  fn test_padded_root() {
+    let X: Sha256Hash = Sha256::new().hash_empty();
+
+    let A: Sha256Hash = h!(b"A");
+    let B: Sha256Hash = h!(b"B");
+    let C: Sha256Hash = h!(b"C");
+    let D: Sha256Hash = h!(b"D");
+    let E: Sha256Hash = h!(b"E");
+    let F: Sha256Hash = h!(b"F");
+
+    let AB: Sha256Hash = h!(A, B);
+    let CD: Sha256Hash = h!(C, D);
+    let CX: Sha256Hash = h!(C, X);
+    let EX: Sha256Hash = h!(E, X);
+    let EF: Sha256Hash = h!(E, F);
+
+    let ABCD: Sha256Hash = h!(AB, CD);
+    let EXXX: Sha256Hash = h!(EX, X);
+    let EFXX: Sha256Hash = h!(EF, X);
+
+    assert_eq!(X, compute_padded_merkle_root::<Sha256, Sha256Hash>(&[]));
+    assert_eq!(A, compute_padded_merkle_root(&[A]));
+    assert_eq!(h!(AB, CX), compute_padded_merkle_root(&[A, B, C]));
+    assert_eq!(h!(ABCD, EXXX), compute_padded_merkle_root(&[A, B, C, D, E]));
+    assert_eq!(h!(ABCD, EFXX), compute_padded_merkle_root(&[A, B, C, D, E, F]));
+  }
+
+  #[test]
+  #[allow(non_snake_case)]
+  fn test_padded_proof() {
+    let X: Sha256Hash = Sha256::new().hash_empty();
+
+    let A: Sha256Hash = h!(b"A");
+    let B: Sha256Hash = h!(b"B");
+    let C: Sha256Hash = h!(b"C");
+    let D: Sha256Hash = h!(b"D");
+    let E: Sha256Hash = h!(b"E");
+
+    let AB: Sha256Hash = h!(A, B);
+    let CD: Sha256Hash = h!(C, D);
+    let ABCD: Sha256Hash = h!(AB, CD);
+
+    let leaves: [Sha256Hash; 5] = [A, B, C, D, E];
+
+    let proof: Sha256Proof = compute_padded_merkle_proof(&leaves, 4).unwrap();
+
+    assert!(matches!(
+      proof.nodes(),
+      [Node::R(n0), Node::R(n1), Node::L(n2)] if *n0 == X && *n1 == X && *n2 == ABCD
+    ));
+    assert_eq!(proof.index(), 4);
+
+    let proof: Sha256Proof = compute_padded_merkle_proof(&leaves, 2).unwrap();
+
+    assert!(matches!(
+      proof.nodes(),
+      [Node::R(n0), Node::L(n1), Node::R(n2)] if *n0 == D && *n1 == AB && *n2 == h!(h!(E, X), X)
+    ));
+    assert_eq!(proof.index(), 2);
   }
 }
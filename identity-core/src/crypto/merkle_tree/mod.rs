@@ -6,6 +6,7 @@
 //! [WIKI]: https://en.wikipedia.org/wiki/Merkle_tree
 
 mod digest;
+mod forest;
 mod hash;
 mod merkle;
 mod node;
@@ -16,9 +17,12 @@ mod traits;
 pub use self::digest::Digest;
 pub use self::digest::DigestExt;
 pub use self::digest::Output;
+pub use self::forest::Forest;
 pub use self::hash::Hash;
 pub use self::merkle::compute_merkle_proof;
 pub use self::merkle::compute_merkle_root;
+pub use self::merkle::compute_padded_merkle_proof;
+pub use self::merkle::compute_padded_merkle_root;
 pub use self::node::Node;
 pub use self::proof::Proof;
 pub use self::traits::AsLeaf;
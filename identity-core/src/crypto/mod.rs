@@ -7,24 +7,46 @@ mod key;
 mod proof;
 mod signature;
 
+pub mod bbs;
 pub mod merkle_key;
 pub mod merkle_tree;
 
+pub use self::key::DerivationPath;
 pub use self::key::KeyCollection;
 pub use self::key::KeyPair;
 pub use self::key::KeyRef;
 pub use self::key::KeyType;
+pub use self::key::Mnemonic;
 pub use self::key::PublicKey;
 pub use self::key::SecretKey;
+pub use self::key::Seed;
+pub use self::key::X25519;
+pub use self::proof::CborEd25519;
+pub use self::proof::Ed25519Signature2020;
+pub use self::proof::JcsEcdsaP256;
+pub use self::proof::JcsEcdsaSecp256k1;
 pub use self::proof::JcsEd25519;
+#[cfg(feature = "jose")]
+pub use self::proof::JsonWebSignature2020;
+pub use self::signature::BatchError;
 pub use self::signature::Ed25519;
+pub use self::signature::ExternalSign;
+pub use self::signature::ExternalSigner;
 pub use self::signature::Named;
+pub use self::signature::ProofPurpose;
+pub use self::signature::Secp256k1;
 pub use self::signature::SetSignature;
 pub use self::signature::Sign;
 pub use self::signature::Signature;
+pub use self::signature::SignatureAppend;
+pub use self::signature::SignatureOptions;
+pub use self::signature::SignatureSet;
 pub use self::signature::SignatureValue;
+pub use self::signature::SignatureView;
 pub use self::signature::Signer;
 pub use self::signature::TrySignature;
 pub use self::signature::TrySignatureMut;
 pub use self::signature::Verifier;
+pub use self::signature::VerifierOptions;
 pub use self::signature::Verify;
+pub use self::signature::P256;
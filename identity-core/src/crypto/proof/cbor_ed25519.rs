@@ -0,0 +1,172 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::marker::PhantomData;
+use serde::Serialize;
+
+use crate::convert::ToCbor;
+use crate::crypto::Ed25519;
+use crate::crypto::ExternalSign;
+use crate::crypto::Named;
+use crate::crypto::Sign;
+use crate::crypto::SignatureValue;
+use crate::crypto::Signer;
+use crate::crypto::Verifier;
+use crate::crypto::Verify;
+use crate::error::Error;
+use crate::error::Result;
+use crate::utils::decode_b58;
+use crate::utils::encode_b58;
+
+/// An Ed25519 signature suite that signs the deterministic CBOR encoding of
+/// the data.
+///
+/// The signature covers the same data model as the JSON representation, so
+/// data signed with this suite can be published and verified as either JSON
+/// or CBOR.
+///
+/// Users should use the [`Sign`]/[`Verify`] traits to access
+/// this implementation.
+pub struct CborEd25519<T = Ed25519>(PhantomData<T>);
+
+impl<T> Named for CborEd25519<T> {
+  const NAME: &'static str = "CborEd25519Signature2021";
+}
+
+impl<T> Signer<T::Secret> for CborEd25519<T>
+where
+  T: Sign,
+  T::Output: AsRef<[u8]>,
+{
+  fn sign<X>(data: &X, secret: &T::Secret) -> Result<SignatureValue>
+  where
+    X: Serialize,
+  {
+    let message: Vec<u8> = Self::message(data)?;
+    let signature: T::Output = T::sign(&message, secret)?;
+
+    Ok(Self::signature_value(signature.as_ref()))
+  }
+}
+
+impl<T> ExternalSign for CborEd25519<T> {
+  fn message<X>(data: &X) -> Result<Vec<u8>>
+  where
+    X: Serialize,
+  {
+    data.to_cbor()
+  }
+
+  fn signature_value(signature: &[u8]) -> SignatureValue {
+    SignatureValue::Signature(encode_b58(signature))
+  }
+}
+
+impl<T> Verifier<T::Public> for CborEd25519<T>
+where
+  T: Verify,
+{
+  fn verify<X>(data: &X, signature: &SignatureValue, public: &T::Public) -> Result<()>
+  where
+    X: Serialize,
+  {
+    let signature: &str = signature
+      .as_signature()
+      .ok_or(Error::InvalidProofValue("cbor ed25519"))?;
+
+    let signature: Vec<u8> = decode_b58(signature)?;
+    let message: Vec<u8> = data.to_cbor()?;
+
+    T::verify(&message, &signature, public)?;
+
+    Ok(())
+  }
+}
+
+#[cfg(all(test, feature = "cbor"))]
+mod tests {
+  use crate::common::Object;
+  use crate::common::Value;
+  use crate::convert::FromCbor;
+  use crate::convert::ToCbor;
+  use crate::crypto::CborEd25519;
+  use crate::crypto::Ed25519;
+  use crate::crypto::KeyPair;
+  use crate::crypto::PublicKey;
+  use crate::crypto::SecretKey;
+  use crate::crypto::SetSignature;
+  use crate::crypto::Signature;
+  use crate::crypto::SignatureValue;
+  use crate::crypto::Signer as _;
+  use crate::crypto::TrySignature;
+  use crate::crypto::TrySignatureMut;
+  use crate::crypto::Verifier as _;
+  use crate::json;
+
+  type Signer = CborEd25519<Ed25519<SecretKey>>;
+
+  type Verifier = CborEd25519<Ed25519<PublicKey>>;
+
+  #[derive(Debug, Deserialize, Serialize)]
+  struct That {
+    #[serde(flatten)]
+    data: Object,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proof: Option<Signature>,
+  }
+
+  impl TrySignature for That {
+    fn signature(&self) -> Option<&Signature> {
+      self.proof.as_ref()
+    }
+  }
+
+  impl TrySignatureMut for That {
+    fn signature_mut(&mut self) -> Option<&mut Signature> {
+      self.proof.as_mut()
+    }
+  }
+
+  impl SetSignature for That {
+    fn set_signature(&mut self, signature: Signature) {
+      self.proof = Some(signature);
+    }
+  }
+
+  #[test]
+  fn test_sign_verify() {
+    let key1: KeyPair = KeyPair::new_ed25519().unwrap();
+    let key2: KeyPair = KeyPair::new_ed25519().unwrap();
+
+    let data1: Value = json!({ "msg": "IOTA Identity" });
+    let data2: Value = json!({ "msg": "IOTA Identity 2" });
+
+    let signature: SignatureValue = Signer::sign(&data1, key1.secret()).unwrap();
+
+    // The signature should be valid
+    assert!(Verifier::verify(&data1, &signature, key1.public()).is_ok());
+
+    // Modified data should be invalid
+    assert!(Verifier::verify(&data2, &signature, key1.public()).is_err());
+
+    // A modified key should be invalid
+    assert!(Verifier::verify(&data1, &signature, key2.public()).is_err());
+  }
+
+  #[test]
+  fn test_verify_after_cbor_roundtrip() {
+    let key: KeyPair = KeyPair::new_ed25519().unwrap();
+
+    let mut data: Object = Object::new();
+    data.insert("b".into(), 1.into());
+    data.insert("a".into(), json!([1.5, "x"]));
+
+    let mut that: That = That { data, proof: None };
+
+    Signer::create_signature(&mut that, "#key-1", key.secret()).unwrap();
+
+    let that: That = That::from_cbor(&that.to_cbor().unwrap()).unwrap();
+
+    assert!(Verifier::verify_signature(&that, key.public()).is_ok());
+  }
+}
@@ -0,0 +1,161 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::marker::PhantomData;
+use serde::Serialize;
+
+use crate::crypto::proof::linked_data::create_verify_data;
+use crate::crypto::Ed25519;
+use crate::crypto::ExternalSign;
+use crate::crypto::Named;
+use crate::crypto::Sign;
+use crate::crypto::SignatureValue;
+use crate::crypto::Signer;
+use crate::crypto::Verifier;
+use crate::crypto::Verify;
+use crate::error::Error;
+use crate::error::Result;
+use crate::utils::decode_multibase;
+use crate::utils::encode_multibase;
+
+/// An implementation of the [Ed25519 Signature 2020][SPEC1] signature suite
+/// for [Linked Data Proofs][SPEC2].
+///
+/// The document and proof options are canonicalized with URDNA2015, so the
+/// signed document must reference the Ed25519 2020 suite context and define
+/// every property it contains.
+///
+/// Users should use the [`Sign`]/[`Verify`] traits to access
+/// this implementation.
+///
+/// [SPEC1]: https://w3c-ccg.github.io/lds-ed25519-2020/
+/// [SPEC2]: https://w3c-ccg.github.io/ld-proofs/
+pub struct Ed25519Signature2020<T = Ed25519>(PhantomData<T>);
+
+impl<T> Named for Ed25519Signature2020<T> {
+  const NAME: &'static str = "Ed25519Signature2020";
+}
+
+impl<T> Signer<T::Secret> for Ed25519Signature2020<T>
+where
+  T: Sign,
+  T::Output: AsRef<[u8]>,
+{
+  fn sign<X>(data: &X, secret: &T::Secret) -> Result<SignatureValue>
+  where
+    X: Serialize,
+  {
+    let message: Vec<u8> = Self::message(data)?;
+    let signature: T::Output = T::sign(&message, secret)?;
+
+    Ok(Self::signature_value(signature.as_ref()))
+  }
+}
+
+impl<T> ExternalSign for Ed25519Signature2020<T> {
+  fn message<X>(data: &X) -> Result<Vec<u8>>
+  where
+    X: Serialize,
+  {
+    create_verify_data(data)
+  }
+
+  fn signature_value(signature: &[u8]) -> SignatureValue {
+    SignatureValue::Proof(encode_multibase(signature, None))
+  }
+}
+
+impl<T> Verifier<T::Public> for Ed25519Signature2020<T>
+where
+  T: Verify,
+{
+  fn verify<X>(data: &X, signature: &SignatureValue, public: &T::Public) -> Result<()>
+  where
+    X: Serialize,
+  {
+    let signature: &str = signature
+      .as_proof()
+      .ok_or(Error::InvalidProofValue("ed25519 signature 2020"))?;
+
+    let signature: Vec<u8> = decode_multibase(signature)?;
+    let message: Vec<u8> = create_verify_data(data)?;
+
+    T::verify(&message, &signature, public)?;
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::common::Value;
+  use crate::crypto::Ed25519;
+  use crate::crypto::Ed25519Signature2020;
+  use crate::crypto::KeyPair;
+  use crate::crypto::PublicKey;
+  use crate::crypto::SecretKey;
+  use crate::crypto::SignatureValue;
+  use crate::crypto::Signer as _;
+  use crate::crypto::Verifier as _;
+  use crate::json;
+
+  type Signer = Ed25519Signature2020<Ed25519<SecretKey>>;
+
+  type Verifier = Ed25519Signature2020<Ed25519<PublicKey>>;
+
+  fn credential() -> Value {
+    json!({
+      "@context": [
+        "https://www.w3.org/2018/credentials/v1",
+        "https://w3id.org/security/suites/ed25519-2020/v1",
+        { "name": "https://schema.org/name" },
+      ],
+      "id": "https://example.com/credentials/1872",
+      "type": ["VerifiableCredential"],
+      "issuer": "did:example:issuer",
+      "issuanceDate": "2010-01-01T19:23:24Z",
+      "credentialSubject": {
+        "id": "did:example:holder",
+        "name": "Alice",
+      },
+      "proof": {
+        "type": "Ed25519Signature2020",
+        "verificationMethod": "did:example:issuer#key-1",
+      },
+    })
+  }
+
+  #[test]
+  fn test_sign_verify() {
+    let key1: KeyPair = KeyPair::new_ed25519().unwrap();
+    let key2: KeyPair = KeyPair::new_ed25519().unwrap();
+
+    let data1: Value = credential();
+    let mut data2: Value = credential();
+
+    data2["credentialSubject"]["name"] = "Bob".into();
+
+    let signature: SignatureValue = Signer::sign(&data1, key1.secret()).unwrap();
+
+    assert!(signature.as_str().starts_with('z'));
+
+    // The signature should be valid
+    assert!(Verifier::verify(&data1, &signature, key1.public()).is_ok());
+
+    // Modified data should be invalid
+    assert!(Verifier::verify(&data2, &signature, key1.public()).is_err());
+
+    // A modified key should be invalid
+    assert!(Verifier::verify(&data1, &signature, key2.public()).is_err());
+  }
+
+  #[test]
+  fn test_sign_requires_definitions() {
+    let key: KeyPair = KeyPair::new_ed25519().unwrap();
+    let mut data: Value = credential();
+
+    data["credentialSubject"]["undefined"] = "value".into();
+
+    assert!(Signer::sign(&data, key.secret()).is_err());
+  }
+}
@@ -0,0 +1,132 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::marker::PhantomData;
+use serde::Serialize;
+
+use crate::convert::ToJson;
+use crate::crypto::ExternalSign;
+use crate::crypto::Named;
+use crate::crypto::Sign;
+use crate::crypto::SignatureValue;
+use crate::crypto::Signer;
+use crate::crypto::Verifier;
+use crate::crypto::Verify;
+use crate::crypto::P256;
+use crate::error::Error;
+use crate::error::Result;
+use crate::utils::decode_b58;
+use crate::utils::encode_b58;
+
+/// An implementation of a JCS-based `ECDSA` signature suite over the `NIST P-256`
+/// curve for [Linked Data Proofs][SPEC1].
+///
+/// The input is canonicalized with [JCS][SPEC2] and the resulting signature is
+/// stored as a base58-encoded `signatureValue`, mirroring [`JcsEd25519`][crate::crypto::JcsEd25519].
+///
+/// Note: This suite is not compatible with `EcdsaSecp256r1Signature2019`, which
+/// canonicalizes with URDNA2015 and produces a detached JWS.
+///
+/// Users should use the [`Sign`]/[`Verify`] traits to access
+/// this implementation.
+///
+/// [SPEC1]: https://w3c-ccg.github.io/ld-proofs/
+/// [SPEC2]: https://tools.ietf.org/html/rfc8785
+pub struct JcsEcdsaP256<T = P256>(PhantomData<T>);
+
+impl<T> Named for JcsEcdsaP256<T> {
+  const NAME: &'static str = "JcsEcdsaSecp256r1Signature2019";
+}
+
+impl<T> Signer<T::Secret> for JcsEcdsaP256<T>
+where
+  T: Sign,
+  T::Output: AsRef<[u8]>,
+{
+  fn sign<X>(data: &X, secret: &T::Secret) -> Result<SignatureValue>
+  where
+    X: Serialize,
+  {
+    let message: Vec<u8> = Self::message(data)?;
+    let signature: T::Output = T::sign(&message, secret)?;
+
+    Ok(Self::signature_value(signature.as_ref()))
+  }
+}
+
+impl<T> ExternalSign for JcsEcdsaP256<T> {
+  fn message<X>(data: &X) -> Result<Vec<u8>>
+  where
+    X: Serialize,
+  {
+    data.to_jcs()
+  }
+
+  fn signature_value(signature: &[u8]) -> SignatureValue {
+    SignatureValue::Signature(encode_b58(signature))
+  }
+}
+
+impl<T> Verifier<T::Public> for JcsEcdsaP256<T>
+where
+  T: Verify,
+{
+  fn verify<X>(data: &X, signature: &SignatureValue, public: &T::Public) -> Result<()>
+  where
+    X: Serialize,
+  {
+    let signature: &str = signature
+      .as_signature()
+      .ok_or(Error::InvalidProofValue("jcs ecdsa p256"))?;
+
+    let signature: Vec<u8> = decode_b58(signature)?;
+    let message: Vec<u8> = data.to_jcs()?;
+
+    T::verify(&message, &signature, public)?;
+
+    Ok(())
+  }
+}
+
+#[cfg(all(test, feature = "p256"))]
+mod tests {
+  use crate::common::Value;
+  use crate::crypto::JcsEcdsaP256;
+  use crate::crypto::KeyPair;
+  use crate::crypto::KeyType;
+  use crate::crypto::PublicKey;
+  use crate::crypto::SecretKey;
+  use crate::crypto::SignatureValue;
+  use crate::crypto::Signer as _;
+  use crate::crypto::Verifier as _;
+  use crate::crypto::P256;
+  use crate::json;
+
+  type Signer = JcsEcdsaP256<P256<SecretKey>>;
+
+  type Verifier = JcsEcdsaP256<P256<PublicKey>>;
+
+  #[test]
+  fn test_sign_verify() {
+    let key1: KeyPair = KeyPair::new(KeyType::P256).unwrap();
+    let key2: KeyPair = KeyPair::new(KeyType::P256).unwrap();
+
+    let data1: Value = json!({ "msg": "IOTA Identity" });
+    let data2: Value = json!({ "msg": "IOTA Identity 2" });
+
+    let signature: _ = Signer::sign(&data1, key1.secret()).unwrap();
+
+    // The signature should be valid
+    assert!(Verifier::verify(&data1, &signature, key1.public()).is_ok());
+
+    // Modified data should be invalid
+    assert!(Verifier::verify(&data2, &signature, key1.public()).is_err());
+
+    // A modified key should be invalid
+    assert!(Verifier::verify(&data1, &signature, key2.public()).is_err());
+
+    // A mutated signature should be invalid
+    let signature: _ = SignatureValue::Signature("IOTA".into());
+    assert!(Verifier::verify(&data1, &signature, key1.public()).is_err());
+  }
+}
@@ -0,0 +1,133 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::marker::PhantomData;
+use serde::Serialize;
+
+use crate::convert::ToJson;
+use crate::crypto::ExternalSign;
+use crate::crypto::Named;
+use crate::crypto::Secp256k1;
+use crate::crypto::Sign;
+use crate::crypto::SignatureValue;
+use crate::crypto::Signer;
+use crate::crypto::Verifier;
+use crate::crypto::Verify;
+use crate::error::Error;
+use crate::error::Result;
+use crate::utils::decode_b58;
+use crate::utils::encode_b58;
+
+/// An implementation of a JCS-based `ECDSA` signature suite over the `secp256k1`
+/// curve for [Linked Data Proofs][SPEC1].
+///
+/// The input is canonicalized with [JCS][SPEC2] and the resulting signature is
+/// stored as a base58-encoded `signatureValue`, mirroring [`JcsEd25519`][crate::crypto::JcsEd25519].
+///
+/// Note: This suite is not compatible with [Ecdsa Secp256k1 Signature 2019][SPEC3],
+/// which canonicalizes with URDNA2015 and produces a detached JWS.
+///
+/// Users should use the [`Sign`]/[`Verify`] traits to access
+/// this implementation.
+///
+/// [SPEC1]: https://w3c-ccg.github.io/ld-proofs/
+/// [SPEC2]: https://tools.ietf.org/html/rfc8785
+/// [SPEC3]: https://w3c-ccg.github.io/lds-ecdsa-secp256k1-2019/
+pub struct JcsEcdsaSecp256k1<T = Secp256k1>(PhantomData<T>);
+
+impl<T> Named for JcsEcdsaSecp256k1<T> {
+  const NAME: &'static str = "JcsEcdsaSecp256k1Signature2019";
+}
+
+impl<T> Signer<T::Secret> for JcsEcdsaSecp256k1<T>
+where
+  T: Sign,
+  T::Output: AsRef<[u8]>,
+{
+  fn sign<X>(data: &X, secret: &T::Secret) -> Result<SignatureValue>
+  where
+    X: Serialize,
+  {
+    let message: Vec<u8> = Self::message(data)?;
+    let signature: T::Output = T::sign(&message, secret)?;
+
+    Ok(Self::signature_value(signature.as_ref()))
+  }
+}
+
+impl<T> ExternalSign for JcsEcdsaSecp256k1<T> {
+  fn message<X>(data: &X) -> Result<Vec<u8>>
+  where
+    X: Serialize,
+  {
+    data.to_jcs()
+  }
+
+  fn signature_value(signature: &[u8]) -> SignatureValue {
+    SignatureValue::Signature(encode_b58(signature))
+  }
+}
+
+impl<T> Verifier<T::Public> for JcsEcdsaSecp256k1<T>
+where
+  T: Verify,
+{
+  fn verify<X>(data: &X, signature: &SignatureValue, public: &T::Public) -> Result<()>
+  where
+    X: Serialize,
+  {
+    let signature: &str = signature
+      .as_signature()
+      .ok_or(Error::InvalidProofValue("ecdsa secp256k1"))?;
+
+    let signature: Vec<u8> = decode_b58(signature)?;
+    let message: Vec<u8> = data.to_jcs()?;
+
+    T::verify(&message, &signature, public)?;
+
+    Ok(())
+  }
+}
+
+#[cfg(all(test, feature = "secp256k1"))]
+mod tests {
+  use crate::common::Value;
+  use crate::crypto::JcsEcdsaSecp256k1;
+  use crate::crypto::KeyPair;
+  use crate::crypto::KeyType;
+  use crate::crypto::PublicKey;
+  use crate::crypto::Secp256k1;
+  use crate::crypto::SecretKey;
+  use crate::crypto::SignatureValue;
+  use crate::crypto::Signer as _;
+  use crate::crypto::Verifier as _;
+  use crate::json;
+
+  type Signer = JcsEcdsaSecp256k1<Secp256k1<SecretKey>>;
+
+  type Verifier = JcsEcdsaSecp256k1<Secp256k1<PublicKey>>;
+
+  #[test]
+  fn test_sign_verify() {
+    let key1: KeyPair = KeyPair::new(KeyType::Secp256k1).unwrap();
+    let key2: KeyPair = KeyPair::new(KeyType::Secp256k1).unwrap();
+
+    let data1: Value = json!({ "msg": "IOTA Identity" });
+    let data2: Value = json!({ "msg": "IOTA Identity 2" });
+
+    let signature: _ = Signer::sign(&data1, key1.secret()).unwrap();
+
+    // The signature should be valid
+    assert!(Verifier::verify(&data1, &signature, key1.public()).is_ok());
+
+    // Modified data should be invalid
+    assert!(Verifier::verify(&data2, &signature, key1.public()).is_err());
+
+    // A modified key should be invalid
+    assert!(Verifier::verify(&data1, &signature, key2.public()).is_err());
+
+    // A mutated signature should be invalid
+    let signature: _ = SignatureValue::Signature("IOTA".into());
+    assert!(Verifier::verify(&data1, &signature, key1.public()).is_err());
+  }
+}
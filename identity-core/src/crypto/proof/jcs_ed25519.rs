@@ -3,14 +3,20 @@
 
 use core::marker::PhantomData;
 use serde::Serialize;
+use std::vec::IntoIter;
 
 use crate::convert::ToJson;
+use crate::crypto::BatchError;
 use crate::crypto::Ed25519;
+use crate::crypto::ExternalSign;
 use crate::crypto::Named;
 use crate::crypto::Sign;
+use crate::crypto::Signature;
 use crate::crypto::SignatureValue;
 use crate::crypto::Signer;
+use crate::crypto::TrySignature;
 use crate::crypto::Verifier;
+use crate::crypto::VerifierOptions;
 use crate::crypto::Verify;
 use crate::error::Error;
 use crate::error::Result;
@@ -42,11 +48,23 @@ where
   where
     X: Serialize,
   {
-    let message: Vec<u8> = data.to_jcs()?;
+    let message: Vec<u8> = Self::message(data)?;
     let signature: T::Output = T::sign(&message, secret)?;
-    let signature: String = encode_b58(signature.as_ref());
 
-    Ok(SignatureValue::Signature(signature))
+    Ok(Self::signature_value(signature.as_ref()))
+  }
+}
+
+impl<T> ExternalSign for JcsEd25519<T> {
+  fn message<X>(data: &X) -> Result<Vec<u8>>
+  where
+    X: Serialize,
+  {
+    data.to_jcs()
+  }
+
+  fn signature_value(signature: &[u8]) -> SignatureValue {
+    SignatureValue::Signature(encode_b58(signature))
   }
 }
 
@@ -69,6 +87,71 @@ where
 
     Ok(())
   }
+
+  fn verify_signature_batch<X>(items: &[(&X, &T::Public)], options: &VerifierOptions) -> Result<()>
+  where
+    X: Serialize + TrySignature,
+  {
+    let prepared: Vec<Result<(Vec<u8>, Vec<u8>)>> =
+      items.iter().map(|(data, _)| Self::prepare(*data, options)).collect();
+
+    let mut results: IntoIter<Result<()>> = {
+      let batch: Vec<(&[u8], &[u8], &T::Public)> = prepared
+        .iter()
+        .zip(items.iter())
+        .filter_map(|(item, (_, public))| {
+          let (message, signature): &(Vec<u8>, Vec<u8>) = item.as_ref().ok()?;
+          Some((&message[..], &signature[..], *public))
+        })
+        .collect();
+
+      match T::verify_batch(&batch) {
+        Ok(()) => Vec::new().into_iter(),
+        Err(Error::InvalidBatch(error)) => error.into_results().into_iter(),
+        Err(error) => return Err(error),
+      }
+    };
+
+    // Map the results of the batch back to the items that were queued
+    BatchError::check(
+      prepared
+        .into_iter()
+        .map(|item| item.and_then(|_| results.next().unwrap_or(Ok(()))))
+        .collect(),
+    )
+    .map_err(Error::InvalidBatch)
+  }
+}
+
+impl<T> JcsEd25519<T> {
+  // Extracts the signature of `data` and returns the JCS-encoded message and
+  // the decoded signature value.
+  fn prepare<X>(data: &X, options: &VerifierOptions) -> Result<(Vec<u8>, Vec<u8>)>
+  where
+    X: Serialize + TrySignature,
+  {
+    let signature: &Signature = data.try_signature()?;
+
+    if signature.type_() != <Self as Named>::NAME {
+      return Err(Error::InvalidProofValue("signature name"));
+    }
+
+    signature.verify_options(options)?;
+
+    let value: &str = signature
+      .value()
+      .as_signature()
+      .ok_or(Error::InvalidProofValue("jcs ed25519"))?;
+    let value: Vec<u8> = decode_b58(value)?;
+
+    signature.hide_value();
+
+    let message: Result<Vec<u8>> = data.to_jcs();
+
+    signature.show_value();
+
+    Ok((message?, value))
+  }
 }
 
 #[cfg(test)]
@@ -0,0 +1,231 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use libjose::jwk::EcCurve;
+use libjose::jwk::EdCurve;
+use libjose::jwk::Jwk;
+use libjose::jwk::JwkParams;
+use libjose::jws::Decoder;
+use libjose::jws::Encoder;
+use libjose::jws::JwsAlgorithm;
+use libjose::jws::JwsFormat;
+use libjose::jws::JwsHeader;
+use serde::Serialize;
+
+use crate::crypto::proof::linked_data::create_verify_data;
+use crate::crypto::Named;
+use crate::crypto::SignatureValue;
+use crate::crypto::Signer;
+use crate::crypto::Verifier;
+use crate::error::Error;
+use crate::error::Result;
+
+/// An implementation of the [JSON Web Signature 2020][SPEC1] signature suite
+/// for [Linked Data Proofs][SPEC2].
+///
+/// The canonicalized document is signed as a detached JWS with an unencoded
+/// payload ([RFC 7797][RFC7797]). The algorithm is derived from the key type
+/// and curve of the key and never taken from the JWS header; only asymmetric
+/// keys are supported.
+///
+/// Users should use the [`Sign`][crate::crypto::Sign]/[`Verify`][crate::crypto::Verify]
+/// traits to access this implementation.
+///
+/// [SPEC1]: https://w3c-ccg.github.io/lds-jws2020/
+/// [SPEC2]: https://w3c-ccg.github.io/ld-proofs/
+/// [RFC7797]: https://tools.ietf.org/html/rfc7797
+pub struct JsonWebSignature2020;
+
+impl JsonWebSignature2020 {
+  fn algorithm(jwk: &Jwk) -> Result<JwsAlgorithm> {
+    // The `kty` member is not used to select the (untagged) key parameters.
+    if jwk.kty() != jwk.params().kty() {
+      return Err(Error::InvalidKeyFormat);
+    }
+
+    let algorithm: JwsAlgorithm = match jwk.params() {
+      JwkParams::Ec(params) => match params.try_ec_curve()? {
+        EcCurve::P256 => JwsAlgorithm::ES256,
+        EcCurve::P384 => JwsAlgorithm::ES384,
+        EcCurve::P521 => JwsAlgorithm::ES512,
+        EcCurve::Secp256K1 => JwsAlgorithm::ES256K,
+      },
+      JwkParams::Okp(params) => match params.try_ed_curve()? {
+        EdCurve::Ed25519 | EdCurve::Ed448 => JwsAlgorithm::EdDSA,
+      },
+      JwkParams::Rsa(_) => JwsAlgorithm::PS256,
+      JwkParams::Oct(_) => return Err(Error::InvalidProofValue("jws algorithm")),
+    };
+
+    // An explicit `alg` parameter must agree with the key.
+    match jwk.alg() {
+      Some(alg) if alg != algorithm.name() => Err(Error::InvalidProofValue("jws algorithm")),
+      _ => Ok(algorithm),
+    }
+  }
+}
+
+impl Named for JsonWebSignature2020 {
+  const NAME: &'static str = "JsonWebSignature2020";
+}
+
+impl Signer<Jwk> for JsonWebSignature2020 {
+  fn sign<X>(data: &X, secret: &Jwk) -> Result<SignatureValue>
+  where
+    X: Serialize,
+  {
+    let algorithm: JwsAlgorithm = Self::algorithm(secret)?;
+    let message: Vec<u8> = create_verify_data(data)?;
+
+    let header: JwsHeader = {
+      let mut header: JwsHeader = JwsHeader::new(algorithm);
+      header.set_b64(false);
+      header.set_crit(["b64"]);
+      header
+    };
+
+    Encoder::new()
+      .format(JwsFormat::Compact)
+      .detached(true)
+      .recipient((secret, &header))
+      .encode(&message)
+      .map(SignatureValue::Jws)
+      .map_err(Into::into)
+  }
+}
+
+impl Verifier<Jwk> for JsonWebSignature2020 {
+  fn verify<X>(data: &X, signature: &SignatureValue, public: &Jwk) -> Result<()>
+  where
+    X: Serialize,
+  {
+    let signature: &str = signature
+      .as_jws()
+      .ok_or(Error::InvalidProofValue("json web signature 2020"))?;
+
+    let algorithm: JwsAlgorithm = Self::algorithm(public)?;
+    let message: Vec<u8> = create_verify_data(data)?;
+
+    Decoder::new(public)
+      .format(JwsFormat::Compact)
+      .algorithm(algorithm)
+      .critical("b64")
+      .payload(&message)
+      .decode(signature.as_bytes())?;
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use libjose::jwk::Jwk;
+  use libjose::jws::Encoder;
+  use libjose::jws::JwsAlgorithm;
+  use libjose::jws::JwsFormat;
+  use libjose::jws::JwsHeader;
+
+  use crate::common::Value;
+  use crate::crypto::JsonWebSignature2020;
+  use crate::crypto::SignatureValue;
+  use crate::crypto::Signer as _;
+  use crate::crypto::Verifier as _;
+  use crate::json;
+
+  fn credential() -> Value {
+    json!({
+      "@context": [
+        "https://www.w3.org/2018/credentials/v1",
+        "https://w3id.org/security/suites/jws-2020/v1",
+        { "name": "https://schema.org/name" },
+      ],
+      "id": "https://example.com/credentials/1872",
+      "type": ["VerifiableCredential"],
+      "issuer": "did:example:issuer",
+      "issuanceDate": "2010-01-01T19:23:24Z",
+      "credentialSubject": {
+        "id": "did:example:holder",
+        "name": "Alice",
+      },
+      "proof": {
+        "type": "JsonWebSignature2020",
+        "verificationMethod": "did:example:issuer#key-1",
+      },
+    })
+  }
+
+  fn random(algorithm: JwsAlgorithm) -> Jwk {
+    let mut jwk: Jwk = Jwk::random(algorithm).unwrap();
+    jwk.set_alg(algorithm.name());
+    jwk
+  }
+
+  #[test]
+  fn test_sign_verify() {
+    let algorithms: &[JwsAlgorithm] = &[JwsAlgorithm::ES256, JwsAlgorithm::ES256K, JwsAlgorithm::EdDSA];
+
+    for algorithm in algorithms.iter().copied() {
+      let key1: Jwk = random(algorithm);
+      let key2: Jwk = random(algorithm);
+
+      let public1: Jwk = key1.to_public();
+      let public2: Jwk = key2.to_public();
+
+      let data1: Value = credential();
+      let mut data2: Value = credential();
+
+      data2["credentialSubject"]["name"] = "Bob".into();
+
+      let signature: SignatureValue = JsonWebSignature2020::sign(&data1, &key1).unwrap();
+
+      // The signature should be a detached JWS
+      assert!(signature.is_jws());
+      assert!(signature.as_str().contains(".."));
+
+      // The signature should be valid
+      assert!(JsonWebSignature2020::verify(&data1, &signature, &public1).is_ok());
+
+      // Modified data should be invalid
+      assert!(JsonWebSignature2020::verify(&data2, &signature, &public1).is_err());
+
+      // A modified key should be invalid
+      assert!(JsonWebSignature2020::verify(&data1, &signature, &public2).is_err());
+    }
+  }
+
+  #[test]
+  fn test_algorithm_from_key() {
+    // The algorithm is derived from the key if no `alg` parameter is set
+    let key: Jwk = Jwk::random(JwsAlgorithm::EdDSA).unwrap();
+    let signature: SignatureValue = JsonWebSignature2020::sign(&credential(), &key).unwrap();
+
+    assert!(JsonWebSignature2020::verify(&credential(), &signature, &key.to_public()).is_ok());
+
+    // A conflicting `alg` parameter should be invalid
+    let mut public: Jwk = key.to_public();
+    public.set_alg(JwsAlgorithm::ES256.name());
+
+    assert!(JsonWebSignature2020::verify(&credential(), &signature, &public).is_err());
+  }
+
+  #[test]
+  fn test_symmetric_keys_are_invalid() {
+    for algorithm in [JwsAlgorithm::HS256, JwsAlgorithm::HS384, JwsAlgorithm::HS512] {
+      let key: Jwk = random(algorithm);
+
+      assert!(JsonWebSignature2020::sign(&credential(), &key).is_err());
+    }
+
+    // A JWS with a symmetric algorithm in the header should be rejected even
+    // if the verifier holds a symmetric key of the same value.
+    let key: Jwk = random(JwsAlgorithm::HS256);
+    let signature: String = Encoder::new()
+      .format(JwsFormat::Compact)
+      .detached(true)
+      .recipient((&key, &JwsHeader::new(JwsAlgorithm::HS256)))
+      .encode(b"payload")
+      .unwrap();
+
+    assert!(JsonWebSignature2020::verify(&credential(), &SignatureValue::Jws(signature), &key).is_err());
+  }
+}
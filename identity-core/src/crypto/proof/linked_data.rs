@@ -0,0 +1,62 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::hashes::sha::Sha256;
+use crypto::hashes::Digest;
+use serde::Serialize;
+
+use crate::common::Object;
+use crate::common::Value;
+use crate::convert::ToJson;
+use crate::error::Error;
+use crate::error::Result;
+use crate::jsonld::canonicalize;
+use crate::jsonld::StaticLoader;
+
+lazy_static! {
+  // Parsing the bundled contexts is expensive, so the loader is shared by
+  // every proof.
+  static ref LOADER: StaticLoader = StaticLoader::new();
+}
+
+/// Returns the data to be signed by a Linked Data proof suite: the SHA-256
+/// hash of the canonical proof options followed by the SHA-256 hash of the
+/// canonical document.
+pub(crate) fn create_verify_data<X>(data: &X) -> Result<Vec<u8>>
+where
+  X: Serialize,
+{
+  let mut document: Object = match data.to_json_value()? {
+    Value::Object(object) => object.into_iter().collect(),
+    _ => return Err(Error::InvalidJsonLd("expected a JSON-LD object")),
+  };
+
+  // A chained proof is preceded by the proof it was created over; the
+  // previous proof is secured as part of the document.
+  let mut options: Object = match document.remove("proof") {
+    Some(Value::Object(object)) => object.into_iter().collect(),
+    Some(Value::Array(mut proofs)) => match proofs.pop() {
+      Some(Value::Object(object)) => {
+        if !proofs.is_empty() {
+          document.insert("proof".into(), Value::Array(proofs));
+        }
+
+        object.into_iter().collect()
+      }
+      _ => return Err(Error::MissingSignature),
+    },
+    _ => return Err(Error::MissingSignature),
+  };
+
+  options.remove("proofValue");
+  options.remove("jws");
+
+  if let Some(context) = document.get("@context") {
+    options.insert("@context".into(), context.clone());
+  }
+
+  let mut output: Vec<u8> = Sha256::digest(canonicalize(&options, &*LOADER)?.as_bytes()).to_vec();
+  output.extend_from_slice(&Sha256::digest(canonicalize(&document, &*LOADER)?.as_bytes()));
+
+  Ok(output)
+}
@@ -4,6 +4,19 @@
 //! Types and traits for helping ensure the authenticity and integrity of
 //! DID Documents and Verifiable Credentials.
 
+mod cbor_ed25519;
+mod ed25519_signature_2020;
+mod jcs_ecdsa_p256;
+mod jcs_ecdsa_secp256k1;
 mod jcs_ed25519;
+#[cfg(feature = "jose")]
+mod json_web_signature_2020;
+mod linked_data;
 
+pub use self::cbor_ed25519::CborEd25519;
+pub use self::ed25519_signature_2020::Ed25519Signature2020;
+pub use self::jcs_ecdsa_p256::JcsEcdsaP256;
+pub use self::jcs_ecdsa_secp256k1::JcsEcdsaSecp256k1;
 pub use self::jcs_ed25519::JcsEd25519;
+#[cfg(feature = "jose")]
+pub use self::json_web_signature_2020::JsonWebSignature2020;
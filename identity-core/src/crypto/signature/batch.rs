@@ -0,0 +1,73 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
+use crate::crypto::Verify;
+use crate::error::Error;
+use crate::error::Result;
+
+/// The result of each item of a failed batch verification.
+///
+/// A batch fails if any of its items fails - the results identify the items
+/// that did.
+#[derive(Debug)]
+pub struct BatchError<E = Error> {
+  results: Vec<Result<(), E>>,
+}
+
+impl<E> BatchError<E> {
+  /// Collects the given per-item `results`, returning a `BatchError` if any
+  /// item failed.
+  pub fn check(results: Vec<Result<(), E>>) -> Result<(), Self> {
+    if results.iter().all(Result::is_ok) {
+      Ok(())
+    } else {
+      Err(Self { results })
+    }
+  }
+
+  /// Returns the result of each item of the batch, in order.
+  pub fn results(&self) -> &[Result<(), E>] {
+    &self.results
+  }
+
+  /// Returns an iterator over the indexes of the items that failed.
+  pub fn failed(&self) -> impl Iterator<Item = usize> + '_ {
+    self
+      .results
+      .iter()
+      .enumerate()
+      .filter_map(|(index, result)| result.as_ref().err().map(|_| index))
+  }
+
+  /// Consumes the `BatchError` and returns the result of each item.
+  pub fn into_results(self) -> Vec<Result<(), E>> {
+    self.results
+  }
+}
+
+impl<E> Display for BatchError<E> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{} of {} items failed", self.failed().count(), self.results.len())
+  }
+}
+
+impl<E: Debug> std::error::Error for BatchError<E> {}
+
+// Verifies each item of a batch individually.
+pub(crate) fn verify_each<T>(items: &[(&[u8], &[u8], &T::Public)]) -> Result<()>
+where
+  T: Verify + ?Sized,
+{
+  BatchError::check(
+    items
+      .iter()
+      .map(|(message, signature, key)| T::verify(message, signature, key))
+      .collect(),
+  )
+  .map_err(Error::InvalidBatch)
+}
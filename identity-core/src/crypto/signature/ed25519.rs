@@ -7,7 +7,17 @@ use crypto::signatures::ed25519;
 use crypto::signatures::ed25519::PUBLIC_KEY_LENGTH;
 use crypto::signatures::ed25519::SECRET_KEY_LENGTH;
 use crypto::signatures::ed25519::SIGNATURE_LENGTH;
-
+#[cfg(feature = "batch")]
+use ed25519_zebra::batch;
+#[cfg(feature = "batch")]
+use ed25519_zebra::Signature as ZebraSignature;
+#[cfg(feature = "batch")]
+use ed25519_zebra::VerificationKeyBytes;
+#[cfg(feature = "batch")]
+use rand_core::OsRng;
+
+#[cfg(feature = "batch")]
+use crate::crypto::signature::batch::verify_each;
 use crate::crypto::Sign;
 use crate::crypto::Verify;
 use crate::error::Error;
@@ -45,15 +55,43 @@ where
       Err(Error::InvalidProofValue("ed25519"))
     }
   }
+
+  // Without the `batch` feature signatures are verified one by one.
+  #[cfg(feature = "batch")]
+  fn verify_batch(items: &[(&[u8], &[u8], &Self::Public)]) -> Result<()> {
+    let mut verifier: batch::Verifier = batch::Verifier::new();
+
+    for (message, signature, key) in items.iter() {
+      match (public_bytes(key.as_ref()), signature_bytes(signature)) {
+        (Ok(key), Ok(signature)) => {
+          verifier.queue((
+            VerificationKeyBytes::from(key),
+            ZebraSignature::from(signature),
+            message,
+          ));
+        }
+        _ => return verify_each::<Self>(items),
+      }
+    }
+
+    // A failed batch doesn't identify the invalid signatures - verify each
+    // signature to report the result of every item.
+    match verifier.verify(OsRng) {
+      Ok(()) => Ok(()),
+      Err(_) => verify_each::<Self>(items),
+    }
+  }
 }
 
 fn parse_public(slice: &[u8]) -> Result<ed25519::PublicKey> {
-  let bytes: [u8; PUBLIC_KEY_LENGTH] = slice
+  ed25519::PublicKey::try_from_bytes(public_bytes(slice)?).map_err(Into::into)
+}
+
+fn public_bytes(slice: &[u8]) -> Result<[u8; PUBLIC_KEY_LENGTH]> {
+  slice
     .get(..PUBLIC_KEY_LENGTH)
     .and_then(|bytes| bytes.try_into().ok())
-    .ok_or_else(|| Error::InvalidKeyLength(slice.len(), PUBLIC_KEY_LENGTH))?;
-
-  ed25519::PublicKey::try_from_bytes(bytes).map_err(Into::into)
+    .ok_or_else(|| Error::InvalidKeyLength(slice.len(), PUBLIC_KEY_LENGTH))
 }
 
 fn parse_secret(slice: &[u8]) -> Result<ed25519::SecretKey> {
@@ -66,19 +104,25 @@ fn parse_secret(slice: &[u8]) -> Result<ed25519::SecretKey> {
 }
 
 fn parse_signature(slice: &[u8]) -> Result<ed25519::Signature> {
-  let bytes: [u8; SIGNATURE_LENGTH] = slice
+  signature_bytes(slice).map(ed25519::Signature::from_bytes)
+}
+
+fn signature_bytes(slice: &[u8]) -> Result<[u8; SIGNATURE_LENGTH]> {
+  slice
     .get(..SIGNATURE_LENGTH)
     .and_then(|bytes| bytes.try_into().ok())
-    .ok_or_else(|| Error::InvalidSigLength(slice.len(), SIGNATURE_LENGTH))?;
-
-  Ok(ed25519::Signature::from_bytes(bytes))
+    .ok_or_else(|| Error::InvalidSigLength(slice.len(), SIGNATURE_LENGTH))
 }
 
 #[cfg(test)]
 mod tests {
   use crate::crypto::Ed25519;
+  use crate::crypto::KeyPair;
+  use crate::crypto::PublicKey;
+  use crate::crypto::SecretKey;
   use crate::crypto::Sign;
   use crate::crypto::Verify;
+  use crate::error::Error;
   use crate::utils::decode_b58;
 
   const SIGNATURE_HELLO: &[u8] = &[
@@ -103,4 +147,49 @@ mod tests {
     let verified: _ = Ed25519::verify(b"hello", &signature, &public);
     assert!(verified.is_ok());
   }
+
+  #[test]
+  fn test_ed25519_verify_batch() {
+    let keys: Vec<KeyPair> = (0..4).map(|_| KeyPair::new_ed25519().unwrap()).collect();
+    let messages: Vec<Vec<u8>> = (0..4).map(|index| format!("message {}", index).into_bytes()).collect();
+
+    let signatures: Vec<_> = keys
+      .iter()
+      .zip(messages.iter())
+      .map(|(key, message)| Ed25519::<SecretKey>::sign(message, key.secret()).unwrap())
+      .collect();
+
+    let mut items: Vec<(&[u8], &[u8], &PublicKey)> = messages
+      .iter()
+      .zip(signatures.iter())
+      .zip(keys.iter())
+      .map(|((message, signature), key)| (&message[..], &signature[..], key.public()))
+      .collect();
+
+    assert!(Ed25519::<PublicKey>::verify_batch(&items).is_ok());
+    assert!(Ed25519::<PublicKey>::verify_batch(&[]).is_ok());
+
+    // Swap the keys of two items - both signatures are now invalid
+    items[1].2 = keys[2].public();
+    items[2].2 = keys[1].public();
+
+    match Ed25519::<PublicKey>::verify_batch(&items) {
+      Err(Error::InvalidBatch(error)) => {
+        assert_eq!(error.failed().collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(error.results().len(), 4);
+      }
+      result => panic!("unexpected result: {:?}", result),
+    }
+
+    // Malformed signatures are reported as well
+    items[1] = (&b"message 1"[..], &b"IOTA"[..], keys[1].public());
+    items[2].2 = keys[2].public();
+
+    match Ed25519::<PublicKey>::verify_batch(&items) {
+      Err(Error::InvalidBatch(error)) => {
+        assert_eq!(error.failed().collect::<Vec<_>>(), [1]);
+      }
+      result => panic!("unexpected result: {:?}", result),
+    }
+  }
 }
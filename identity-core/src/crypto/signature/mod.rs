@@ -3,19 +3,35 @@
 
 #![allow(clippy::module_inception)]
 
+mod batch;
 mod ed25519;
+mod p256;
+mod secp256k1;
 mod signature;
+mod signature_options;
 mod signature_value;
 mod traits;
+mod view;
 
+pub use self::batch::BatchError;
 pub use self::ed25519::Ed25519;
+pub use self::p256::P256;
+pub use self::secp256k1::Secp256k1;
 pub use self::signature::Signature;
+pub use self::signature_options::ProofPurpose;
+pub use self::signature_options::SignatureOptions;
+pub use self::signature_options::VerifierOptions;
 pub use self::signature_value::SignatureValue;
+pub use self::traits::ExternalSign;
+pub use self::traits::ExternalSigner;
 pub use self::traits::Named;
 pub use self::traits::SetSignature;
 pub use self::traits::Sign;
+pub use self::traits::SignatureSet;
 pub use self::traits::Signer;
 pub use self::traits::TrySignature;
 pub use self::traits::TrySignatureMut;
 pub use self::traits::Verifier;
 pub use self::traits::Verify;
+pub use self::view::SignatureAppend;
+pub use self::view::SignatureView;
@@ -0,0 +1,124 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::marker::PhantomData;
+#[cfg(feature = "p256")]
+use p256::ecdsa;
+#[cfg(feature = "p256")]
+use p256::ecdsa::signature::Signature as _;
+#[cfg(feature = "p256")]
+use p256::ecdsa::signature::Signer as _;
+#[cfg(feature = "p256")]
+use p256::ecdsa::signature::Verifier as _;
+
+use crate::crypto::Sign;
+use crate::crypto::Verify;
+use crate::error::Error;
+use crate::error::Result;
+
+/// The length of a compressed `P-256` public key.
+#[cfg(feature = "p256")]
+pub(crate) const P256_PUBLIC_KEY_LENGTH: usize = 33;
+
+/// The length of a `P-256` secret key.
+#[cfg(feature = "p256")]
+pub(crate) const P256_SECRET_KEY_LENGTH: usize = 32;
+
+/// The length of a `P-256` signature.
+pub(crate) const P256_SIGNATURE_LENGTH: usize = 64;
+
+/// An implementation of `ECDSA` signatures over the `NIST P-256` curve (ES256).
+#[derive(Clone, Copy, Debug)]
+pub struct P256<T: ?Sized = [u8]>(PhantomData<T>);
+
+impl<T> Sign for P256<T>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  type Secret = T;
+  type Output = [u8; P256_SIGNATURE_LENGTH];
+
+  #[cfg(feature = "p256")]
+  fn sign(message: &[u8], key: &Self::Secret) -> Result<Self::Output> {
+    let key: ecdsa::SigningKey = parse_secret(key.as_ref())?;
+    let sig: ecdsa::Signature = key.sign(message);
+
+    let mut output: [u8; P256_SIGNATURE_LENGTH] = [0; P256_SIGNATURE_LENGTH];
+    output.copy_from_slice(sig.as_ref());
+
+    Ok(output)
+  }
+
+  #[cfg(not(feature = "p256"))]
+  fn sign(_: &[u8], _: &Self::Secret) -> Result<Self::Output> {
+    Err(Error::MissingFeature("p256"))
+  }
+}
+
+impl<T> Verify for P256<T>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  type Public = T;
+
+  #[cfg(feature = "p256")]
+  fn verify(message: &[u8], signature: &[u8], key: &Self::Public) -> Result<()> {
+    let key: ecdsa::VerifyingKey = parse_public(key.as_ref())?;
+    let sig: ecdsa::Signature = parse_signature(signature)?;
+
+    key.verify(message, &sig).map_err(|_| Error::InvalidProofValue("p256"))
+  }
+
+  #[cfg(not(feature = "p256"))]
+  fn verify(_: &[u8], _: &[u8], _: &Self::Public) -> Result<()> {
+    Err(Error::MissingFeature("p256"))
+  }
+}
+
+#[cfg(feature = "p256")]
+fn parse_public(slice: &[u8]) -> Result<ecdsa::VerifyingKey> {
+  if slice.len() != P256_PUBLIC_KEY_LENGTH {
+    return Err(Error::InvalidKeyLength(slice.len(), P256_PUBLIC_KEY_LENGTH));
+  }
+
+  ecdsa::VerifyingKey::from_sec1_bytes(slice).map_err(|_| Error::InvalidKeyFormat)
+}
+
+#[cfg(feature = "p256")]
+fn parse_secret(slice: &[u8]) -> Result<ecdsa::SigningKey> {
+  if slice.len() != P256_SECRET_KEY_LENGTH {
+    return Err(Error::InvalidKeyLength(slice.len(), P256_SECRET_KEY_LENGTH));
+  }
+
+  ecdsa::SigningKey::from_bytes(slice).map_err(|_| Error::InvalidKeyFormat)
+}
+
+#[cfg(feature = "p256")]
+fn parse_signature(slice: &[u8]) -> Result<ecdsa::Signature> {
+  if slice.len() != P256_SIGNATURE_LENGTH {
+    return Err(Error::InvalidSigLength(slice.len(), P256_SIGNATURE_LENGTH));
+  }
+
+  ecdsa::Signature::from_bytes(slice).map_err(|_| Error::InvalidProofValue("p256"))
+}
+
+#[cfg(all(test, feature = "p256"))]
+mod tests {
+  use crate::crypto::KeyPair;
+  use crate::crypto::KeyType;
+  use crate::crypto::Sign;
+  use crate::crypto::Verify;
+  use crate::crypto::P256;
+
+  #[test]
+  fn test_p256_can_sign_and_verify() {
+    let keypair: KeyPair = KeyPair::new(KeyType::P256).unwrap();
+    let badpair: KeyPair = KeyPair::new(KeyType::P256).unwrap();
+
+    let signature: _ = P256::sign(b"hello", keypair.secret().as_ref()).unwrap();
+
+    assert!(P256::verify(b"hello", &signature, keypair.public().as_ref()).is_ok());
+    assert!(P256::verify(b"world", &signature, keypair.public().as_ref()).is_err());
+    assert!(P256::verify(b"hello", &signature, badpair.public().as_ref()).is_err());
+  }
+}
@@ -0,0 +1,126 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::marker::PhantomData;
+#[cfg(feature = "secp256k1")]
+use k256::ecdsa;
+#[cfg(feature = "secp256k1")]
+use k256::ecdsa::signature::Signature as _;
+#[cfg(feature = "secp256k1")]
+use k256::ecdsa::signature::Signer as _;
+#[cfg(feature = "secp256k1")]
+use k256::ecdsa::signature::Verifier as _;
+
+use crate::crypto::Sign;
+use crate::crypto::Verify;
+use crate::error::Error;
+use crate::error::Result;
+
+/// The length of a compressed `Secp256k1` public key.
+#[cfg(feature = "secp256k1")]
+pub(crate) const SECP256K1_PUBLIC_KEY_LENGTH: usize = 33;
+
+/// The length of a `Secp256k1` secret key.
+#[cfg(feature = "secp256k1")]
+pub(crate) const SECP256K1_SECRET_KEY_LENGTH: usize = 32;
+
+/// The length of a `Secp256k1` signature.
+pub(crate) const SECP256K1_SIGNATURE_LENGTH: usize = 64;
+
+/// An implementation of `ECDSA` signatures over the `Secp256k1` curve (ES256K).
+#[derive(Clone, Copy, Debug)]
+pub struct Secp256k1<T: ?Sized = [u8]>(PhantomData<T>);
+
+impl<T> Sign for Secp256k1<T>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  type Secret = T;
+  type Output = [u8; SECP256K1_SIGNATURE_LENGTH];
+
+  #[cfg(feature = "secp256k1")]
+  fn sign(message: &[u8], key: &Self::Secret) -> Result<Self::Output> {
+    let key: ecdsa::SigningKey = parse_secret(key.as_ref())?;
+    let sig: ecdsa::Signature = key.sign(message);
+
+    let mut output: [u8; SECP256K1_SIGNATURE_LENGTH] = [0; SECP256K1_SIGNATURE_LENGTH];
+    output.copy_from_slice(sig.as_ref());
+
+    Ok(output)
+  }
+
+  #[cfg(not(feature = "secp256k1"))]
+  fn sign(_: &[u8], _: &Self::Secret) -> Result<Self::Output> {
+    Err(Error::MissingFeature("secp256k1"))
+  }
+}
+
+impl<T> Verify for Secp256k1<T>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  type Public = T;
+
+  #[cfg(feature = "secp256k1")]
+  fn verify(message: &[u8], signature: &[u8], key: &Self::Public) -> Result<()> {
+    let key: ecdsa::VerifyingKey = parse_public(key.as_ref())?;
+    let sig: ecdsa::Signature = parse_signature(signature)?;
+
+    key
+      .verify(message, &sig)
+      .map_err(|_| Error::InvalidProofValue("secp256k1"))
+  }
+
+  #[cfg(not(feature = "secp256k1"))]
+  fn verify(_: &[u8], _: &[u8], _: &Self::Public) -> Result<()> {
+    Err(Error::MissingFeature("secp256k1"))
+  }
+}
+
+#[cfg(feature = "secp256k1")]
+fn parse_public(slice: &[u8]) -> Result<ecdsa::VerifyingKey> {
+  if slice.len() != SECP256K1_PUBLIC_KEY_LENGTH {
+    return Err(Error::InvalidKeyLength(slice.len(), SECP256K1_PUBLIC_KEY_LENGTH));
+  }
+
+  ecdsa::VerifyingKey::from_sec1_bytes(slice).map_err(|_| Error::InvalidKeyFormat)
+}
+
+#[cfg(feature = "secp256k1")]
+fn parse_secret(slice: &[u8]) -> Result<ecdsa::SigningKey> {
+  if slice.len() != SECP256K1_SECRET_KEY_LENGTH {
+    return Err(Error::InvalidKeyLength(slice.len(), SECP256K1_SECRET_KEY_LENGTH));
+  }
+
+  ecdsa::SigningKey::from_bytes(slice).map_err(|_| Error::InvalidKeyFormat)
+}
+
+#[cfg(feature = "secp256k1")]
+fn parse_signature(slice: &[u8]) -> Result<ecdsa::Signature> {
+  if slice.len() != SECP256K1_SIGNATURE_LENGTH {
+    return Err(Error::InvalidSigLength(slice.len(), SECP256K1_SIGNATURE_LENGTH));
+  }
+
+  ecdsa::Signature::from_bytes(slice).map_err(|_| Error::InvalidProofValue("secp256k1"))
+}
+
+#[cfg(all(test, feature = "secp256k1"))]
+mod tests {
+  use crate::crypto::KeyPair;
+  use crate::crypto::KeyType;
+  use crate::crypto::Secp256k1;
+  use crate::crypto::Sign;
+  use crate::crypto::Verify;
+
+  #[test]
+  fn test_secp256k1_can_sign_and_verify() {
+    let keypair: KeyPair = KeyPair::new(KeyType::Secp256k1).unwrap();
+    let badpair: KeyPair = KeyPair::new(KeyType::Secp256k1).unwrap();
+
+    let signature: _ = Secp256k1::sign(b"hello", keypair.secret().as_ref()).unwrap();
+
+    assert!(Secp256k1::verify(b"hello", &signature, keypair.public().as_ref()).is_ok());
+    assert!(Secp256k1::verify(b"world", &signature, keypair.public().as_ref()).is_err());
+    assert!(Secp256k1::verify(b"hello", &signature, badpair.public().as_ref()).is_err());
+  }
+}
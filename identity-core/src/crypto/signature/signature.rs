@@ -10,7 +10,12 @@ use serde::ser::Serializer;
 use serde::Serialize;
 use std::sync::atomic::{AtomicBool, Ordering};
 
+use crate::common::Timestamp;
+use crate::crypto::ProofPurpose;
+use crate::crypto::SignatureOptions;
 use crate::crypto::SignatureValue;
+use crate::crypto::VerifierOptions;
+use crate::error::Error;
 use crate::error::Result;
 
 /// A DID Document digital signature.
@@ -22,6 +27,20 @@ pub struct Signature {
   value: SignatureValue,
   #[serde(rename = "verificationMethod")]
   method: String,
+  #[serde(default)]
+  id: Option<String>,
+  #[serde(default, rename = "previousProof")]
+  previous: Option<String>,
+  #[serde(default)]
+  created: Option<Timestamp>,
+  #[serde(default)]
+  expires: Option<Timestamp>,
+  #[serde(default, rename = "proofPurpose")]
+  purpose: Option<ProofPurpose>,
+  #[serde(default)]
+  challenge: Option<String>,
+  #[serde(default)]
+  domain: Option<String>,
   #[serde(default, skip_deserializing)]
   hidden: AtomicBoolCell,
 }
@@ -29,10 +48,23 @@ pub struct Signature {
 impl Signature {
   /// Creates a new [`Signature`] instance with the given `type_` and `method`.
   pub fn new(type_: impl Into<String>, method: impl Into<String>) -> Self {
+    Self::new_with_options(type_, method, SignatureOptions::new())
+  }
+
+  /// Creates a new [`Signature`] instance with the given `type_`, `method`,
+  /// and proof `options`.
+  pub fn new_with_options(type_: impl Into<String>, method: impl Into<String>, options: SignatureOptions) -> Self {
     Self {
       type_: type_.into(),
       value: SignatureValue::None,
       method: method.into(),
+      id: None,
+      previous: None,
+      created: options.created,
+      expires: options.expires,
+      purpose: options.purpose,
+      challenge: options.challenge,
+      domain: options.domain,
       hidden: AtomicBoolCell(AtomicBool::new(false)),
     }
   }
@@ -47,6 +79,96 @@ impl Signature {
     &*self.method
   }
 
+  /// Returns the `id` of the signature, if any.
+  ///
+  /// Signatures in a proof chain are referenced by their `id`.
+  pub fn id(&self) -> Option<&str> {
+    self.id.as_deref()
+  }
+
+  /// Sets the `id` of the signature.
+  pub fn set_id(&mut self, value: impl Into<String>) {
+    self.id = Some(value.into());
+  }
+
+  /// Returns the `id` of the signature preceding this one in a proof chain.
+  pub fn previous_proof(&self) -> Option<&str> {
+    self.previous.as_deref()
+  }
+
+  /// Sets the `id` of the signature preceding this one in a proof chain.
+  pub fn set_previous_proof(&mut self, value: impl Into<String>) {
+    self.previous = Some(value.into());
+  }
+
+  /// Returns the time the signature was created, if any.
+  pub fn created(&self) -> Option<Timestamp> {
+    self.created
+  }
+
+  /// Returns the time the signature expires, if any.
+  pub fn expires(&self) -> Option<Timestamp> {
+    self.expires
+  }
+
+  /// Returns the intended purpose of the signature, if any.
+  pub fn purpose(&self) -> Option<ProofPurpose> {
+    self.purpose
+  }
+
+  /// Returns the challenge of the signature, if any.
+  pub fn challenge(&self) -> Option<&str> {
+    self.challenge.as_deref()
+  }
+
+  /// Returns the operational domain of the signature, if any.
+  pub fn domain(&self) -> Option<&str> {
+    self.domain.as_deref()
+  }
+
+  /// Checks the proof options of the signature against the expectations of
+  /// the verifier.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the signature was created in the future, has expired, or an
+  /// expected purpose, challenge, or domain does not match. A `created`
+  /// timestamp within the clock skew of the `options` is not in the future.
+  ///
+  /// The purpose is only compared with the expected purpose - the verifier is
+  /// responsible for checking that the verification method belongs to the
+  /// matching verification relationship.
+  pub fn verify_options(&self, options: &VerifierOptions) -> Result<()> {
+    let now: Timestamp = Timestamp::now_utc();
+    let skew: u32 = options.clock_skew.unwrap_or(VerifierOptions::DEFAULT_CLOCK_SKEW);
+
+    if let Some(created) = self.created {
+      if created.to_unix() > now.to_unix() + i64::from(skew) {
+        return Err(Error::InvalidProofValue("created"));
+      }
+    }
+
+    if let Some(expires) = self.expires {
+      if expires <= now {
+        return Err(Error::InvalidProofValue("expired"));
+      }
+    }
+
+    if options.purpose.is_some() && options.purpose != self.purpose {
+      return Err(Error::InvalidProofValue("proof purpose"));
+    }
+
+    if options.challenge.is_some() && options.challenge != self.challenge {
+      return Err(Error::InvalidProofValue("challenge"));
+    }
+
+    if options.domain.is_some() && options.domain != self.domain {
+      return Err(Error::InvalidProofValue("domain"));
+    }
+
+    Ok(())
+  }
+
   /// Returns a reference to the signature `value`.
   pub const fn value(&self) -> &SignatureValue {
     &self.value
@@ -88,6 +210,13 @@ impl Debug for Signature {
       .field("type_", &self.type_)
       .field("value", &self.value)
       .field("method", &self.method)
+      .field("id", &self.id)
+      .field("previous", &self.previous)
+      .field("created", &self.created)
+      .field("expires", &self.expires)
+      .field("purpose", &self.purpose)
+      .field("challenge", &self.challenge)
+      .field("domain", &self.domain)
       .finish()
   }
 }
@@ -106,8 +235,37 @@ impl Serialize for Signature {
     };
 
     state.serialize_entry("type", &self.type_)?;
+
+    if let Some(id) = self.id.as_ref() {
+      state.serialize_entry("id", id)?;
+    }
+
+    if let Some(created) = self.created.as_ref() {
+      state.serialize_entry("created", created)?;
+    }
+
+    if let Some(expires) = self.expires.as_ref() {
+      state.serialize_entry("expires", expires)?;
+    }
+
     state.serialize_entry("verificationMethod", &self.method)?;
 
+    if let Some(purpose) = self.purpose.as_ref() {
+      state.serialize_entry("proofPurpose", purpose)?;
+    }
+
+    if let Some(challenge) = self.challenge.as_ref() {
+      state.serialize_entry("challenge", challenge)?;
+    }
+
+    if let Some(domain) = self.domain.as_ref() {
+      state.serialize_entry("domain", domain)?;
+    }
+
+    if let Some(previous) = self.previous.as_ref() {
+      state.serialize_entry("previousProof", previous)?;
+    }
+
     if !hide {
       Serialize::serialize(&self.value, FlatMapSerializer(&mut state))?;
     }
@@ -164,3 +322,39 @@ impl Ord for AtomicBoolCell {
     self.0.load(Ordering::Relaxed).cmp(&other.0.load(Ordering::Relaxed))
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::common::Timestamp;
+  use crate::crypto::Signature;
+  use crate::crypto::SignatureOptions;
+  use crate::crypto::VerifierOptions;
+
+  fn created_in(seconds: i64) -> Signature {
+    let created: Timestamp = Timestamp::from_unix(Timestamp::now_utc().to_unix() + seconds);
+
+    Signature::new_with_options("Test", "#key-1", SignatureOptions::new().created(created))
+  }
+
+  #[test]
+  fn test_verify_options_clock_skew() {
+    let skew: i64 = VerifierOptions::DEFAULT_CLOCK_SKEW.into();
+    let options: VerifierOptions = VerifierOptions::new();
+
+    assert!(created_in(-60).verify_options(&options).is_ok());
+    assert!(created_in(skew - 5).verify_options(&options).is_ok());
+    assert!(created_in(skew + 5).verify_options(&options).is_err());
+
+    // The tolerance can be disabled
+    let options: VerifierOptions = VerifierOptions::new().clock_skew(0);
+
+    assert!(created_in(-5).verify_options(&options).is_ok());
+    assert!(created_in(5).verify_options(&options).is_err());
+
+    // The tolerance can be extended
+    let options: VerifierOptions = VerifierOptions::new().clock_skew(3600);
+
+    assert!(created_in(3595).verify_options(&options).is_ok());
+    assert!(created_in(3605).verify_options(&options).is_err());
+  }
+}
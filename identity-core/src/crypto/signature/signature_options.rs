@@ -0,0 +1,138 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::Timestamp;
+
+/// The intended purpose of a [`Signature`][crate::crypto::Signature].
+///
+/// [More Info](https://w3id.org/security#proofPurpose)
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ProofPurpose {
+  /// The proof asserts a claim, e.g. the issuance of a credential.
+  AssertionMethod,
+  /// The proof authenticates the controller, e.g. the holder of a presentation.
+  Authentication,
+  /// The proof invokes a capability.
+  CapabilityInvocation,
+  /// The proof delegates a capability.
+  CapabilityDelegation,
+  /// The proof is used for key agreement.
+  KeyAgreement,
+}
+
+// =============================================================================
+// =============================================================================
+
+/// Options applied to a [`Signature`][crate::crypto::Signature] when it is
+/// created.
+///
+/// All options are covered by the signature value.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SignatureOptions {
+  pub(crate) created: Option<Timestamp>,
+  pub(crate) expires: Option<Timestamp>,
+  pub(crate) purpose: Option<ProofPurpose>,
+  pub(crate) challenge: Option<String>,
+  pub(crate) domain: Option<String>,
+}
+
+impl SignatureOptions {
+  /// Creates a new `SignatureOptions` instance with no options set.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the time the signature was created.
+  pub fn created(mut self, value: Timestamp) -> Self {
+    self.created = Some(value);
+    self
+  }
+
+  /// Sets the time the signature expires.
+  pub fn expires(mut self, value: Timestamp) -> Self {
+    self.expires = Some(value);
+    self
+  }
+
+  /// Sets the intended purpose of the signature.
+  pub fn purpose(mut self, value: ProofPurpose) -> Self {
+    self.purpose = Some(value);
+    self
+  }
+
+  /// Sets the challenge issued by the verifier, typically a nonce.
+  ///
+  /// Prevents the signed data from being replayed.
+  pub fn challenge(mut self, value: impl Into<String>) -> Self {
+    self.challenge = Some(value.into());
+    self
+  }
+
+  /// Sets the operational domain the signature is restricted to.
+  ///
+  /// Prevents the signed data from being used with a different verifier.
+  pub fn domain(mut self, value: impl Into<String>) -> Self {
+    self.domain = Some(value.into());
+    self
+  }
+}
+
+// =============================================================================
+// =============================================================================
+
+/// Options enforced when a [`Signature`][crate::crypto::Signature] is verified.
+///
+/// Expired signatures are always rejected.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VerifierOptions {
+  pub(crate) purpose: Option<ProofPurpose>,
+  pub(crate) challenge: Option<String>,
+  pub(crate) domain: Option<String>,
+  pub(crate) nonce: Option<Vec<u8>>,
+  pub(crate) clock_skew: Option<u32>,
+}
+
+impl VerifierOptions {
+  /// The default number of seconds a signature may be created ahead of the
+  /// clock of the verifier.
+  pub const DEFAULT_CLOCK_SKEW: u32 = 300;
+
+  /// Creates a new `VerifierOptions` instance with no expectations set.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the expected purpose of the signature.
+  pub fn purpose(mut self, value: ProofPurpose) -> Self {
+    self.purpose = Some(value);
+    self
+  }
+
+  /// Sets the expected challenge of the signature.
+  pub fn challenge(mut self, value: impl Into<String>) -> Self {
+    self.challenge = Some(value.into());
+    self
+  }
+
+  /// Sets the expected domain of the signature.
+  pub fn domain(mut self, value: impl Into<String>) -> Self {
+    self.domain = Some(value.into());
+    self
+  }
+
+  /// Sets the nonce issued by the verifier for a selective disclosure proof.
+  ///
+  /// Proofs bound to any other nonce are rejected.
+  pub fn nonce(mut self, value: impl Into<Vec<u8>>) -> Self {
+    self.nonce = Some(value.into());
+    self
+  }
+
+  /// Sets the number of seconds a signature may be created ahead of the clock
+  /// of the verifier, see [`VerifierOptions::DEFAULT_CLOCK_SKEW`].
+  pub fn clock_skew(mut self, seconds: u32) -> Self {
+    self.clock_skew = Some(seconds);
+    self
+  }
+}
@@ -3,10 +3,17 @@
 
 use serde::Serialize;
 
+use crate::crypto::signature::batch::verify_each;
+use crate::crypto::BatchError;
 use crate::crypto::SetSignature;
 use crate::crypto::Signature;
+use crate::crypto::SignatureAppend;
+use crate::crypto::SignatureOptions;
+use crate::crypto::SignatureSet;
 use crate::crypto::SignatureValue;
+use crate::crypto::SignatureView;
 use crate::crypto::TrySignature;
+use crate::crypto::VerifierOptions;
 use crate::error::Error;
 use crate::error::Result;
 
@@ -32,6 +39,16 @@ pub trait Verify {
 
   /// Verifies the authenticity of `data` and `signature` with `key`.
   fn verify(message: &[u8], signature: &[u8], key: &Self::Public) -> Result<()>;
+
+  /// Verifies a batch of `(message, signature, key)` triples.
+  ///
+  /// # Errors
+  ///
+  /// Fails with [`Error::InvalidBatch`] holding the result of each item if
+  /// any signature is invalid.
+  fn verify_batch(items: &[(&[u8], &[u8], &Self::Public)]) -> Result<()> {
+    verify_each::<Self>(items)
+  }
 }
 
 // =============================================================================
@@ -58,7 +75,21 @@ pub trait Signer<Secret: ?Sized>: Named {
   where
     T: Serialize + SetSignature,
   {
-    data.set_signature(Signature::new(Self::NAME, method));
+    Self::create_signature_with_options(data, method, secret, SignatureOptions::new())
+  }
+
+  /// Creates and applies a [signature][`Signature`] with the given proof
+  /// `options` to the given `data`.
+  fn create_signature_with_options<T>(
+    data: &mut T,
+    method: &str,
+    secret: &Secret,
+    options: SignatureOptions,
+  ) -> Result<()>
+  where
+    T: Serialize + SetSignature,
+  {
+    data.set_signature(Signature::new_with_options(Self::NAME, method, options));
 
     let value: SignatureValue = Self::sign(&data, secret)?;
     let write: &mut Signature = data.try_signature_mut()?;
@@ -67,6 +98,25 @@ pub trait Signer<Secret: ?Sized>: Named {
 
     Ok(())
   }
+
+  /// Creates a [signature][`Signature`] and adds it to the proof set of `data`.
+  ///
+  /// The signature covers `data` without any of the existing signatures.
+  fn append_signature<T>(data: &mut T, method: &str, secret: &Secret) -> Result<()>
+  where
+    T: Serialize + SignatureSet,
+  {
+    SignatureAppend::set(data)?.apply(|target| Self::create_signature(target, method, secret))
+  }
+
+  /// Creates a [signature][`Signature`] over the last signature of `data` and
+  /// adds it to the proof chain of `data`.
+  fn chain_signature<T>(data: &mut T, method: &str, secret: &Secret) -> Result<()>
+  where
+    T: Serialize + SignatureSet,
+  {
+    SignatureAppend::chain(data)?.apply(|target| Self::create_signature(target, method, secret))
+  }
 }
 
 // =============================================================================
@@ -80,7 +130,21 @@ pub trait Verifier<Public: ?Sized>: Named {
     T: Serialize;
 
   /// Extracts and verifies a [signature][`Signature`] from the given `data`.
+  ///
+  /// Fails if the signature has expired.
   fn verify_signature<T>(data: &T, public: &Public) -> Result<()>
+  where
+    T: Serialize + TrySignature,
+  {
+    Self::verify_signature_with_options(data, public, &VerifierOptions::new())
+  }
+
+  /// Extracts and verifies a [signature][`Signature`] from the given `data`,
+  /// enforcing the given proof `options`.
+  ///
+  /// Fails if the signature has expired or does not match the expected
+  /// purpose, challenge, or domain.
+  fn verify_signature_with_options<T>(data: &T, public: &Public, options: &VerifierOptions) -> Result<()>
   where
     T: Serialize + TrySignature,
   {
@@ -90,12 +154,43 @@ pub trait Verifier<Public: ?Sized>: Named {
       return Err(Error::InvalidProofValue("signature name"));
     }
 
+    signature.verify_options(options)?;
+
     signature.hide_value();
 
-    Self::verify(&data, signature.value(), public)?;
+    let result: Result<()> = Self::verify(&data, signature.value(), public);
 
     signature.show_value();
 
-    Ok(())
+    result
+  }
+
+  /// Extracts and verifies the [signatures][`Signature`] of a batch of
+  /// `(data, public)` pairs, enforcing the given proof `options`.
+  ///
+  /// # Errors
+  ///
+  /// Fails with [`Error::InvalidBatch`] holding the result of each item if
+  /// any signature is invalid.
+  fn verify_signature_batch<T>(items: &[(&T, &Public)], options: &VerifierOptions) -> Result<()>
+  where
+    T: Serialize + TrySignature,
+  {
+    BatchError::check(
+      items
+        .iter()
+        .map(|(data, public)| Self::verify_signature_with_options(*data, public, options))
+        .collect(),
+    )
+    .map_err(Error::InvalidBatch)
+  }
+
+  /// Verifies the [signature][`Signature`] at the specified `index` of the
+  /// signature set of `data`.
+  fn verify_signature_at<T>(data: &T, index: usize, public: &Public) -> Result<()>
+  where
+    T: Serialize + SignatureSet,
+  {
+    Self::verify_signature(&SignatureView::new(data, index), public)
   }
 }
@@ -1,6 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::common::OneOrMany;
 use crate::crypto::Signature;
 use crate::error::Error;
 use crate::error::Result;
@@ -27,6 +28,10 @@ where
   fn signature(&self) -> Option<&Signature> {
     (**self).signature()
   }
+
+  fn try_signature(&self) -> Result<&Signature> {
+    (**self).try_signature()
+  }
 }
 
 impl<'a, T> TrySignature for &'a mut T
@@ -36,6 +41,10 @@ where
   fn signature(&self) -> Option<&Signature> {
     (**self).signature()
   }
+
+  fn try_signature(&self) -> Result<&Signature> {
+    (**self).try_signature()
+  }
 }
 
 // =============================================================================
@@ -63,6 +72,10 @@ where
   fn signature_mut(&mut self) -> Option<&mut Signature> {
     (**self).signature_mut()
   }
+
+  fn try_signature_mut(&mut self) -> Result<&mut Signature> {
+    (**self).try_signature_mut()
+  }
 }
 
 // =============================================================================
@@ -82,3 +95,57 @@ where
     (**self).set_signature(signature);
   }
 }
+
+// =============================================================================
+// =============================================================================
+
+/// A trait for types that can store a set of digital [signatures][`Signature`].
+///
+/// Signatures in the set are either independent of each other (a proof set)
+/// or reference the signature they were created over (a proof chain).
+pub trait SignatureSet {
+  /// Returns a reference to the set of [`Signature`] objects.
+  fn signatures(&self) -> &OneOrMany<Signature>;
+
+  /// Returns a mutable reference to the set of [`Signature`] objects.
+  fn signatures_mut(&mut self) -> &mut OneOrMany<Signature>;
+
+  /// Returns a reference to the only [`Signature`] object of the set.
+  ///
+  /// Errors
+  ///
+  /// Fails if the set is empty or contains more than one signature.
+  fn try_single_signature(&self) -> Result<&Signature> {
+    match self.signatures().as_slice() {
+      [] => Err(Error::MissingSignature),
+      [signature] => Ok(signature),
+      signatures => Err(Error::AmbiguousSignature(signatures.len())),
+    }
+  }
+
+  /// Returns a mutable reference to the only [`Signature`] object of the set.
+  ///
+  /// Errors
+  ///
+  /// Fails if the set is empty or contains more than one signature.
+  fn try_single_signature_mut(&mut self) -> Result<&mut Signature> {
+    match self.signatures().len() {
+      0 => Err(Error::MissingSignature),
+      1 => self.signatures_mut().get_mut(0).ok_or(Error::MissingSignature),
+      count => Err(Error::AmbiguousSignature(count)),
+    }
+  }
+}
+
+impl<'a, T> SignatureSet for &'a mut T
+where
+  T: SignatureSet,
+{
+  fn signatures(&self) -> &OneOrMany<Signature> {
+    (**self).signatures()
+  }
+
+  fn signatures_mut(&mut self) -> &mut OneOrMany<Signature> {
+    (**self).signatures_mut()
+  }
+}
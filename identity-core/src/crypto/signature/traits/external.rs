@@ -0,0 +1,101 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Serialize;
+
+use crate::crypto::Ed25519;
+use crate::crypto::KeyPair;
+use crate::crypto::KeyType;
+use crate::crypto::Named;
+use crate::crypto::Secp256k1;
+use crate::crypto::SecretKey;
+use crate::crypto::SetSignature;
+use crate::crypto::Sign;
+use crate::crypto::Signature;
+use crate::crypto::SignatureOptions;
+use crate::crypto::SignatureValue;
+use crate::crypto::P256;
+use crate::error::Error;
+use crate::error::Result;
+
+/// A common interface for signers that hold their secret key outside of the
+/// process, e.g. in an HSM, a KMS, or a Stronghold vault.
+#[async_trait::async_trait]
+pub trait ExternalSigner: Send + Sync {
+  /// Signs the given `message` and returns the raw signature.
+  async fn sign(&self, message: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// Signs with the secret key of the `KeyPair`, held in process.
+#[async_trait::async_trait]
+impl ExternalSigner for KeyPair {
+  async fn sign(&self, message: &[u8]) -> Result<Vec<u8>> {
+    match self.type_() {
+      KeyType::Ed25519 => Ed25519::<SecretKey>::sign(message, self.secret()).map(|signature| signature.to_vec()),
+      KeyType::Secp256k1 => Secp256k1::<SecretKey>::sign(message, self.secret()).map(|signature| signature.to_vec()),
+      KeyType::P256 => P256::<SecretKey>::sign(message, self.secret()).map(|signature| signature.to_vec()),
+      KeyType::Bls12381G2 | KeyType::X25519 => Err(Error::InvalidKeyFormat),
+    }
+  }
+}
+
+// =============================================================================
+// =============================================================================
+
+/// A common interface for signature suites that can create signatures with an
+/// [`ExternalSigner`].
+#[async_trait::async_trait]
+pub trait ExternalSign: Named {
+  /// Returns the message signed by the suite for the given `data`.
+  fn message<T>(data: &T) -> Result<Vec<u8>>
+  where
+    T: Serialize;
+
+  /// Encodes the raw `signature` as a [`SignatureValue`].
+  fn signature_value(signature: &[u8]) -> SignatureValue;
+
+  /// Creates and applies a [signature][`Signature`] with the given proof
+  /// `options` to the given `data`, delegating the signature operation to
+  /// `signer`.
+  async fn create_signature_external<T, S>(
+    data: &mut T,
+    method: &str,
+    signer: &S,
+    options: SignatureOptions,
+  ) -> Result<()>
+  where
+    T: Serialize + SetSignature + Send,
+    S: ExternalSigner + ?Sized,
+  {
+    data.set_signature(Signature::new_with_options(Self::NAME, method, options));
+
+    let message: Vec<u8> = Self::message(&data)?;
+    let signature: Vec<u8> = signer.sign(&message).await?;
+    let write: &mut Signature = data.try_signature_mut()?;
+
+    write.set_value(Self::signature_value(&signature));
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use futures::executor::block_on;
+
+  use super::*;
+  use crate::crypto::PublicKey;
+  use crate::crypto::Verify;
+
+  #[test]
+  fn test_keypair_sign() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let signature: Vec<u8> = block_on(ExternalSigner::sign(&keypair, b"IOTA")).unwrap();
+
+    assert!(Ed25519::<PublicKey>::verify(b"IOTA", &signature, keypair.public()).is_ok());
+
+    let keypair: KeyPair = KeyPair::new(KeyType::X25519).unwrap();
+
+    assert!(block_on(ExternalSigner::sign(&keypair, b"IOTA")).is_err());
+  }
+}
@@ -3,6 +3,7 @@
 
 mod core;
 mod data;
+mod external;
 
 pub use self::core::Named;
 pub use self::core::Sign;
@@ -10,5 +11,8 @@ pub use self::core::Signer;
 pub use self::core::Verifier;
 pub use self::core::Verify;
 pub use self::data::SetSignature;
+pub use self::data::SignatureSet;
 pub use self::data::TrySignature;
 pub use self::data::TrySignatureMut;
+pub use self::external::ExternalSign;
+pub use self::external::ExternalSigner;
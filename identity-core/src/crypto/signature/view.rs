@@ -0,0 +1,249 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::mem::take;
+use serde::ser::Error as _;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::common::Object;
+use crate::common::OneOrMany;
+use crate::common::Value;
+use crate::convert::ToJson;
+use crate::crypto::SetSignature;
+use crate::crypto::Signature;
+use crate::crypto::SignatureSet;
+use crate::crypto::TrySignature;
+use crate::crypto::TrySignatureMut;
+use crate::error::Error;
+use crate::error::Result;
+
+/// A view of a single [`Signature`] in a [`SignatureSet`].
+///
+/// The view serializes as the underlying data with the `proof` property
+/// replaced by the selected signature, preceded by the signature it was
+/// chained to (if any). This is the data covered by the selected signature.
+pub struct SignatureView<'a, T> {
+  data: &'a T,
+  index: usize,
+}
+
+impl<'a, T> SignatureView<'a, T> {
+  /// Creates a new `SignatureView` of the signature at the specified `index`.
+  pub const fn new(data: &'a T, index: usize) -> Self {
+    Self { data, index }
+  }
+}
+
+impl<T> TrySignature for SignatureView<'_, T>
+where
+  T: SignatureSet,
+{
+  fn signature(&self) -> Option<&Signature> {
+    self.data.signatures().get(self.index)
+  }
+}
+
+impl<T> Serialize for SignatureView<'_, T>
+where
+  T: Serialize + SignatureSet,
+{
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serialize_view(self.data, self.index, serializer)
+  }
+}
+
+// =============================================================================
+// =============================================================================
+
+/// A wrapper used to add a new [`Signature`] to a [`SignatureSet`] with the
+/// [`Signer`][crate::crypto::Signer] API.
+///
+/// The new signature is assigned a unique `id`. A chained signature also
+/// references the `id` of the signature it was created over.
+pub struct SignatureAppend<'a, T> {
+  data: &'a mut T,
+  id: String,
+  previous: Option<String>,
+  index: Option<usize>,
+}
+
+impl<'a, T> SignatureAppend<'a, T>
+where
+  T: SignatureSet,
+{
+  /// Creates a new `SignatureAppend` that adds an independent signature to
+  /// the proof set of `data`.
+  pub fn set(data: &'a mut T) -> Result<Self> {
+    Ok(Self {
+      data,
+      id: generate_id()?,
+      previous: None,
+      index: None,
+    })
+  }
+
+  /// Creates a new `SignatureAppend` that adds a signature covering the last
+  /// signature of `data` (a proof chain).
+  ///
+  /// # Errors
+  ///
+  /// Fails if `data` has no signatures or the last signature has no `id`.
+  pub fn chain(data: &'a mut T) -> Result<Self> {
+    let previous: String = data
+      .signatures()
+      .iter()
+      .last()
+      .ok_or(Error::MissingSignature)?
+      .id()
+      .ok_or(Error::InvalidProofValue("previous proof id"))?
+      .to_string();
+
+    Ok(Self {
+      data,
+      id: generate_id()?,
+      previous: Some(previous),
+      index: None,
+    })
+  }
+
+  /// Calls `f` with `self`, removing the new signature from the set if `f`
+  /// fails.
+  pub fn apply<F, E>(mut self, f: F) -> Result<(), E>
+  where
+    F: FnOnce(&mut Self) -> Result<(), E>,
+  {
+    let result: Result<(), E> = f(&mut self);
+
+    if result.is_err() {
+      self.rollback();
+    }
+
+    result
+  }
+
+  /// Removes the new signature from the set, e.g. after a failed signature
+  /// operation.
+  pub fn rollback(&mut self) {
+    if let Some(index) = self.index.take() {
+      let mut signatures: Vec<Signature> = take(self.data.signatures_mut()).into_vec();
+      signatures.remove(index);
+      *self.data.signatures_mut() = signatures.into();
+    }
+  }
+}
+
+impl<T> TrySignature for SignatureAppend<'_, T>
+where
+  T: SignatureSet,
+{
+  fn signature(&self) -> Option<&Signature> {
+    self.index.and_then(|index| self.data.signatures().get(index))
+  }
+}
+
+impl<T> TrySignatureMut for SignatureAppend<'_, T>
+where
+  T: SignatureSet,
+{
+  fn signature_mut(&mut self) -> Option<&mut Signature> {
+    let index: usize = self.index?;
+    self.data.signatures_mut().get_mut(index)
+  }
+}
+
+impl<T> SetSignature for SignatureAppend<'_, T>
+where
+  T: SignatureSet,
+{
+  fn set_signature(&mut self, mut signature: Signature) {
+    signature.set_id(self.id.clone());
+
+    if let Some(previous) = self.previous.as_ref() {
+      signature.set_previous_proof(previous.clone());
+    }
+
+    if let Some(current) = self.signature_mut() {
+      *current = signature;
+    } else {
+      let mut signatures: Vec<Signature> = take(self.data.signatures_mut()).into_vec();
+      signatures.push(signature);
+      self.index = Some(signatures.len() - 1);
+      *self.data.signatures_mut() = signatures.into();
+    }
+  }
+}
+
+impl<T> Serialize for SignatureAppend<'_, T>
+where
+  T: Serialize + SignatureSet,
+{
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    match self.index {
+      Some(index) => serialize_view(&*self.data, index, serializer),
+      None => Err(S::Error::custom("signature not found")),
+    }
+  }
+}
+
+// =============================================================================
+// =============================================================================
+
+fn serialize_view<T, S>(data: &T, index: usize, serializer: S) -> Result<S::Ok, S::Error>
+where
+  T: Serialize + SignatureSet,
+  S: Serializer,
+{
+  let signatures: &OneOrMany<Signature> = data.signatures();
+  let current: &Signature = signatures
+    .get(index)
+    .ok_or_else(|| S::Error::custom("signature not found"))?;
+
+  let proof: Value = match current.previous_proof() {
+    Some(id) => {
+      // A chained signature can only reference a signature created before it.
+      let previous: &Signature = signatures[..index]
+        .iter()
+        .find(|signature| signature.id() == Some(id))
+        .ok_or_else(|| S::Error::custom("previous proof not found"))?;
+
+      [previous, current].to_json_value().map_err(S::Error::custom)?
+    }
+    None => current.to_json_value().map_err(S::Error::custom)?,
+  };
+
+  let mut object: Object = match data.to_json_value().map_err(S::Error::custom)? {
+    Value::Object(object) => object.into_iter().collect(),
+    _ => return Err(S::Error::custom("expected an object")),
+  };
+
+  object.insert("proof".into(), proof);
+  object.serialize(serializer)
+}
+
+// Generates a random `urn:uuid` identifier (UUID version 4).
+fn generate_id() -> Result<String> {
+  let mut bytes: [u8; 16] = [0; 16];
+
+  crypto::utils::rand::fill(&mut bytes)?;
+
+  bytes[6] = (bytes[6] & 0x0f) | 0x40;
+  bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+  let hex: String = hex::encode(bytes);
+
+  Ok(format!(
+    "urn:uuid:{}-{}-{}-{}-{}",
+    &hex[0..8],
+    &hex[8..12],
+    &hex[12..16],
+    &hex[16..20],
+    &hex[20..32]
+  ))
+}
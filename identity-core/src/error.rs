@@ -8,6 +8,7 @@ pub type Result<T, E = Error> = ::core::result::Result<T, E>;
 
 use crate::crypto::merkle_key::MerkleDigestTag;
 use crate::crypto::merkle_key::MerkleSignatureTag;
+use crate::crypto::BatchError;
 
 /// This type represents all possible errors that can occur in the library.
 #[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
@@ -15,12 +16,22 @@ pub enum Error {
   /// Caused when a cryptographic operation fails.
   #[error("Crypto Error: {0}")]
   Crypto(crypto::Error),
+  /// Caused by errors from the `libjose` crate.
+  #[cfg(feature = "jose")]
+  #[error("JOSE Error: {0}")]
+  Jose(#[from] libjose::Error),
   /// Caused by a failure to encode Rust types as JSON.
   #[error("Failed to encode JSON: {0}")]
   EncodeJSON(serde_json::Error),
   /// Caused by a failure to decode Rust types from JSON.
   #[error("Failed to decode JSON: {0}")]
   DecodeJSON(serde_json::Error),
+  /// Caused by a failure to encode Rust types as CBOR.
+  #[error("Failed to encode CBOR: {0}")]
+  EncodeCBOR(String),
+  /// Caused by a failure to decode Rust types from CBOR.
+  #[error("Failed to decode CBOR: {0}")]
+  DecodeCBOR(String),
   /// Caused by a failure to decode base16-encoded data.
   #[error("Failed to decode base16 data: {0}")]
   DecodeBase16(#[from] hex::FromHexError),
@@ -33,12 +44,21 @@ pub enum Error {
   /// Caused by a failure to decode multibase-encoded data.
   #[error("Failed to decode multibase data: {0}")]
   DecodeMultibase(#[from] multibase::Error),
+  /// Caused by a failure to decode multicodec-prefixed data.
+  #[error("Failed to decode multicodec data: {0}")]
+  DecodeMulticodec(&'static str),
   /// Cause by a failure to encode a Roaring Bitmap.
   #[error("Failed to encode roaring bitmap: {0}")]
   EncodeBitmap(std::io::Error),
   /// Cause by a failure to decode a Roaring Bitmap.
   #[error("Failed to decode roaring bitmap: {0}")]
   DecodeBitmap(std::io::Error),
+  /// Caused by a failure to encode a status list bitstring.
+  #[error("Failed to encode status list: {0}")]
+  EncodeStatusList(std::io::Error),
+  /// Caused by a failure to decode a status list bitstring.
+  #[error("Failed to decode status list: {0}")]
+  DecodeStatusList(&'static str),
   /// Caused by attempting to perform an invalid `Diff` operation.
   #[error("Invalid Document Diff: {0}")]
   InvalidDiff(#[from] identity_diff::Error),
@@ -69,12 +89,39 @@ pub enum Error {
   /// Caused by attempting to parse an invalid Merkle Signature Key Collection tag.
   #[error("Invalid Merkle Signature Key Tag: {0:?}")]
   InvalidMerkleSignatureKeyTag(Option<MerkleSignatureTag>),
+  /// Caused by a batch verification with at least one invalid signature.
+  #[error("Invalid Batch: {0}")]
+  InvalidBatch(BatchError),
   /// Caused by a failed attempt at retrieving a digital signature.
   #[error("Signature Not Found")]
   MissingSignature,
+  /// Caused by attempting to retrieve a single digital signature from a set of signatures.
+  #[error("Ambiguous Signature: found {0} signatures")]
+  AmbiguousSignature(usize),
+  /// Caused by attempting to create a key derivation seed of invalid length.
+  #[error("Invalid Seed Length: {0}")]
+  InvalidSeedLength(usize),
+  /// Caused by attempting to parse an invalid BIP-39 mnemonic phrase.
+  #[error("Invalid Mnemonic")]
+  InvalidMnemonic,
+  /// Caused by attempting to use an invalid SLIP-10 derivation path.
+  #[error("Invalid Derivation Path")]
+  InvalidDerivationPath,
   /// Caused by attempting to create a KeyCollection of invalid size.
   #[error("Invalid Key Collection Size: {0}")]
   InvalidKeyCollectionSize(usize),
+  /// Caused by attempting to create a KeyCollection of keys that cannot sign.
+  #[error("Invalid Key Collection Type: {}", .0.as_str())]
+  InvalidKeyCollectionType(crate::crypto::KeyType),
+  /// Caused by a failure to process a JSON-LD document.
+  #[error("Invalid JSON-LD: {0}")]
+  InvalidJsonLd(&'static str),
+  /// Caused by a failure to load a remote JSON-LD context.
+  #[error("Unknown JSON-LD Context: {0}")]
+  UnknownJsonLdContext(String),
+  /// Caused by attempting to use an algorithm of a disabled cargo feature.
+  #[error("Missing Feature: {0}")]
+  MissingFeature(&'static str),
 }
 
 impl From<crypto::Error> for Error {
@@ -0,0 +1,520 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use serde_json::Map;
+
+use crate::common::Value;
+use crate::error::Error;
+use crate::error::Result;
+use crate::jsonld::DocumentLoader;
+
+type JsonMap = Map<String, Value>;
+
+// The maximum depth of nested remote contexts.
+const MAX_REMOTE_CONTEXTS: usize = 32;
+
+const KEYWORDS: &[&str] = &[
+  "@base",
+  "@container",
+  "@context",
+  "@direction",
+  "@graph",
+  "@id",
+  "@import",
+  "@included",
+  "@index",
+  "@json",
+  "@language",
+  "@list",
+  "@nest",
+  "@none",
+  "@prefix",
+  "@propagate",
+  "@protected",
+  "@reverse",
+  "@set",
+  "@type",
+  "@value",
+  "@version",
+  "@vocab",
+];
+
+const CONTAINERS: &[&str] = &["@graph", "@id", "@index", "@language", "@list", "@set", "@type"];
+
+pub(crate) fn is_keyword(value: &str) -> bool {
+  KEYWORDS.contains(&value)
+}
+
+// Strings of the form `@[a-zA-Z]+` are reserved for future keywords and ignored.
+fn is_keyword_like(value: &str) -> bool {
+  value.len() > 1 && value.starts_with('@') && value[1..].bytes().all(|byte| byte.is_ascii_alphabetic())
+}
+
+fn is_absolute_iri(value: &str) -> bool {
+  match value.find(':') {
+    Some(index) if index > 0 => {
+      let scheme: &[u8] = &value.as_bytes()[..index];
+
+      scheme[0].is_ascii_alphabetic()
+        && scheme
+          .iter()
+          .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'+' | b'-' | b'.'))
+    }
+    _ => false,
+  }
+}
+
+fn is_gen_delim(value: char) -> bool {
+  matches!(value, ':' | '/' | '?' | '#' | '[' | ']' | '@')
+}
+
+/// A processed JSON-LD term definition.
+#[derive(Clone, Debug)]
+pub(crate) struct TermDefinition {
+  pub(crate) iri: Option<String>,
+  pub(crate) prefix: bool,
+  pub(crate) protected: bool,
+  pub(crate) type_: Option<String>,
+  pub(crate) language: Option<Option<String>>,
+  pub(crate) container: Vec<String>,
+  pub(crate) context: Option<Value>,
+}
+
+impl TermDefinition {
+  pub(crate) fn has_container(&self, container: &str) -> bool {
+    self.container.iter().any(|value| value == container)
+  }
+
+  // Term definitions are compared without the protected flag when checking
+  // for redefinitions of protected terms.
+  fn same_as(&self, other: &Self) -> bool {
+    self.iri == other.iri
+      && self.prefix == other.prefix
+      && self.type_ == other.type_
+      && self.language == other.language
+      && self.container == other.container
+      && self.context == other.context
+  }
+}
+
+/// A JSON-LD active context.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Context {
+  pub(crate) terms: BTreeMap<String, TermDefinition>,
+  pub(crate) vocab: Option<String>,
+  pub(crate) base: Option<String>,
+  pub(crate) language: Option<String>,
+  pub(crate) previous: Option<Box<Context>>,
+}
+
+impl Context {
+  pub(crate) fn term(&self, term: &str) -> Option<&TermDefinition> {
+    self.terms.get(term)
+  }
+
+  /// Expands the given `value` to an absolute IRI or keyword.
+  ///
+  /// Returns `None` if the value is explicitly mapped to `null`.
+  pub(crate) fn expand_iri(&self, value: &str, document_relative: bool, vocab: bool) -> Option<String> {
+    if is_keyword(value) {
+      return Some(value.to_string());
+    }
+
+    if is_keyword_like(value) {
+      return None;
+    }
+
+    if vocab {
+      if let Some(definition) = self.terms.get(value) {
+        return definition.iri.clone();
+      }
+    }
+
+    if let Some(index) = value.find(':') {
+      let (prefix, suffix): (&str, &str) = (&value[..index], &value[index + 1..]);
+
+      if prefix == "_" || suffix.starts_with("//") {
+        return Some(value.to_string());
+      }
+
+      if let Some(definition) = self.terms.get(prefix) {
+        if let (true, Some(iri)) = (definition.prefix, definition.iri.as_deref()) {
+          return Some(format!("{}{}", iri, suffix));
+        }
+      }
+
+      if is_absolute_iri(value) {
+        return Some(value.to_string());
+      }
+    }
+
+    if vocab {
+      if let Some(vocab) = self.vocab.as_deref() {
+        return Some(format!("{}{}", vocab, value));
+      }
+    }
+
+    if document_relative {
+      if let Some(base) = self.base.as_deref() {
+        if let Ok(url) = url::Url::parse(base).and_then(|base| base.join(value)) {
+          return Some(url.into());
+        }
+      }
+    }
+
+    Some(value.to_string())
+  }
+
+  /// Processes a local context and returns the updated active context.
+  pub(crate) fn process<L>(&self, local: &Value, loader: &L, override_protected: bool, propagate: bool) -> Result<Self>
+  where
+    L: DocumentLoader + ?Sized,
+  {
+    self.process_remote(local, loader, 0, override_protected, propagate)
+  }
+
+  fn process_remote<L>(
+    &self,
+    local: &Value,
+    loader: &L,
+    depth: usize,
+    override_protected: bool,
+    propagate: bool,
+  ) -> Result<Self>
+  where
+    L: DocumentLoader + ?Sized,
+  {
+    let mut result: Self = self.clone();
+
+    let propagate: bool = match local {
+      Value::Object(object) => match object.get("@propagate") {
+        Some(Value::Bool(value)) => *value,
+        Some(_) => return Err(Error::InvalidJsonLd("invalid @propagate value")),
+        None => propagate,
+      },
+      _ => propagate,
+    };
+
+    if !propagate && result.previous.is_none() {
+      result.previous = Some(Box::new(self.clone()));
+    }
+
+    let contexts: Vec<&Value> = match local {
+      Value::Array(values) => values.iter().collect(),
+      value => vec![value],
+    };
+
+    for context in contexts {
+      match context {
+        Value::Null => {
+          if !override_protected && result.terms.values().any(|term| term.protected) {
+            return Err(Error::InvalidJsonLd("invalid context nullification"));
+          }
+
+          result = Self {
+            previous: if propagate { None } else { Some(Box::new(self.clone())) },
+            ..Self::default()
+          };
+        }
+        Value::String(url) => {
+          if depth >= MAX_REMOTE_CONTEXTS {
+            return Err(Error::InvalidJsonLd("context overflow"));
+          }
+
+          let document: Value = loader.load(url)?;
+          let context: &Value = document
+            .get("@context")
+            .ok_or(Error::InvalidJsonLd("invalid remote context"))?;
+
+          result = result.process_remote(context, loader, depth + 1, override_protected, true)?;
+        }
+        Value::Object(object) => {
+          result.process_object(object, override_protected)?;
+        }
+        _ => return Err(Error::InvalidJsonLd("invalid local context")),
+      }
+    }
+
+    Ok(result)
+  }
+
+  fn process_object(&mut self, object: &JsonMap, override_protected: bool) -> Result<()> {
+    match object.get("@version") {
+      None => {}
+      Some(Value::Number(number)) if number.as_f64() == Some(1.1) => {}
+      Some(_) => return Err(Error::InvalidJsonLd("invalid @version value")),
+    }
+
+    if object.contains_key("@import") {
+      return Err(Error::InvalidJsonLd("@import is not supported"));
+    }
+
+    match object.get("@base") {
+      None => {}
+      Some(Value::Null) => self.base = None,
+      Some(Value::String(base)) => self.base = self.expand_iri(base, true, false),
+      Some(_) => return Err(Error::InvalidJsonLd("invalid base IRI")),
+    }
+
+    match object.get("@vocab") {
+      None => {}
+      Some(Value::Null) => self.vocab = None,
+      Some(Value::String(vocab)) => self.vocab = self.expand_iri(vocab, true, true),
+      Some(_) => return Err(Error::InvalidJsonLd("invalid vocab mapping")),
+    }
+
+    match object.get("@language") {
+      None => {}
+      Some(Value::Null) => self.language = None,
+      Some(Value::String(language)) => self.language = Some(language.to_lowercase()),
+      Some(_) => return Err(Error::InvalidJsonLd("invalid default language")),
+    }
+
+    let protected: bool = match object.get("@protected") {
+      None => false,
+      Some(Value::Bool(value)) => *value,
+      Some(_) => return Err(Error::InvalidJsonLd("invalid @protected value")),
+    };
+
+    let mut defined: HashMap<String, bool> = HashMap::new();
+
+    for term in object.keys() {
+      match term.as_str() {
+        "@base" | "@direction" | "@import" | "@language" | "@propagate" | "@protected" | "@version" | "@vocab" => {}
+        _ => self.define(object, term, &mut defined, protected, override_protected)?,
+      }
+    }
+
+    Ok(())
+  }
+
+  // Ensures that a term or compact IRI prefix referenced while creating a term
+  // definition has already been defined from the local context.
+  fn define_dependency(
+    &mut self,
+    local: &JsonMap,
+    value: &str,
+    defined: &mut HashMap<String, bool>,
+    protected: bool,
+    override_protected: bool,
+  ) -> Result<()> {
+    let term: &str = value.find(':').map(|index| &value[..index]).unwrap_or(value);
+
+    if local.contains_key(term) && !is_keyword(term) {
+      self.define(local, term, defined, protected, override_protected)?;
+    }
+
+    Ok(())
+  }
+
+  fn define(
+    &mut self,
+    local: &JsonMap,
+    term: &str,
+    defined: &mut HashMap<String, bool>,
+    protected: bool,
+    override_protected: bool,
+  ) -> Result<()> {
+    match defined.get(term) {
+      Some(true) => return Ok(()),
+      Some(false) => return Err(Error::InvalidJsonLd("cyclic IRI mapping")),
+      None => {}
+    }
+
+    if term.is_empty() {
+      return Err(Error::InvalidJsonLd("invalid term definition"));
+    }
+
+    defined.insert(term.to_string(), false);
+
+    let value: &Value = &local[term];
+
+    if term == "@type" {
+      // JSON-LD 1.1 only allows `@container: @set` and `@protected` for `@type`.
+      let valid: bool = match value {
+        Value::Object(object) => object.iter().all(|(key, value)| match key.as_str() {
+          "@container" => value == "@set",
+          "@protected" => value.is_boolean(),
+          _ => false,
+        }),
+        _ => false,
+      };
+
+      if !valid {
+        return Err(Error::InvalidJsonLd("keyword redefinition"));
+      }
+
+      defined.insert(term.to_string(), true);
+      return Ok(());
+    }
+
+    if is_keyword(term) {
+      return Err(Error::InvalidJsonLd("keyword redefinition"));
+    }
+
+    if is_keyword_like(term) {
+      defined.insert(term.to_string(), true);
+      return Ok(());
+    }
+
+    let previous: Option<TermDefinition> = self.terms.remove(term);
+
+    let mut simple: bool = false;
+    let object: JsonMap = match value {
+      Value::Null => {
+        let mut object: JsonMap = JsonMap::new();
+        object.insert("@id".into(), Value::Null);
+        object
+      }
+      Value::String(iri) => {
+        let mut object: JsonMap = JsonMap::new();
+        object.insert("@id".into(), Value::String(iri.clone()));
+        simple = true;
+        object
+      }
+      Value::Object(object) => object.clone(),
+      _ => return Err(Error::InvalidJsonLd("invalid term definition")),
+    };
+
+    let mut definition: TermDefinition = TermDefinition {
+      iri: None,
+      prefix: false,
+      protected: match object.get("@protected") {
+        None => protected,
+        Some(Value::Bool(value)) => *value,
+        Some(_) => return Err(Error::InvalidJsonLd("invalid @protected value")),
+      },
+      type_: None,
+      language: None,
+      container: Vec::new(),
+      context: None,
+    };
+
+    for key in object.keys() {
+      match key.as_str() {
+        "@id" | "@type" | "@container" | "@context" | "@language" | "@direction" | "@prefix" | "@protected" => {}
+        "@reverse" | "@nest" | "@index" => return Err(Error::InvalidJsonLd("unsupported term definition")),
+        _ => return Err(Error::InvalidJsonLd("invalid term definition")),
+      }
+    }
+
+    match object.get("@type") {
+      None => {}
+      Some(Value::String(type_)) => {
+        self.define_dependency(local, type_, defined, protected, override_protected)?;
+
+        let type_: String = self
+          .expand_iri(type_, false, true)
+          .filter(|type_| matches!(type_.as_str(), "@id" | "@json" | "@none" | "@vocab") || is_absolute_iri(type_))
+          .ok_or(Error::InvalidJsonLd("invalid type mapping"))?;
+
+        definition.type_ = Some(type_);
+      }
+      Some(_) => return Err(Error::InvalidJsonLd("invalid type mapping")),
+    }
+
+    match object.get("@id") {
+      Some(Value::Null) => {}
+      Some(Value::String(iri)) if iri != term => {
+        if !is_keyword(iri) && is_keyword_like(iri) {
+          defined.insert(term.to_string(), true);
+          return Ok(());
+        }
+
+        self.define_dependency(local, iri, defined, protected, override_protected)?;
+
+        let iri: String = self
+          .expand_iri(iri, false, true)
+          .filter(|iri| is_keyword(iri) || is_absolute_iri(iri) || iri.starts_with("_:"))
+          .ok_or(Error::InvalidJsonLd("invalid IRI mapping"))?;
+
+        if iri == "@context" {
+          return Err(Error::InvalidJsonLd("invalid keyword alias"));
+        }
+
+        definition.prefix = simple
+          && !term.contains(':')
+          && !term.contains('/')
+          && (iri.ends_with(is_gen_delim) || iri.starts_with("_:"));
+
+        definition.iri = Some(iri);
+      }
+      Some(Value::String(_)) | None => {
+        if let Some(index) = term.find(':') {
+          self.define_dependency(local, term, defined, protected, override_protected)?;
+
+          let prefix: &str = &term[..index];
+          let suffix: &str = &term[index + 1..];
+
+          definition.iri = match self.terms.get(prefix).and_then(|term| term.iri.as_deref()) {
+            Some(iri) => Some(format!("{}{}", iri, suffix)),
+            None => Some(term.to_string()),
+          };
+        } else if term.contains('/') {
+          return Err(Error::InvalidJsonLd("invalid IRI mapping"));
+        } else if let Some(vocab) = self.vocab.as_deref() {
+          definition.iri = Some(format!("{}{}", vocab, term));
+        } else {
+          return Err(Error::InvalidJsonLd("invalid IRI mapping"));
+        }
+      }
+      Some(_) => return Err(Error::InvalidJsonLd("invalid IRI mapping")),
+    }
+
+    match object.get("@container") {
+      None => {}
+      Some(Value::String(container)) if CONTAINERS.contains(&container.as_str()) => {
+        definition.container.push(container.clone());
+      }
+      Some(Value::Array(containers)) => {
+        for container in containers {
+          match container.as_str() {
+            Some(container) if CONTAINERS.contains(&container) => definition.container.push(container.to_string()),
+            _ => return Err(Error::InvalidJsonLd("invalid container mapping")),
+          }
+        }
+
+        definition.container.sort();
+      }
+      Some(_) => return Err(Error::InvalidJsonLd("invalid container mapping")),
+    }
+
+    if let Some(context) = object.get("@context") {
+      definition.context = Some(context.clone());
+    }
+
+    match object.get("@language") {
+      None => {}
+      Some(Value::Null) => definition.language = Some(None),
+      Some(Value::String(language)) => definition.language = Some(Some(language.to_lowercase())),
+      Some(_) => return Err(Error::InvalidJsonLd("invalid language mapping")),
+    }
+
+    match object.get("@prefix") {
+      None => {}
+      Some(Value::Bool(_)) if term.contains(':') || term.contains('/') => {
+        return Err(Error::InvalidJsonLd("invalid term definition"));
+      }
+      Some(Value::Bool(value)) => definition.prefix = *value,
+      Some(_) => return Err(Error::InvalidJsonLd("invalid @prefix value")),
+    }
+
+    if let Some(previous) = previous {
+      if !override_protected && previous.protected {
+        if !previous.same_as(&definition) {
+          return Err(Error::InvalidJsonLd("protected term redefinition"));
+        }
+
+        definition = previous;
+      }
+    }
+
+    self.terms.insert(term.to_string(), definition);
+    defined.insert(term.to_string(), true);
+
+    Ok(())
+  }
+}
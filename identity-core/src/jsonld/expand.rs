@@ -0,0 +1,421 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde_json::Map;
+
+use crate::common::Value;
+use crate::error::Error;
+use crate::error::Result;
+use crate::jsonld::context::is_keyword;
+use crate::jsonld::context::Context;
+use crate::jsonld::context::TermDefinition;
+use crate::jsonld::DocumentLoader;
+
+type JsonMap = Map<String, Value>;
+
+/// Expands a JSON-LD document and returns the expanded node objects.
+///
+/// Properties that do not expand to an absolute IRI are rejected instead of
+/// silently dropped, so every claim of a signed document is covered by the
+/// canonical form.
+pub(crate) fn expand<L>(document: &Value, loader: &L) -> Result<Vec<Value>>
+where
+  L: DocumentLoader + ?Sized,
+{
+  let expander: Expander<'_, L> = Expander { loader };
+
+  match expander.expand_element(&Context::default(), None, document)? {
+    Value::Null => Ok(Vec::new()),
+    Value::Object(mut object) if object.len() == 1 && object.contains_key("@graph") => {
+      Ok(into_array(object.remove("@graph").unwrap_or(Value::Null)))
+    }
+    Value::Array(values) => Ok(values),
+    value => Ok(vec![value]),
+  }
+}
+
+fn into_array(value: Value) -> Vec<Value> {
+  match value {
+    Value::Null => Vec::new(),
+    Value::Array(values) => values,
+    value => vec![value],
+  }
+}
+
+fn is_list_object(value: &Value) -> bool {
+  value
+    .as_object()
+    .map(|object| object.contains_key("@list"))
+    .unwrap_or(false)
+}
+
+fn is_graph_object(value: &Value) -> bool {
+  value
+    .as_object()
+    .map(|object| object.contains_key("@graph"))
+    .unwrap_or(false)
+}
+
+struct Expander<'a, L: ?Sized> {
+  loader: &'a L,
+}
+
+impl<L> Expander<'_, L>
+where
+  L: DocumentLoader + ?Sized,
+{
+  fn expand_element(&self, active: &Context, property: Option<&str>, element: &Value) -> Result<Value> {
+    match element {
+      Value::Null => Ok(Value::Null),
+      Value::Array(values) => {
+        let mut output: Vec<Value> = Vec::with_capacity(values.len());
+
+        for value in values {
+          match self.expand_element(active, property, value)? {
+            Value::Null => {}
+            Value::Array(values) => output.extend(values),
+            value => output.push(value),
+          }
+        }
+
+        Ok(Value::Array(output))
+      }
+      Value::Object(object) => self.expand_object(active, property, object),
+      scalar => match property {
+        None | Some("@graph") => Ok(Value::Null),
+        Some(property) => {
+          let definition: Option<&TermDefinition> = active.term(property);
+
+          match definition.and_then(|definition| definition.context.as_ref()) {
+            Some(context) => {
+              let scoped: Context = active.process(context, self.loader, true, true)?;
+              Ok(expand_value(&scoped, definition, scalar))
+            }
+            None => Ok(expand_value(active, definition, scalar)),
+          }
+        }
+      },
+    }
+  }
+
+  fn expand_object(&self, active: &Context, property: Option<&str>, object: &JsonMap) -> Result<Value> {
+    let scoped: Option<Value> = property
+      .and_then(|property| active.term(property))
+      .and_then(|definition| definition.context.clone());
+
+    let mut active: Context = active.clone();
+
+    // Type-scoped contexts do not propagate into nested node objects.
+    if let Some(previous) = active.previous.as_deref() {
+      let expanded: Vec<Option<String>> = object.keys().map(|key| active.expand_iri(key, false, true)).collect();
+
+      let is_value: bool = expanded.iter().any(|key| key.as_deref() == Some("@value"));
+      let is_reference: bool = expanded.len() == 1 && expanded[0].as_deref() == Some("@id");
+
+      if !is_value && !is_reference {
+        active = previous.clone();
+      }
+    }
+
+    if let Some(context) = scoped {
+      active = active.process(&context, self.loader, true, true)?;
+    }
+
+    if let Some(context) = object.get("@context") {
+      active = active.process(context, self.loader, false, true)?;
+    }
+
+    let type_scoped: Context = active.clone();
+
+    let mut types: Vec<&str> = Vec::new();
+
+    for (key, value) in object {
+      if type_scoped.expand_iri(key, false, true).as_deref() == Some("@type") {
+        match value {
+          Value::String(type_) => types.push(type_),
+          Value::Array(values) => types.extend(values.iter().filter_map(Value::as_str)),
+          _ => return Err(Error::InvalidJsonLd("invalid type value")),
+        }
+      }
+    }
+
+    types.sort_unstable();
+
+    for type_ in types {
+      if let Some(context) = type_scoped
+        .term(type_)
+        .and_then(|definition| definition.context.as_ref())
+      {
+        active = active.process(context, self.loader, false, false)?;
+      }
+    }
+
+    let mut result: JsonMap = JsonMap::new();
+
+    for (key, value) in object {
+      if key == "@context" {
+        continue;
+      }
+
+      let expanded: String = match active.expand_iri(key, false, true) {
+        Some(expanded) => expanded,
+        None => continue,
+      };
+
+      if is_keyword(&expanded) {
+        self.expand_keyword(&active, &type_scoped, property, &expanded, value, &mut result)?;
+        continue;
+      }
+
+      if !expanded.contains(':') {
+        return Err(Error::InvalidJsonLd("undefined term"));
+      }
+
+      let definition: Option<&TermDefinition> = active.term(key);
+      let container = |name: &str| {
+        definition
+          .map(|definition| definition.has_container(name))
+          .unwrap_or(false)
+      };
+
+      let mut expanded_value: Value = if definition.and_then(|definition| definition.type_.as_deref()) == Some("@json")
+      {
+        let mut object: JsonMap = JsonMap::new();
+        object.insert("@value".into(), value.clone());
+        object.insert("@type".into(), "@json".into());
+        Value::Object(object)
+      } else if value.is_object()
+        && (container("@language") || container("@index") || container("@id") || container("@type"))
+      {
+        return Err(Error::InvalidJsonLd("unsupported container mapping"));
+      } else {
+        self.expand_element(&active, Some(key), value)?
+      };
+
+      if expanded_value.is_null() {
+        continue;
+      }
+
+      if container("@list") && !is_list_object(&expanded_value) {
+        let mut object: JsonMap = JsonMap::new();
+        object.insert("@list".into(), Value::Array(into_array(expanded_value)));
+        expanded_value = Value::Object(object);
+      }
+
+      if container("@graph") {
+        let values: Vec<Value> = into_array(expanded_value)
+          .into_iter()
+          .map(|value| {
+            if is_graph_object(&value) {
+              value
+            } else {
+              let mut object: JsonMap = JsonMap::new();
+              object.insert("@graph".into(), Value::Array(into_array(value)));
+              Value::Object(object)
+            }
+          })
+          .collect();
+
+        expanded_value = Value::Array(values);
+      }
+
+      push(&mut result, expanded, expanded_value);
+    }
+
+    if let Some(value) = result.get("@value").cloned() {
+      if result
+        .keys()
+        .any(|key| !matches!(key.as_str(), "@value" | "@type" | "@language" | "@direction" | "@index"))
+      {
+        return Err(Error::InvalidJsonLd("invalid value object"));
+      }
+
+      if value.is_null() {
+        return Ok(Value::Null);
+      }
+
+      let type_: Option<Value> = match result.remove("@type") {
+        Some(Value::Array(mut types)) if types.len() == 1 => types.pop(),
+        Some(Value::Array(_)) => return Err(Error::InvalidJsonLd("invalid typed value")),
+        type_ => type_,
+      };
+
+      let is_json: bool = type_.as_ref().and_then(Value::as_str) == Some("@json");
+
+      if !is_json && (value.is_object() || value.is_array()) {
+        return Err(Error::InvalidJsonLd("invalid value object value"));
+      }
+
+      if result.contains_key("@language") && !value.is_string() {
+        return Err(Error::InvalidJsonLd("invalid language-tagged value"));
+      }
+
+      if let Some(type_) = type_ {
+        result.insert("@type".into(), type_);
+      }
+    } else if let Some(value) = result.remove("@set") {
+      if !result.is_empty() {
+        return Err(Error::InvalidJsonLd("invalid set object"));
+      }
+
+      return Ok(value);
+    } else if result.contains_key("@list") && result.len() > 1 {
+      return Err(Error::InvalidJsonLd("invalid list object"));
+    }
+
+    if result.len() == 1 && result.contains_key("@language") {
+      return Ok(Value::Null);
+    }
+
+    // Drop free-floating values at the top level or within a graph.
+    if matches!(property, None | Some("@graph"))
+      && (result.is_empty()
+        || result.contains_key("@value")
+        || result.contains_key("@list")
+        || (result.len() == 1 && result.contains_key("@id")))
+    {
+      return Ok(Value::Null);
+    }
+
+    Ok(Value::Object(result))
+  }
+
+  fn expand_keyword(
+    &self,
+    active: &Context,
+    type_scoped: &Context,
+    property: Option<&str>,
+    keyword: &str,
+    value: &Value,
+    result: &mut JsonMap,
+  ) -> Result<()> {
+    if result.contains_key(keyword) && keyword != "@type" {
+      return Err(Error::InvalidJsonLd("colliding keywords"));
+    }
+
+    let expanded: Value = match keyword {
+      "@id" => match value {
+        Value::String(id) => active
+          .expand_iri(id, true, false)
+          .map(Value::String)
+          .unwrap_or(Value::Null),
+        _ => return Err(Error::InvalidJsonLd("invalid @id value")),
+      },
+      "@type" => {
+        let types: Vec<&Value> = match value {
+          Value::Array(values) => values.iter().collect(),
+          value => vec![value],
+        };
+
+        let mut output: Vec<Value> = Vec::with_capacity(types.len());
+
+        for type_ in types {
+          match type_ {
+            Value::String(type_) => {
+              if let Some(type_) = type_scoped.expand_iri(type_, true, true) {
+                if !type_.contains(':') && !is_keyword(&type_) {
+                  return Err(Error::InvalidJsonLd("undefined type"));
+                }
+
+                output.push(Value::String(type_));
+              }
+            }
+            _ => return Err(Error::InvalidJsonLd("invalid type value")),
+          }
+        }
+
+        Value::Array(output)
+      }
+      "@graph" => Value::Array(into_array(self.expand_element(active, Some("@graph"), value)?)),
+      "@value" => value.clone(),
+      "@language" => match value {
+        Value::String(language) => Value::String(language.to_lowercase()),
+        _ => return Err(Error::InvalidJsonLd("invalid language-tagged string")),
+      },
+      "@direction" | "@index" => match value {
+        Value::String(_) => value.clone(),
+        _ => return Err(Error::InvalidJsonLd("invalid keyword value")),
+      },
+      "@list" => {
+        if matches!(property, None | Some("@graph")) {
+          return Ok(());
+        }
+
+        Value::Array(into_array(self.expand_element(active, property, value)?))
+      }
+      "@set" => self.expand_element(active, property, value)?,
+      "@reverse" | "@nest" | "@included" => return Err(Error::InvalidJsonLd("unsupported keyword")),
+      _ => return Ok(()),
+    };
+
+    if keyword == "@id" && expanded.is_null() {
+      return Ok(());
+    }
+
+    push_keyword(result, keyword, expanded);
+
+    Ok(())
+  }
+}
+
+fn push(result: &mut JsonMap, key: String, value: Value) {
+  let entry: &mut Value = result.entry(key).or_insert_with(|| Value::Array(Vec::new()));
+
+  if let Value::Array(values) = entry {
+    values.extend(into_array(value));
+  }
+}
+
+fn push_keyword(result: &mut JsonMap, keyword: &str, value: Value) {
+  match (result.get_mut(keyword), value) {
+    (Some(Value::Array(values)), Value::Array(value)) => values.extend(value),
+    (_, value) => {
+      result.insert(keyword.to_string(), value);
+    }
+  }
+}
+
+fn expand_value(active: &Context, definition: Option<&TermDefinition>, value: &Value) -> Value {
+  let type_: Option<&str> = definition.and_then(|definition| definition.type_.as_deref());
+  let mut object: JsonMap = JsonMap::new();
+
+  match (type_, value) {
+    (Some("@id"), Value::String(id)) => {
+      if let Some(id) = active.expand_iri(id, true, false) {
+        object.insert("@id".into(), Value::String(id));
+      }
+
+      return Value::Object(object);
+    }
+    (Some("@vocab"), Value::String(id)) => {
+      if let Some(id) = active.expand_iri(id, true, true) {
+        object.insert("@id".into(), Value::String(id));
+      }
+
+      return Value::Object(object);
+    }
+    _ => {}
+  }
+
+  object.insert("@value".into(), value.clone());
+
+  match type_ {
+    Some("@id") | Some("@vocab") | Some("@none") | None => {
+      if value.is_string() {
+        let language: Option<&str> = match definition.and_then(|definition| definition.language.as_ref()) {
+          Some(language) => language.as_deref(),
+          None => active.language.as_deref(),
+        };
+
+        if let Some(language) = language {
+          object.insert("@language".into(), language.into());
+        }
+      }
+    }
+    Some(type_) => {
+      object.insert("@type".into(), type_.into());
+    }
+  }
+
+  Value::Object(object)
+}
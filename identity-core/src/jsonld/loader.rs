@@ -0,0 +1,104 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use crate::common::Value;
+use crate::error::Error;
+use crate::error::Result;
+
+/// The URL of the W3C Verifiable Credentials v1 context.
+pub const CREDENTIALS_V1_URL: &str = "https://www.w3.org/2018/credentials/v1";
+
+/// The URL of the W3C Decentralized Identifiers v1 context.
+pub const DID_V1_URL: &str = "https://www.w3.org/ns/did/v1";
+
+/// The URL of the Linked Data Security v1 context.
+pub const SECURITY_V1_URL: &str = "https://w3id.org/security/v1";
+
+/// The URL of the Linked Data Security v2 context.
+pub const SECURITY_V2_URL: &str = "https://w3id.org/security/v2";
+
+/// The URL of the Ed25519 Signature 2020 suite context.
+pub const ED25519_2020_V1_URL: &str = "https://w3id.org/security/suites/ed25519-2020/v1";
+
+/// The URL of the JSON Web Signature 2020 suite context.
+pub const JWS_2020_V1_URL: &str = "https://w3id.org/security/suites/jws-2020/v1";
+
+const CONTEXTS: &[(&str, &str)] = &[
+  (CREDENTIALS_V1_URL, include_str!("contexts/credentials-v1.jsonld")),
+  (DID_V1_URL, include_str!("contexts/did-v1.jsonld")),
+  (SECURITY_V1_URL, include_str!("contexts/security-v1.jsonld")),
+  (SECURITY_V2_URL, include_str!("contexts/security-v2.jsonld")),
+  (ED25519_2020_V1_URL, include_str!("contexts/ed25519-2020-v1.jsonld")),
+  (JWS_2020_V1_URL, include_str!("contexts/jws-2020-v1.jsonld")),
+];
+
+/// A trait for resolving remote JSON-LD contexts.
+pub trait DocumentLoader {
+  /// Returns the JSON-LD document identified by `url`.
+  fn load(&self, url: &str) -> Result<Value>;
+}
+
+/// A [`DocumentLoader`] that never touches the network.
+///
+/// Contexts commonly referenced by DID Documents and Verifiable Credentials
+/// are bundled with the library; additional contexts can be registered with
+/// [`StaticLoader::with_context`].
+#[derive(Clone, Debug)]
+pub struct StaticLoader {
+  documents: BTreeMap<String, Value>,
+}
+
+impl StaticLoader {
+  /// Creates a new `StaticLoader` with the bundled contexts.
+  pub fn new() -> Self {
+    let documents: BTreeMap<String, Value> = CONTEXTS
+      .iter()
+      .map(|(url, data)| {
+        let document: Value = serde_json::from_str(data).expect("invalid bundled context");
+        ((*url).to_string(), document)
+      })
+      .collect();
+
+    Self { documents }
+  }
+
+  /// Creates a new `StaticLoader` without any contexts.
+  pub fn empty() -> Self {
+    Self {
+      documents: BTreeMap::new(),
+    }
+  }
+
+  /// Registers the JSON-LD `document` under the given `url`.
+  pub fn with_context(mut self, url: impl Into<String>, document: Value) -> Self {
+    self.documents.insert(url.into(), document);
+    self
+  }
+}
+
+impl Default for StaticLoader {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl DocumentLoader for StaticLoader {
+  fn load(&self, url: &str) -> Result<Value> {
+    self
+      .documents
+      .get(url)
+      .cloned()
+      .ok_or_else(|| Error::UnknownJsonLdContext(url.to_string()))
+  }
+}
+
+impl<T> DocumentLoader for &'_ T
+where
+  T: DocumentLoader + ?Sized,
+{
+  fn load(&self, url: &str) -> Result<Value> {
+    (**self).load(url)
+  }
+}
@@ -0,0 +1,50 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! JSON-LD processing and [RDF Dataset Canonicalization][SPEC].
+//!
+//! Only the subset of [JSON-LD 1.1][JSONLD] needed to canonicalize DID
+//! Documents and Verifiable Credentials is supported; documents using
+//! unsupported features (`@reverse`, `@nest`, index/language maps, ...) or
+//! properties that are not defined by a context are rejected.
+//!
+//! [SPEC]: https://json-ld.github.io/rdf-dataset-canonicalization/spec/
+//! [JSONLD]: https://www.w3.org/TR/json-ld11-api/
+
+mod context;
+mod expand;
+mod loader;
+mod rdf;
+mod urdna2015;
+
+pub use self::loader::DocumentLoader;
+pub use self::loader::StaticLoader;
+pub use self::loader::CREDENTIALS_V1_URL;
+pub use self::loader::DID_V1_URL;
+pub use self::loader::ED25519_2020_V1_URL;
+pub use self::loader::JWS_2020_V1_URL;
+pub use self::loader::SECURITY_V1_URL;
+pub use self::loader::SECURITY_V2_URL;
+
+use serde::Serialize;
+
+use crate::common::Value;
+use crate::convert::ToJson;
+use crate::error::Result;
+
+/// Converts `data` to an RDF dataset and returns the canonical N-Quads
+/// serialization produced by the URDNA2015 algorithm.
+pub fn canonicalize<T, L>(data: &T, loader: &L) -> Result<String>
+where
+  T: Serialize,
+  L: DocumentLoader + ?Sized,
+{
+  let document: Value = data.to_json_value()?;
+  let expanded: Vec<Value> = expand::expand(&document, loader)?;
+  let quads: Vec<rdf::Quad> = rdf::to_rdf(&expanded)?;
+
+  urdna2015::canonicalize(&quads).map(|lines| lines.concat())
+}
+
+#[cfg(test)]
+mod tests;
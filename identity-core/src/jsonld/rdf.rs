@@ -0,0 +1,362 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use serde_json::Map;
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::convert::ToJson;
+use crate::error::Error;
+use crate::error::Result;
+
+type JsonMap = Map<String, Value>;
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const RDF_FIRST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#first";
+const RDF_REST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#rest";
+const RDF_NIL: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#nil";
+const RDF_JSON: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#JSON";
+const RDF_LANG_STRING: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#langString";
+const XSD_BOOLEAN: &str = "http://www.w3.org/2001/XMLSchema#boolean";
+const XSD_DOUBLE: &str = "http://www.w3.org/2001/XMLSchema#double";
+const XSD_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#integer";
+const XSD_STRING: &str = "http://www.w3.org/2001/XMLSchema#string";
+
+/// A node or literal of an RDF quad.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Term {
+  Iri(String),
+  Blank(String),
+  Literal {
+    value: String,
+    datatype: String,
+    language: Option<String>,
+  },
+}
+
+impl Term {
+  pub(crate) fn as_blank(&self) -> Option<&str> {
+    match self {
+      Self::Blank(id) => Some(id),
+      _ => None,
+    }
+  }
+}
+
+impl Display for Term {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      Self::Iri(iri) => write!(f, "<{}>", iri),
+      Self::Blank(id) => write!(f, "_:{}", id),
+      Self::Literal {
+        value,
+        datatype,
+        language,
+      } => {
+        f.write_str("\"")?;
+
+        for char in value.chars() {
+          match char {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            char => write!(f, "{}", char)?,
+          }
+        }
+
+        f.write_str("\"")?;
+
+        if let Some(language) = language {
+          write!(f, "@{}", language)
+        } else if datatype != XSD_STRING {
+          write!(f, "^^<{}>", datatype)
+        } else {
+          Ok(())
+        }
+      }
+    }
+  }
+}
+
+/// An RDF quad; the graph is `None` for the default graph.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Quad {
+  pub(crate) subject: Term,
+  pub(crate) predicate: Term,
+  pub(crate) object: Term,
+  pub(crate) graph: Option<Term>,
+}
+
+impl Quad {
+  /// Returns the terms of the quad that may be blank nodes.
+  pub(crate) fn blank_nodes(&self) -> impl Iterator<Item = &str> {
+    Some(&self.subject)
+      .into_iter()
+      .chain(Some(&self.object))
+      .chain(self.graph.as_ref())
+      .filter_map(Term::as_blank)
+  }
+}
+
+impl Display for Quad {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{} {} {} ", self.subject, self.predicate, self.object)?;
+
+    if let Some(graph) = self.graph.as_ref() {
+      write!(f, "{} ", graph)?;
+    }
+
+    f.write_str(".\n")
+  }
+}
+
+/// Converts expanded JSON-LD node objects to an RDF dataset.
+pub(crate) fn to_rdf(nodes: &[Value]) -> Result<Vec<Quad>> {
+  let mut builder: Builder = Builder {
+    quads: Vec::new(),
+    labels: HashMap::new(),
+    counter: 0,
+  };
+
+  for node in nodes {
+    if let Value::Object(node) = node {
+      builder.node(node, None)?;
+    }
+  }
+
+  let mut quads: Vec<Quad> = builder.quads;
+
+  quads.sort();
+  quads.dedup();
+
+  Ok(quads)
+}
+
+struct Builder {
+  quads: Vec<Quad>,
+  labels: HashMap<String, String>,
+  counter: usize,
+}
+
+impl Builder {
+  fn blank(&mut self, label: Option<&str>) -> Term {
+    if let Some(id) = label.and_then(|label| self.labels.get(label)) {
+      return Term::Blank(id.clone());
+    }
+
+    let id: String = format!("b{}", self.counter);
+
+    self.counter += 1;
+
+    if let Some(label) = label {
+      self.labels.insert(label.to_string(), id.clone());
+    }
+
+    Term::Blank(id)
+  }
+
+  fn resource(&mut self, id: &str) -> Result<Term> {
+    if let Some(label) = id.strip_prefix("_:") {
+      Ok(self.blank(Some(label)))
+    } else if id.contains(':') {
+      Ok(Term::Iri(id.to_string()))
+    } else {
+      Err(Error::InvalidJsonLd("relative IRI"))
+    }
+  }
+
+  fn push(&mut self, subject: Term, predicate: &str, object: Term, graph: Option<&Term>) {
+    self.quads.push(Quad {
+      subject,
+      predicate: Term::Iri(predicate.to_string()),
+      object,
+      graph: graph.cloned(),
+    });
+  }
+
+  fn node(&mut self, node: &JsonMap, graph: Option<&Term>) -> Result<Term> {
+    let subject: Term = match node.get("@id").and_then(Value::as_str) {
+      Some(id) => self.resource(id)?,
+      None => self.blank(None),
+    };
+
+    if let Some(Value::Array(types)) = node.get("@type") {
+      for type_ in types.iter().filter_map(Value::as_str) {
+        let object: Term = self.resource(type_)?;
+        self.push(subject.clone(), RDF_TYPE, object, graph);
+      }
+    }
+
+    if let Some(Value::Array(nodes)) = node.get("@graph") {
+      for inner in nodes.iter().filter_map(Value::as_object) {
+        if !inner.contains_key("@value") && !inner.contains_key("@list") {
+          self.node(inner, Some(&subject))?;
+        }
+      }
+    }
+
+    for (property, values) in node {
+      if property.starts_with('@') {
+        continue;
+      }
+
+      // Blank node predicates are not part of RDF 1.1.
+      if property.starts_with("_:") {
+        continue;
+      }
+
+      if !property.contains(':') {
+        return Err(Error::InvalidJsonLd("relative IRI"));
+      }
+
+      if let Value::Array(values) = values {
+        for value in values {
+          if let Some(object) = self.object(value, graph)? {
+            self.push(subject.clone(), property, object, graph);
+          }
+        }
+      }
+    }
+
+    Ok(subject)
+  }
+
+  fn object(&mut self, value: &Value, graph: Option<&Term>) -> Result<Option<Term>> {
+    let object: &JsonMap = match value {
+      Value::Object(object) => object,
+      _ => return Ok(None),
+    };
+
+    if object.contains_key("@value") {
+      literal(object).map(Some)
+    } else if let Some(Value::Array(items)) = object.get("@list") {
+      self.list(items, graph).map(Some)
+    } else {
+      self.node(object, graph).map(Some)
+    }
+  }
+
+  fn list(&mut self, items: &[Value], graph: Option<&Term>) -> Result<Term> {
+    if items.is_empty() {
+      return Ok(Term::Iri(RDF_NIL.to_string()));
+    }
+
+    let nodes: Vec<Term> = items.iter().map(|_| self.blank(None)).collect();
+
+    for (index, item) in items.iter().enumerate() {
+      if let Some(object) = self.object(item, graph)? {
+        self.push(nodes[index].clone(), RDF_FIRST, object, graph);
+      }
+
+      let rest: Term = nodes
+        .get(index + 1)
+        .cloned()
+        .unwrap_or_else(|| Term::Iri(RDF_NIL.to_string()));
+
+      self.push(nodes[index].clone(), RDF_REST, rest, graph);
+    }
+
+    Ok(nodes[0].clone())
+  }
+}
+
+fn literal(object: &JsonMap) -> Result<Term> {
+  let value: &Value = &object["@value"];
+  let datatype: Option<&str> = object.get("@type").and_then(Value::as_str);
+  let language: Option<String> = object.get("@language").and_then(Value::as_str).map(ToString::to_string);
+
+  if datatype == Some("@json") {
+    return Ok(Term::Literal {
+      value: String::from_utf8(value.to_jcs()?).map_err(|_| Error::InvalidJsonLd("invalid JSON literal"))?,
+      datatype: RDF_JSON.to_string(),
+      language: None,
+    });
+  }
+
+  let (value, default): (String, &str) = match value {
+    Value::Bool(value) => (value.to_string(), XSD_BOOLEAN),
+    Value::Number(number) => match (number.as_i64(), number.as_u64(), number.as_f64()) {
+      _ if datatype == Some(XSD_DOUBLE) => (format_double(number.as_f64().unwrap_or_default()), XSD_DOUBLE),
+      (Some(value), _, _) => (value.to_string(), XSD_INTEGER),
+      (_, Some(value), _) => (value.to_string(), XSD_INTEGER),
+      (_, _, Some(value)) if value.fract() == 0.0 && value.abs() < 1e21 => (format!("{:.0}", value), XSD_INTEGER),
+      (_, _, Some(value)) => (format_double(value), XSD_DOUBLE),
+      _ => return Err(Error::InvalidJsonLd("invalid number")),
+    },
+    Value::String(value) if language.is_some() => (value.clone(), RDF_LANG_STRING),
+    Value::String(value) => (value.clone(), XSD_STRING),
+    _ => return Err(Error::InvalidJsonLd("invalid value object value")),
+  };
+
+  Ok(Term::Literal {
+    value,
+    datatype: datatype.unwrap_or(default).to_string(),
+    language,
+  })
+}
+
+// Formats a double in canonical `xsd:double` form (e.g. `1.1E0`).
+//
+// JSON-LD uses the `%1.15E` format (`toExponential(15)` in JavaScript): the
+// exact value is rounded to 16 significant digits, before trailing zeros are
+// removed from the mantissa. The shortest round-trip form is not used since it
+// differs from the rounded value for many doubles.
+fn format_double(value: f64) -> String {
+  let formatted: String = format_tie(value).unwrap_or_else(|| format!("{:.15e}", value));
+  let (mantissa, exponent): (&str, &str) = formatted.split_at(formatted.find('e').unwrap_or(formatted.len()));
+  let mut mantissa: String = mantissa.trim_end_matches('0').to_string();
+
+  if mantissa.ends_with('.') {
+    mantissa.push('0');
+  }
+
+  format!("{}E{}", mantissa, exponent.trim_start_matches('e'))
+}
+
+// Rust rounds ties to even, but `%1.15E` rounds ties away from zero. A tie
+// needs exactly 17 significant digits, so it is detected and rounded with the
+// exact decimal digits of `value`.
+fn format_tie(value: f64) -> Option<String> {
+  if !value.is_normal() {
+    return None;
+  }
+
+  let bits: u64 = value.to_bits();
+  let mut mantissa: u64 = (bits & ((1 << 52) - 1)) | (1 << 52);
+  let mut exponent: i32 = ((bits >> 52) & 0x7ff) as i32 - 1075;
+
+  let zeros: u32 = mantissa.trailing_zeros();
+
+  mantissa >>= zeros;
+  exponent += zeros as i32;
+
+  // Integers never end in a `5` at the 17th digit, and values with more than
+  // 24 fractional bits have more than 17 significant digits.
+  if exponent >= 0 || exponent < -24 {
+    return None;
+  }
+
+  // |value| == digits * 10^exponent
+  let digits: u128 = u128::from(mantissa) * 5_u128.pow(exponent.unsigned_abs());
+
+  if !(10_u128.pow(16)..10_u128.pow(17)).contains(&digits) {
+    return None;
+  }
+
+  let mut rounded: u128 = (digits + 5) / 10;
+  let mut power: i32 = 16 + exponent;
+
+  if rounded == 10_u128.pow(16) {
+    rounded /= 10;
+    power += 1;
+  }
+
+  let rounded: String = rounded.to_string();
+  let sign: &str = if value.is_sign_negative() { "-" } else { "" };
+
+  Some(format!("{}{}.{}e{}", sign, &rounded[..1], &rounded[1..], power))
+}
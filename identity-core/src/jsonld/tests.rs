@@ -0,0 +1,296 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::Value;
+use crate::error::Error;
+use crate::json;
+use crate::jsonld::canonicalize;
+use crate::jsonld::StaticLoader;
+
+fn canonical(value: &Value) -> String {
+  canonicalize(value, &StaticLoader::new()).unwrap()
+}
+
+#[test]
+fn test_canonicalize_simple() {
+  let document: Value = json!({
+    "@context": {
+      "@vocab": "http://schema.org/",
+      "knows": { "@type": "@id" },
+      "age": { "@type": "http://www.w3.org/2001/XMLSchema#integer" },
+    },
+    "@id": "http://example.com/alice",
+    "@type": "Person",
+    "name": ["Alice", { "@value": "Alicia", "@language": "ES" }],
+    "knows": "http://example.com/bob",
+    "age": "42",
+    "height": 1.5,
+    "married": false,
+    "children": 2,
+  });
+
+  let expected: &str = concat!(
+    "<http://example.com/alice> <http://schema.org/age> \"42\"^^<http://www.w3.org/2001/XMLSchema#integer> .\n",
+    "<http://example.com/alice> <http://schema.org/children> \"2\"^^<http://www.w3.org/2001/XMLSchema#integer> .\n",
+    "<http://example.com/alice> <http://schema.org/height> \"1.5E0\"^^<http://www.w3.org/2001/XMLSchema#double> .\n",
+    "<http://example.com/alice> <http://schema.org/knows> <http://example.com/bob> .\n",
+    "<http://example.com/alice> <http://schema.org/married> \"false\"^^<http://www.w3.org/2001/XMLSchema#boolean> .\n",
+    "<http://example.com/alice> <http://schema.org/name> \"Alice\" .\n",
+    "<http://example.com/alice> <http://schema.org/name> \"Alicia\"@es .\n",
+    "<http://example.com/alice> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://schema.org/Person> .\n",
+  );
+
+  assert_eq!(canonical(&document), expected);
+}
+
+#[test]
+fn test_canonicalize_doubles() {
+  let document: Value = json!({
+    "@context": { "@vocab": "http://schema.org/" },
+    "@id": "http://example.com/a",
+    "values": [0.1 + 0.2, 9833.2900405222, 5.9604644775390625e-8, -5.9604644775390625e-8, 1.0e21],
+  });
+
+  let output: String = canonical(&document);
+
+  // The lexical forms produced by jsonld.js (`toExponential(15)`)
+  for expected in [
+    "3.0E-1",
+    "9.833290040522201E3",
+    "5.960464477539063E-8",
+    "-5.960464477539063E-8",
+    "1.0E21",
+  ] {
+    assert!(output.contains(&format!(
+      "<http://example.com/a> <http://schema.org/values> \"{}\"^^<http://www.w3.org/2001/XMLSchema#double> .\n",
+      expected
+    )));
+  }
+}
+
+#[test]
+fn test_canonicalize_lists() {
+  let document: Value = json!({
+    "@context": {
+      "@vocab": "http://example.com/",
+      "items": { "@container": "@list" },
+    },
+    "@id": "http://example.com/a",
+    "items": ["x", "y"],
+  });
+
+  let output: String = canonical(&document);
+
+  assert_eq!(output.lines().count(), 5);
+  assert!(output.contains("<http://example.com/a> <http://example.com/items> _:c14n"));
+  assert!(output.contains("<http://www.w3.org/1999/02/22-rdf-syntax-ns#first> \"x\" .\n"));
+  assert!(output.contains("<http://www.w3.org/1999/02/22-rdf-syntax-ns#first> \"y\" .\n"));
+  assert!(output.contains(
+    "<http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> .\n"
+  ));
+}
+
+#[test]
+fn test_canonicalize_blank_node_labels() {
+  let context: Value = json!({ "@vocab": "http://example.com/" });
+
+  let document1: Value = json!({
+    "@context": context,
+    "@graph": [
+      { "@id": "_:x", "name": "a", "knows": { "@id": "_:y" } },
+      { "@id": "_:y", "name": "b", "knows": { "@id": "_:x" } },
+    ],
+  });
+
+  let document2: Value = json!({
+    "@context": context,
+    "@graph": [
+      { "@id": "_:second", "name": "b", "knows": { "@id": "_:first" } },
+      { "@id": "_:first", "name": "a", "knows": { "@id": "_:second" } },
+    ],
+  });
+
+  let output: String = canonical(&document1);
+
+  assert_eq!(output, canonical(&document2));
+  assert!(output.contains("_:c14n0"));
+  assert!(output.contains("_:c14n1"));
+  assert!(!output.contains("_:x"));
+}
+
+#[test]
+fn test_canonicalize_symmetric_blank_nodes() {
+  let context: Value = json!({ "@vocab": "http://example.com/" });
+
+  // Blank nodes with identical first-degree hashes require n-degree hashing.
+  let document1: Value = json!({
+    "@context": context,
+    "@graph": [
+      { "@id": "_:a", "p": { "@id": "_:b" } },
+      { "@id": "_:b", "p": { "@id": "_:c" } },
+      { "@id": "_:c", "p": { "@id": "_:a" } },
+      { "@id": "_:d", "p": { "@id": "_:e" } },
+      { "@id": "_:e", "p": { "@id": "_:d" } },
+    ],
+  });
+
+  let document2: Value = json!({
+    "@context": context,
+    "@graph": [
+      { "@id": "_:e", "p": { "@id": "_:a" } },
+      { "@id": "_:a", "p": { "@id": "_:e" } },
+      { "@id": "_:b", "p": { "@id": "_:d" } },
+      { "@id": "_:c", "p": { "@id": "_:b" } },
+      { "@id": "_:d", "p": { "@id": "_:c" } },
+    ],
+  });
+
+  let output: String = canonical(&document1);
+
+  assert_eq!(output, canonical(&document2));
+  assert_eq!(output.lines().count(), 5);
+
+  for index in 0..5 {
+    assert!(output.contains(&format!("_:c14n{} ", index)));
+  }
+}
+
+#[test]
+fn test_canonicalize_credential() {
+  let document: Value = json!({
+    "@context": [
+      "https://www.w3.org/2018/credentials/v1",
+      { "name": "https://schema.org/name" },
+    ],
+    "id": "https://example.com/credentials/1872",
+    "type": "VerifiableCredential",
+    "issuer": "did:example:issuer",
+    "issuanceDate": "2010-01-01T19:23:24Z",
+    "credentialSubject": {
+      "id": "did:example:holder",
+      "name": "Alice",
+    },
+  });
+
+  let expected: &str = concat!(
+    "<did:example:holder> <https://schema.org/name> \"Alice\" .\n",
+    "<https://example.com/credentials/1872> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential> .\n",
+    "<https://example.com/credentials/1872> <https://www.w3.org/2018/credentials#credentialSubject> <did:example:holder> .\n",
+    "<https://example.com/credentials/1872> <https://www.w3.org/2018/credentials#issuanceDate> \"2010-01-01T19:23:24Z\"^^<http://www.w3.org/2001/XMLSchema#dateTime> .\n",
+    "<https://example.com/credentials/1872> <https://www.w3.org/2018/credentials#issuer> <did:example:issuer> .\n",
+  );
+
+  assert_eq!(canonical(&document), expected);
+}
+
+#[test]
+fn test_canonicalize_graph_container() {
+  let document: Value = json!({
+    "@context": [
+      "https://www.w3.org/2018/credentials/v1",
+      "https://w3id.org/security/suites/ed25519-2020/v1",
+    ],
+    "id": "https://example.com/credentials/1872",
+    "type": "VerifiableCredential",
+    "proof": {
+      "type": "Ed25519Signature2020",
+      "proofPurpose": "assertionMethod",
+      "verificationMethod": "did:example:issuer#key-1",
+    },
+  });
+
+  let output: String = canonical(&document);
+
+  assert!(output.contains("<https://example.com/credentials/1872> <https://w3id.org/security#proof> _:c14n0 .\n"));
+  assert!(output.contains(
+    "_:c14n1 <https://w3id.org/security#proofPurpose> <https://w3id.org/security#assertionMethod> _:c14n0 .\n"
+  ));
+  assert!(
+    output.contains("_:c14n1 <https://w3id.org/security#verificationMethod> <did:example:issuer#key-1> _:c14n0 .\n")
+  );
+}
+
+#[test]
+fn test_undefined_term() {
+  let document: Value = json!({
+    "@context": "https://www.w3.org/2018/credentials/v1",
+    "type": "VerifiableCredential",
+    "undefined": "value",
+  });
+
+  assert!(matches!(
+    canonicalize(&document, &StaticLoader::new()),
+    Err(Error::InvalidJsonLd(_))
+  ));
+}
+
+#[test]
+fn test_protected_term_redefinition() {
+  let document: Value = json!({
+    "@context": [
+      "https://www.w3.org/2018/credentials/v1",
+      { "VerifiableCredential": "https://example.com/Other" },
+    ],
+    "type": "VerifiableCredential",
+  });
+
+  assert!(matches!(
+    canonicalize(&document, &StaticLoader::new()),
+    Err(Error::InvalidJsonLd(_))
+  ));
+}
+
+#[test]
+fn test_unknown_context() {
+  let document: Value = json!({
+    "@context": "https://example.com/unknown/v1",
+    "@id": "https://example.com/",
+  });
+
+  assert!(matches!(
+    canonicalize(&document, &StaticLoader::new()),
+    Err(Error::UnknownJsonLdContext(_))
+  ));
+
+  let loader: StaticLoader = StaticLoader::empty().with_context(
+    "https://example.com/unknown/v1",
+    json!({ "@context": { "name": "https://schema.org/name" } }),
+  );
+
+  let document: Value = json!({
+    "@context": "https://example.com/unknown/v1",
+    "@id": "https://example.com/",
+    "name": "Example",
+  });
+
+  assert_eq!(
+    canonicalize(&document, &loader).unwrap(),
+    "<https://example.com/> <https://schema.org/name> \"Example\" .\n"
+  );
+}
+
+#[test]
+fn test_blank_node_overflow() {
+  // Two indistinguishable nodes, each related to `count` indistinguishable
+  // nodes - the N-degree hash explores `count!` permutations.
+  let document = |count: usize| -> Value {
+    let children: Vec<Value> = (0..count)
+      .map(|_| json!({ "http://schema.org/name": "Child" }))
+      .collect();
+
+    json!({
+      "@context": { "@vocab": "http://schema.org/" },
+      "@graph": [
+        { "children": children.clone() },
+        { "children": children },
+      ],
+    })
+  };
+
+  assert!(canonicalize(&document(4), &StaticLoader::new()).is_ok());
+
+  assert!(matches!(
+    canonicalize(&document(8), &StaticLoader::new()),
+    Err(Error::InvalidJsonLd(_))
+  ));
+}
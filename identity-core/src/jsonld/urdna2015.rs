@@ -0,0 +1,353 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! An implementation of the [URDNA2015][SPEC] RDF Dataset Canonicalization algorithm.
+//!
+//! [SPEC]: https://json-ld.github.io/rdf-dataset-canonicalization/spec/
+
+use crypto::hashes::sha::Sha256;
+use crypto::hashes::Digest;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::jsonld::rdf::Quad;
+use crate::jsonld::rdf::Term;
+
+// The maximum number of permutations of related blank nodes explored while
+// hashing a dataset. The N-degree hash is factorial in the number of related
+// blank nodes with equal hashes, so unbounded inputs are rejected.
+const MAX_PERMUTATIONS: usize = 4096;
+
+// The maximum recursion depth of the N-degree hash.
+const MAX_RECURSION_DEPTH: usize = 64;
+
+/// Issues sequential identifiers for blank nodes, remembering the order.
+#[derive(Clone, Debug)]
+struct IdentifierIssuer {
+  prefix: &'static str,
+  counter: usize,
+  issued: HashMap<String, String>,
+  order: Vec<String>,
+}
+
+impl IdentifierIssuer {
+  fn new(prefix: &'static str) -> Self {
+    Self {
+      prefix,
+      counter: 0,
+      issued: HashMap::new(),
+      order: Vec::new(),
+    }
+  }
+
+  fn get(&self, id: &str) -> Option<&str> {
+    self.issued.get(id).map(String::as_str)
+  }
+
+  fn issue(&mut self, id: &str) -> String {
+    if let Some(issued) = self.issued.get(id) {
+      return issued.clone();
+    }
+
+    let issued: String = format!("{}{}", self.prefix, self.counter);
+
+    self.counter += 1;
+    self.issued.insert(id.to_string(), issued.clone());
+    self.order.push(id.to_string());
+
+    issued
+  }
+}
+
+struct State<'a> {
+  blank_nodes: HashMap<&'a str, Vec<&'a Quad>>,
+  canonical: IdentifierIssuer,
+  first_degree: HashMap<&'a str, String>,
+  permutations: usize,
+}
+
+/// Canonicalizes the given RDF dataset and returns the sorted N-Quads lines.
+///
+/// Fails if the blank nodes of the dataset are too complex to canonicalize
+/// within a bounded number of steps.
+pub(crate) fn canonicalize(quads: &[Quad]) -> Result<Vec<String>> {
+  let mut state: State<'_> = State {
+    blank_nodes: HashMap::new(),
+    canonical: IdentifierIssuer::new("c14n"),
+    first_degree: HashMap::new(),
+    permutations: 0,
+  };
+
+  for quad in quads {
+    for id in quad.blank_nodes() {
+      let entry: &mut Vec<&Quad> = state.blank_nodes.entry(id).or_default();
+
+      if !entry.iter().any(|other| core::ptr::eq(*other, quad)) {
+        entry.push(quad);
+      }
+    }
+  }
+
+  let mut hash_to_nodes: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+  let mut nodes: Vec<&str> = state.blank_nodes.keys().copied().collect();
+
+  nodes.sort_unstable();
+
+  for node in nodes {
+    let hash: String = state.hash_first_degree(node);
+    hash_to_nodes.entry(hash).or_default().push(node);
+  }
+
+  let mut shared: Vec<Vec<&str>> = Vec::new();
+
+  for (_, nodes) in hash_to_nodes {
+    if nodes.len() == 1 {
+      state.canonical.issue(nodes[0]);
+    } else {
+      shared.push(nodes);
+    }
+  }
+
+  for nodes in shared {
+    let mut results: Vec<(String, IdentifierIssuer)> = Vec::new();
+
+    for node in nodes {
+      if state.canonical.get(node).is_some() {
+        continue;
+      }
+
+      let mut issuer: IdentifierIssuer = IdentifierIssuer::new("b");
+      issuer.issue(node);
+      results.push(state.hash_n_degree(node, issuer, 0)?);
+    }
+
+    results.sort_by(|lhs, rhs| lhs.0.cmp(&rhs.0));
+
+    for (_, issuer) in results {
+      for node in issuer.order {
+        state.canonical.issue(&node);
+      }
+    }
+  }
+
+  let relabel = |term: &Term| match term {
+    Term::Blank(id) => Term::Blank(state.canonical.get(id).unwrap_or(id).to_string()),
+    term => term.clone(),
+  };
+
+  let mut output: Vec<String> = quads
+    .iter()
+    .map(|quad| {
+      Quad {
+        subject: relabel(&quad.subject),
+        predicate: quad.predicate.clone(),
+        object: relabel(&quad.object),
+        graph: quad.graph.as_ref().map(relabel),
+      }
+      .to_string()
+    })
+    .collect();
+
+  output.sort();
+  output.dedup();
+
+  Ok(output)
+}
+
+fn sha256_hex(data: &str) -> String {
+  hex::encode(Sha256::digest(data.as_bytes()))
+}
+
+impl<'a> State<'a> {
+  fn hash_first_degree(&mut self, node: &'a str) -> String {
+    if let Some(hash) = self.first_degree.get(node) {
+      return hash.clone();
+    }
+
+    let replace = |term: &Term| match term {
+      Term::Blank(id) if id == node => Term::Blank("a".to_string()),
+      Term::Blank(_) => Term::Blank("z".to_string()),
+      term => term.clone(),
+    };
+
+    let mut nquads: Vec<String> = self.blank_nodes[node]
+      .iter()
+      .map(|quad| {
+        Quad {
+          subject: replace(&quad.subject),
+          predicate: quad.predicate.clone(),
+          object: replace(&quad.object),
+          graph: quad.graph.as_ref().map(replace),
+        }
+        .to_string()
+      })
+      .collect();
+
+    nquads.sort();
+
+    let hash: String = sha256_hex(&nquads.concat());
+
+    self.first_degree.insert(node, hash.clone());
+
+    hash
+  }
+
+  fn hash_related(&mut self, related: &'a str, quad: &Quad, issuer: &IdentifierIssuer, position: char) -> String {
+    let id: String = match self.canonical.get(related).or_else(|| issuer.get(related)) {
+      Some(id) => format!("_:{}", id),
+      None => self.hash_first_degree(related),
+    };
+
+    let mut input: String = position.to_string();
+
+    if position != 'g' {
+      input.push_str(&quad.predicate.to_string());
+    }
+
+    input.push_str(&id);
+
+    sha256_hex(&input)
+  }
+
+  fn hash_n_degree(
+    &mut self,
+    node: &'a str,
+    mut issuer: IdentifierIssuer,
+    depth: usize,
+  ) -> Result<(String, IdentifierIssuer)> {
+    if depth >= MAX_RECURSION_DEPTH {
+      return Err(Error::InvalidJsonLd("blank node overflow"));
+    }
+
+    let mut hash_to_related: BTreeMap<String, Vec<&'a str>> = BTreeMap::new();
+    let quads: Vec<&'a Quad> = self.blank_nodes[node].clone();
+
+    for quad in quads {
+      let components: [(Option<&'a Term>, char); 3] = [
+        (Some(&quad.subject), 's'),
+        (Some(&quad.object), 'o'),
+        (quad.graph.as_ref(), 'g'),
+      ];
+
+      for (term, position) in components.iter() {
+        if let Some(related) = term.and_then(Term::as_blank) {
+          if related != node {
+            let hash: String = self.hash_related(related, quad, &issuer, *position);
+            hash_to_related.entry(hash).or_default().push(related);
+          }
+        }
+      }
+    }
+
+    let mut data: String = String::new();
+
+    for (hash, related) in hash_to_related {
+      data.push_str(&hash);
+
+      let mut chosen_path: String = String::new();
+      let mut chosen_issuer: Option<IdentifierIssuer> = None;
+
+      self.permutations = factorial(related.len())
+        .and_then(|count| self.permutations.checked_add(count))
+        .filter(|total| *total <= MAX_PERMUTATIONS)
+        .ok_or(Error::InvalidJsonLd("blank node overflow"))?;
+
+      for permutation in permutations(&related) {
+        let mut issuer_copy: IdentifierIssuer = issuer.clone();
+        let mut path: String = String::new();
+        let mut recursion: Vec<&'a str> = Vec::new();
+        let mut skip: bool = false;
+
+        for related in permutation.iter().copied() {
+          if let Some(id) = self.canonical.get(related) {
+            path.push_str("_:");
+            path.push_str(id);
+          } else {
+            if issuer_copy.get(related).is_none() {
+              recursion.push(related);
+            }
+
+            path.push_str("_:");
+            path.push_str(&issuer_copy.issue(related));
+          }
+
+          if !chosen_path.is_empty() && path.len() >= chosen_path.len() && path > chosen_path {
+            skip = true;
+            break;
+          }
+        }
+
+        if skip {
+          continue;
+        }
+
+        for related in recursion {
+          let (hash, issuer): (String, IdentifierIssuer) =
+            self.hash_n_degree(related, issuer_copy.clone(), depth + 1)?;
+
+          path.push_str("_:");
+          path.push_str(&issuer_copy.issue(related));
+          path.push('<');
+          path.push_str(&hash);
+          path.push('>');
+
+          issuer_copy = issuer;
+
+          if !chosen_path.is_empty() && path.len() >= chosen_path.len() && path > chosen_path {
+            skip = true;
+            break;
+          }
+        }
+
+        if skip {
+          continue;
+        }
+
+        if chosen_path.is_empty() || path < chosen_path {
+          chosen_path = path;
+          chosen_issuer = Some(issuer_copy);
+        }
+      }
+
+      data.push_str(&chosen_path);
+
+      if let Some(chosen_issuer) = chosen_issuer {
+        issuer = chosen_issuer;
+      }
+    }
+
+    Ok((sha256_hex(&data), issuer))
+  }
+}
+
+// Returns the number of permutations of `count` items, if it fits a `usize`.
+fn factorial(count: usize) -> Option<usize> {
+  (1..=count).try_fold(1_usize, |total, item| total.checked_mul(item))
+}
+
+// Returns every permutation of `items` (Heap's algorithm).
+fn permutations<'a>(items: &[&'a str]) -> Vec<Vec<&'a str>> {
+  let mut items: Vec<&'a str> = items.to_vec();
+  let mut output: Vec<Vec<&'a str>> = vec![items.clone()];
+  let mut counters: Vec<usize> = vec![0; items.len()];
+  let mut index: usize = 1;
+
+  while index < items.len() {
+    if counters[index] < index {
+      let swap: usize = if index & 1 == 0 { 0 } else { counters[index] };
+
+      items.swap(swap, index);
+
+      output.push(items.clone());
+      counters[index] += 1;
+      index = 1;
+    } else {
+      counters[index] = 0;
+      index += 1;
+    }
+  }
+
+  output
+}
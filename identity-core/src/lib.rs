@@ -17,6 +17,9 @@
   // clippy::missing_errors_doc
 )]
 
+#[macro_use]
+extern crate lazy_static;
+
 #[macro_use]
 extern crate serde;
 
@@ -30,6 +33,7 @@ pub mod common;
 pub mod convert;
 pub mod crypto;
 pub mod error;
+pub mod jsonld;
 pub mod utils;
 
 pub use self::error::Error;
@@ -0,0 +1,50 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(feature = "bbs")]
+use bls12_381::G2Affine;
+#[cfg(feature = "bbs")]
+use bls12_381::G2Projective;
+#[cfg(feature = "bbs")]
+use bls12_381::Scalar;
+#[cfg(feature = "bbs")]
+use zeroize::Zeroize;
+
+use crate::crypto::PublicKey;
+use crate::crypto::SecretKey;
+#[cfg(not(feature = "bbs"))]
+use crate::error::Error;
+use crate::error::Result;
+
+/// Generates a new pair of public/secret BLS12-381 keys for BBS+ signatures.
+///
+/// The public key is returned as a compressed G2 point (96 bytes).
+#[cfg(feature = "bbs")]
+pub fn generate_bls12381_keypair() -> Result<(PublicKey, SecretKey)> {
+  let mut bytes: [u8; 64] = [0; 64];
+
+  crypto::utils::rand::fill(&mut bytes)?;
+
+  let secret: Scalar = Scalar::from_bytes_wide(&bytes);
+  let public: G2Affine = G2Affine::from(G2Projective::generator() * secret);
+
+  bytes.zeroize();
+
+  let secret: SecretKey = secret.to_bytes().to_vec().into();
+  let public: PublicKey = public.to_compressed().to_vec().into();
+
+  Ok((public, secret))
+}
+
+/// Generates a new pair of public/secret BLS12-381 keys for BBS+ signatures.
+///
+/// Always fails since the `bbs` feature is disabled.
+#[cfg(not(feature = "bbs"))]
+pub fn generate_bls12381_keypair() -> Result<(PublicKey, SecretKey)> {
+  Err(Error::MissingFeature("bbs"))
+}
+
+/// Generates a list of public/secret BLS12-381 keys for BBS+ signatures.
+pub fn generate_bls12381_keypairs(count: usize) -> Result<Vec<(PublicKey, SecretKey)>> {
+  (0..count).map(|_| generate_bls12381_keypair()).collect()
+}
@@ -1,10 +1,15 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use core::convert::TryFrom;
+use crypto::keys::slip10;
 use crypto::signatures::ed25519;
 
+use crate::crypto::DerivationPath;
 use crate::crypto::PublicKey;
 use crate::crypto::SecretKey;
+use crate::crypto::Seed;
+use crate::error::Error;
 use crate::error::Result;
 
 /// Generates a new pair of public/secret ed25519 keys.
@@ -22,3 +27,35 @@ pub fn generate_ed25519_keypair() -> Result<(PublicKey, SecretKey)> {
 pub fn generate_ed25519_keypairs(count: usize) -> Result<Vec<(PublicKey, SecretKey)>> {
   (0..count).map(|_| generate_ed25519_keypair()).collect()
 }
+
+/// Derives a pair of public/secret ed25519 keys from the given `seed` with
+/// [SLIP-10](https://github.com/satoshilabs/slips/blob/master/slip-0010.md).
+pub fn derive_ed25519_keypair(seed: &Seed, path: &DerivationPath) -> Result<(PublicKey, SecretKey)> {
+  let seed: slip10::Seed = slip10::Seed::from_bytes(seed.as_ref());
+  let chain: slip10::Chain = slip10::Chain::from_u32_hardened(path.indexes().to_vec());
+  let key: slip10::Key = seed.derive(slip10::Curve::Ed25519, &chain)?;
+
+  let secret: ed25519::SecretKey = key.secret_key()?;
+  let public: ed25519::PublicKey = secret.public_key();
+
+  let secret: SecretKey = secret.to_bytes().to_vec().into();
+  let public: PublicKey = public.to_bytes().to_vec().into();
+
+  Ok((public, secret))
+}
+
+/// Derives a list of public/secret ed25519 keys from the given `seed` using
+/// the consecutive child indexes `0..count` of `path`.
+pub fn derive_ed25519_keypairs(
+  seed: &Seed,
+  path: &DerivationPath,
+  count: usize,
+) -> Result<Vec<(PublicKey, SecretKey)>> {
+  (0..count)
+    .map(|index| {
+      let index: u32 = u32::try_from(index).map_err(|_| Error::InvalidDerivationPath)?;
+
+      derive_ed25519_keypair(seed, &path.child(index)?)
+    })
+    .collect()
+}
@@ -1,10 +1,20 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-//! Misc. utility functions (encoding, decoding, and ed25519 utils).
+//! Misc. utility functions (encoding, decoding, and key generation utils).
 
 mod base_encoding;
+mod bls12381;
 mod ed25519;
+mod multicodec;
+mod p256;
+mod secp256k1;
+mod x25519;
 
 pub use self::base_encoding::*;
+pub use self::bls12381::*;
 pub use self::ed25519::*;
+pub use self::multicodec::*;
+pub use self::p256::*;
+pub use self::secp256k1::*;
+pub use self::x25519::*;
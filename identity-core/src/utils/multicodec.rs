@@ -0,0 +1,184 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::crypto::KeyType;
+use crate::error::Error;
+use crate::error::Result;
+
+// The maximum length of an unsigned varint, as defined by the multiformats spec.
+const MAX_VARINT_LEN: usize = 9;
+
+/// A [Multicodec] identifying the type of the data that follows it.
+///
+/// Only the public key codecs used by verification methods are supported.
+///
+/// [Multicodec]: https://github.com/multiformats/multicodec
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Multicodec {
+  /// An `Ed25519` public key (`0xed`).
+  Ed25519Pub,
+  /// An `X25519` public key (`0xec`).
+  X25519Pub,
+  /// A compressed `Secp256k1` public key (`0xe7`).
+  Secp256k1Pub,
+  /// A `BLS12-381` G2 public key (`0xeb`).
+  Bls12381G2Pub,
+  /// A compressed `NIST P-256` public key (`0x1200`).
+  P256Pub,
+}
+
+impl Multicodec {
+  /// Returns the numeric code of the `Multicodec`.
+  pub const fn code(self) -> u64 {
+    match self {
+      Self::Ed25519Pub => 0xed,
+      Self::X25519Pub => 0xec,
+      Self::Secp256k1Pub => 0xe7,
+      Self::Bls12381G2Pub => 0xeb,
+      Self::P256Pub => 0x1200,
+    }
+  }
+
+  /// Returns the `Multicodec` with the given numeric `code`, if supported.
+  pub const fn from_code(code: u64) -> Option<Self> {
+    match code {
+      0xed => Some(Self::Ed25519Pub),
+      0xec => Some(Self::X25519Pub),
+      0xe7 => Some(Self::Secp256k1Pub),
+      0xeb => Some(Self::Bls12381G2Pub),
+      0x1200 => Some(Self::P256Pub),
+      _ => None,
+    }
+  }
+
+  /// Returns the name of the `Multicodec` as a static `str`.
+  pub const fn as_str(self) -> &'static str {
+    match self {
+      Self::Ed25519Pub => "ed25519-pub",
+      Self::X25519Pub => "x25519-pub",
+      Self::Secp256k1Pub => "secp256k1-pub",
+      Self::Bls12381G2Pub => "bls12_381-g2-pub",
+      Self::P256Pub => "p256-pub",
+    }
+  }
+}
+
+impl From<KeyType> for Multicodec {
+  fn from(other: KeyType) -> Self {
+    match other {
+      KeyType::Ed25519 => Self::Ed25519Pub,
+      KeyType::Secp256k1 => Self::Secp256k1Pub,
+      KeyType::P256 => Self::P256Pub,
+      KeyType::Bls12381G2 => Self::Bls12381G2Pub,
+      KeyType::X25519 => Self::X25519Pub,
+    }
+  }
+}
+
+/// Prepends the varint-encoded `codec` to the given `data`.
+pub fn encode_multicodec<T>(codec: Multicodec, data: &T) -> Vec<u8>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  let data: &[u8] = data.as_ref();
+  let mut code: u64 = codec.code();
+  let mut output: Vec<u8> = Vec::with_capacity(MAX_VARINT_LEN + data.len());
+
+  while code >= 0x80 {
+    output.push((code as u8) | 0x80);
+    code >>= 7;
+  }
+
+  output.push(code as u8);
+  output.extend_from_slice(data);
+  output
+}
+
+/// Splits the given `data` into its [`Multicodec`] and the data that follows it.
+///
+/// # Errors
+///
+/// Fails if `data` does not start with a minimally-encoded varint or the
+/// codec is not supported.
+pub fn decode_multicodec<T>(data: &T) -> Result<(Multicodec, &[u8])>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  let data: &[u8] = data.as_ref();
+  let mut code: u64 = 0;
+
+  for (index, byte) in data.iter().copied().take(MAX_VARINT_LEN).enumerate() {
+    code |= u64::from(byte & 0x7f) << (index * 7);
+
+    if byte & 0x80 == 0 {
+      // A trailing zero byte is a non-minimal encoding of the same code
+      if byte == 0 && index > 0 {
+        return Err(Error::DecodeMulticodec("non-minimal varint"));
+      }
+
+      let codec: Multicodec = Multicodec::from_code(code).ok_or(Error::DecodeMulticodec("unsupported codec"))?;
+
+      return Ok((codec, &data[index + 1..]));
+    }
+  }
+
+  Err(Error::DecodeMulticodec("invalid varint"))
+}
+
+#[cfg(test)]
+mod tests {
+  use quickcheck_macros::quickcheck;
+
+  use super::*;
+  use crate::utils::decode_multibase;
+
+  const CODECS: &[Multicodec] = &[
+    Multicodec::Ed25519Pub,
+    Multicodec::X25519Pub,
+    Multicodec::Secp256k1Pub,
+    Multicodec::Bls12381G2Pub,
+    Multicodec::P256Pub,
+  ];
+
+  #[test]
+  fn test_encode_header() {
+    assert_eq!(
+      encode_multicodec(Multicodec::Ed25519Pub, b"\x01\x02"),
+      [0xed, 0x01, 1, 2]
+    );
+    assert_eq!(encode_multicodec(Multicodec::X25519Pub, b""), [0xec, 0x01]);
+    assert_eq!(encode_multicodec(Multicodec::P256Pub, b""), [0x80, 0x24]);
+  }
+
+  #[test]
+  fn test_decode_did_key() {
+    // https://w3c-ccg.github.io/did-method-key/#ed25519-x25519
+    let data: Vec<u8> = decode_multibase("z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK").unwrap();
+    let (codec, key): (Multicodec, &[u8]) = decode_multicodec(&data).unwrap();
+
+    assert_eq!(codec, Multicodec::Ed25519Pub);
+    assert_eq!(key.len(), 32);
+    assert_eq!(key[..4], [0x2e, 0x6f, 0xcc, 0xe3]);
+  }
+
+  #[test]
+  fn test_decode_invalid() {
+    assert!(decode_multicodec(b"").is_err());
+    assert!(decode_multicodec(b"\xed").is_err());
+    assert!(decode_multicodec(b"\x00\x01\x02").is_err());
+    assert!(decode_multicodec(b"\xed\x81\x00").is_err());
+    assert!(decode_multicodec(&[0xffu8; MAX_VARINT_LEN + 1]).is_err());
+  }
+
+  #[quickcheck]
+  fn test_multicodec_random(data: Vec<u8>) {
+    for codec in CODECS.iter().copied() {
+      assert_eq!(Multicodec::from_code(codec.code()), Some(codec));
+      assert_eq!(
+        decode_multicodec(&encode_multicodec(codec, &data)).unwrap(),
+        (codec, &data[..])
+      );
+    }
+  }
+}
@@ -0,0 +1,141 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(feature = "p256")]
+use p256::ecdsa;
+#[cfg(feature = "p256")]
+use p256::EncodedPoint;
+#[cfg(feature = "p256")]
+use p256::FieldBytes;
+#[cfg(feature = "p256")]
+use zeroize::Zeroize;
+
+use crate::crypto::PublicKey;
+use crate::crypto::SecretKey;
+use crate::error::Error;
+use crate::error::Result;
+
+/// Generates a new pair of public/secret P-256 keys.
+///
+/// The public key is returned in compressed SEC1 form (33 bytes).
+#[cfg(feature = "p256")]
+pub fn generate_p256_keypair() -> Result<(PublicKey, SecretKey)> {
+  let mut bytes: [u8; 32] = [0; 32];
+
+  // The probability of drawing an invalid scalar is negligible but not zero.
+  let secret: ecdsa::SigningKey = loop {
+    crypto::utils::rand::fill(&mut bytes)?;
+
+    if let Ok(secret) = ecdsa::SigningKey::from_bytes(&bytes) {
+      break secret;
+    }
+  };
+
+  bytes.zeroize();
+
+  let public: ecdsa::VerifyingKey = secret.verifying_key();
+
+  let secret: SecretKey = secret.to_bytes().to_vec().into();
+  let public: PublicKey = public.to_encoded_point(true).as_bytes().to_vec().into();
+
+  Ok((public, secret))
+}
+
+/// Generates a new pair of public/secret P-256 keys.
+///
+/// Always fails since the `p256` feature is disabled.
+#[cfg(not(feature = "p256"))]
+pub fn generate_p256_keypair() -> Result<(PublicKey, SecretKey)> {
+  Err(Error::MissingFeature("p256"))
+}
+
+/// Generates a list of public/secret P-256 keys.
+pub fn generate_p256_keypairs(count: usize) -> Result<Vec<(PublicKey, SecretKey)>> {
+  (0..count).map(|_| generate_p256_keypair()).collect()
+}
+
+/// Returns the base64url-encoded `x` and `y` coordinates of a SEC1-encoded
+/// P-256 public key, as used by the `EC` JSON Web Key type.
+#[cfg(feature = "p256")]
+pub fn encode_p256_jwk<T>(public: &T) -> Result<(String, String)>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  let public: ecdsa::VerifyingKey =
+    ecdsa::VerifyingKey::from_sec1_bytes(public.as_ref()).map_err(|_| Error::InvalidKeyFormat)?;
+
+  let point: EncodedPoint = public.to_encoded_point(false);
+  let x: &FieldBytes = point.x().ok_or(Error::InvalidKeyFormat)?;
+  let y: &FieldBytes = point.y().ok_or(Error::InvalidKeyFormat)?;
+
+  Ok((encode_b64_nopad(x), encode_b64_nopad(y)))
+}
+
+/// Creates a compressed SEC1-encoded P-256 public key from the base64url-encoded
+/// `x` and `y` coordinates of an `EC` JSON Web Key.
+#[cfg(feature = "p256")]
+pub fn decode_p256_jwk(x: &str, y: &str) -> Result<Vec<u8>> {
+  let x: FieldBytes = decode_coordinate(x)?;
+  let y: FieldBytes = decode_coordinate(y)?;
+
+  let point: EncodedPoint = EncodedPoint::from_affine_coordinates(&x, &y, true);
+  let public: ecdsa::VerifyingKey =
+    ecdsa::VerifyingKey::from_encoded_point(&point).map_err(|_| Error::InvalidKeyFormat)?;
+
+  Ok(public.to_encoded_point(true).as_bytes().to_vec())
+}
+
+/// Returns the base64url-encoded `x` and `y` coordinates of a SEC1-encoded
+/// P-256 public key.
+///
+/// Always fails since the `p256` feature is disabled.
+#[cfg(not(feature = "p256"))]
+pub fn encode_p256_jwk<T>(_: &T) -> Result<(String, String)>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  Err(Error::MissingFeature("p256"))
+}
+
+/// Creates a compressed SEC1-encoded P-256 public key from the base64url-encoded
+/// `x` and `y` coordinates of an `EC` JSON Web Key.
+///
+/// Always fails since the `p256` feature is disabled.
+#[cfg(not(feature = "p256"))]
+pub fn decode_p256_jwk(_: &str, _: &str) -> Result<Vec<u8>> {
+  Err(Error::MissingFeature("p256"))
+}
+
+#[cfg(feature = "p256")]
+fn decode_coordinate(data: &str) -> Result<FieldBytes> {
+  let bytes: Vec<u8> = base64::decode_config(data.trim_end_matches('='), base64::URL_SAFE_NO_PAD)?;
+  let mut output: FieldBytes = FieldBytes::default();
+
+  if bytes.len() != output.len() {
+    return Err(Error::InvalidKeyLength(bytes.len(), output.len()));
+  }
+
+  output.copy_from_slice(&bytes);
+
+  Ok(output)
+}
+
+#[cfg(feature = "p256")]
+fn encode_b64_nopad(data: &[u8]) -> String {
+  base64::encode_config(data, base64::URL_SAFE_NO_PAD)
+}
+
+#[cfg(all(test, feature = "p256"))]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_p256_jwk_roundtrip() {
+    let (public, _secret): (PublicKey, SecretKey) = generate_p256_keypair().unwrap();
+    let (x, y): (String, String) = encode_p256_jwk(&public).unwrap();
+
+    assert_eq!(x.len(), 43);
+    assert_eq!(y.len(), 43);
+    assert_eq!(decode_p256_jwk(&x, &y).unwrap(), public.as_ref());
+  }
+}
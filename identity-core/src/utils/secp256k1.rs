@@ -0,0 +1,52 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(feature = "secp256k1")]
+use k256::ecdsa;
+#[cfg(feature = "secp256k1")]
+use zeroize::Zeroize;
+
+use crate::crypto::PublicKey;
+use crate::crypto::SecretKey;
+#[cfg(not(feature = "secp256k1"))]
+use crate::error::Error;
+use crate::error::Result;
+
+/// Generates a new pair of public/secret secp256k1 keys.
+///
+/// The public key is returned in compressed SEC1 form (33 bytes).
+#[cfg(feature = "secp256k1")]
+pub fn generate_secp256k1_keypair() -> Result<(PublicKey, SecretKey)> {
+  let mut bytes: [u8; 32] = [0; 32];
+
+  // The probability of drawing an invalid scalar is negligible but not zero.
+  let secret: ecdsa::SigningKey = loop {
+    crypto::utils::rand::fill(&mut bytes)?;
+
+    if let Ok(secret) = ecdsa::SigningKey::from_bytes(&bytes) {
+      break secret;
+    }
+  };
+
+  bytes.zeroize();
+
+  let public: ecdsa::VerifyingKey = secret.verifying_key();
+
+  let secret: SecretKey = secret.to_bytes().to_vec().into();
+  let public: PublicKey = public.to_bytes().to_vec().into();
+
+  Ok((public, secret))
+}
+
+/// Generates a new pair of public/secret secp256k1 keys.
+///
+/// Always fails since the `secp256k1` feature is disabled.
+#[cfg(not(feature = "secp256k1"))]
+pub fn generate_secp256k1_keypair() -> Result<(PublicKey, SecretKey)> {
+  Err(Error::MissingFeature("secp256k1"))
+}
+
+/// Generates a list of public/secret secp256k1 keys.
+pub fn generate_secp256k1_keypairs(count: usize) -> Result<Vec<(PublicKey, SecretKey)>> {
+  (0..count).map(|_| generate_secp256k1_keypair()).collect()
+}
@@ -0,0 +1,24 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::x25519;
+
+use crate::crypto::PublicKey;
+use crate::crypto::SecretKey;
+use crate::error::Result;
+
+/// Generates a new pair of public/secret x25519 keys.
+pub fn generate_x25519_keypair() -> Result<(PublicKey, SecretKey)> {
+  let secret: x25519::SecretKey = x25519::SecretKey::generate()?;
+  let public: x25519::PublicKey = secret.public_key();
+
+  let secret: SecretKey = secret.to_bytes().to_vec().into();
+  let public: PublicKey = public.to_bytes().to_vec().into();
+
+  Ok((public, secret))
+}
+
+/// Generates a list of public/secret x25519 keys.
+pub fn generate_x25519_keypairs(count: usize) -> Result<Vec<(PublicKey, SecretKey)>> {
+  (0..count).map(|_| generate_x25519_keypair()).collect()
+}
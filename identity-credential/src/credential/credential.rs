@@ -10,13 +10,18 @@ use identity_core::common::Object;
 use identity_core::common::OneOrMany;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
+use identity_core::convert::FromJson;
 use identity_core::convert::ToJson;
+use identity_core::crypto::bbs::JcsBbsBls12381SignatureProof2021;
+use identity_core::crypto::PublicKey;
 use identity_core::crypto::SetSignature;
 use identity_core::crypto::Signature;
+use identity_core::crypto::SignatureSet;
 use identity_core::crypto::TrySignature;
 use identity_core::crypto::TrySignatureMut;
 use identity_did::verification::MethodUriType;
 use identity_did::verification::TryMethod;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use crate::credential::CredentialBuilder;
@@ -30,6 +35,9 @@ use crate::credential::Subject;
 use crate::error::Error;
 use crate::error::Result;
 
+// Credential properties that are always disclosed in a derived `Credential`.
+const MANDATORY_CLAIMS: &[&str] = &["/@context", "/type", "/issuer", "/issuanceDate"];
+
 lazy_static! {
   static ref BASE_CONTEXT: Context = Context::Url(Url::parse("https://www.w3.org/2018/credentials/v1").unwrap());
 }
@@ -80,8 +88,8 @@ pub struct Credential<T = Object> {
   #[serde(flatten)]
   pub properties: T,
   /// Proof(s) used to verify a `Credential`
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub proof: Option<Signature>,
+  #[serde(default, skip_serializing_if = "OneOrMany::is_empty")]
+  pub proof: OneOrMany<Signature>,
 }
 
 impl<T> Credential<T> {
@@ -119,7 +127,7 @@ impl<T> Credential<T> {
       evidence: builder.evidence.into(),
       non_transferable: builder.non_transferable,
       properties: builder.properties,
-      proof: None,
+      proof: OneOrMany::default(),
     };
 
     this.check_structure()?;
@@ -156,13 +164,44 @@ impl<T> Credential<T> {
   }
 
   /// Returns a reference to the proof.
+  ///
+  /// Returns `None` if the `Credential` has no proof or a set of proofs.
   pub fn proof(&self) -> Option<&Signature> {
-    self.proof.as_ref()
+    if self.proof.len() == 1 {
+      self.proof.get(0)
+    } else {
+      None
+    }
   }
 
   /// Returns a mutable reference to the proof.
+  ///
+  /// Returns `None` if the `Credential` has no proof or a set of proofs.
   pub fn proof_mut(&mut self) -> Option<&mut Signature> {
-    self.proof.as_mut()
+    if self.proof.len() == 1 {
+      self.proof.get_mut(0)
+    } else {
+      None
+    }
+  }
+}
+
+impl<T> Credential<T>
+where
+  T: Serialize + DeserializeOwned,
+{
+  /// Derives a new `Credential` that discloses only the claims selected by the
+  /// `reveal` JSON Pointers (e.g. `/credentialSubject/degree`).
+  ///
+  /// The `Credential` must be signed with a `JcsBbsBls12381Signature2021` created by
+  /// the `public` key. The derived `Credential` carries a
+  /// `JcsBbsBls12381SignatureProof2021` bound to the given `nonce`. The `@context`,
+  /// `type`, `issuer`, and `issuanceDate` properties are always disclosed.
+  pub fn derive_proof(&self, public: &PublicKey, reveal: &[&str], nonce: &[u8]) -> Result<Self> {
+    let reveal: Vec<&str> = MANDATORY_CLAIMS.iter().chain(reveal.iter()).copied().collect();
+    let derived: Object = JcsBbsBls12381SignatureProof2021::derive(self, public, &reveal, nonce)?;
+
+    Self::from_json_value(derived.to_json_value()?).map_err(Into::into)
   }
 }
 
@@ -181,19 +220,38 @@ where
 
 impl<T> TrySignature for Credential<T> {
   fn signature(&self) -> Option<&Signature> {
-    self.proof.as_ref()
+    self.proof()
+  }
+
+  fn try_signature(&self) -> identity_core::Result<&Signature> {
+    self.try_single_signature()
   }
 }
 
 impl<T> TrySignatureMut for Credential<T> {
   fn signature_mut(&mut self) -> Option<&mut Signature> {
-    self.proof.as_mut()
+    self.proof_mut()
+  }
+
+  fn try_signature_mut(&mut self) -> identity_core::Result<&mut Signature> {
+    self.try_single_signature_mut()
   }
 }
 
 impl<T> SetSignature for Credential<T> {
+  /// Replaces every proof of the set with the given [`Signature`].
   fn set_signature(&mut self, value: Signature) {
-    self.proof.replace(value);
+    self.proof = OneOrMany::One(value);
+  }
+}
+
+impl<T> SignatureSet for Credential<T> {
+  fn signatures(&self) -> &OneOrMany<Signature> {
+    &self.proof
+  }
+
+  fn signatures_mut(&mut self) -> &mut OneOrMany<Signature> {
+    &mut self.proof
   }
 }
 
@@ -203,9 +261,24 @@ impl<T> TryMethod for Credential<T> {
 
 #[cfg(test)]
 mod tests {
+  use identity_core::common::OneOrMany;
+  use identity_core::common::Url;
+  use identity_core::convert::FromCbor;
   use identity_core::convert::FromJson;
+  use identity_core::convert::ToCbor;
+  use identity_core::crypto::bbs::JcsBbsBls12381Signature2021;
+  use identity_core::crypto::bbs::JcsBbsBls12381SignatureProof2021;
+  use identity_core::crypto::CborEd25519;
+  use identity_core::crypto::Ed25519;
+  use identity_core::crypto::KeyPair;
+  use identity_core::crypto::KeyType;
+  use identity_core::crypto::Signer;
+  use identity_core::crypto::Verifier;
+  use identity_core::crypto::VerifierOptions;
+  use identity_core::json;
 
   use crate::credential::Credential;
+  use crate::credential::Subject;
 
   const JSON1: &str = include_str!("../../tests/fixtures/credential-1.json");
   const JSON2: &str = include_str!("../../tests/fixtures/credential-2.json");
@@ -235,4 +308,84 @@ mod tests {
     let _credential: Credential = Credential::from_json(JSON11).unwrap();
     let _credential: Credential = Credential::from_json(JSON12).unwrap();
   }
+
+  #[cfg(feature = "cbor")]
+  #[test]
+  fn test_cbor() {
+    for json in [
+      JSON1, JSON2, JSON3, JSON4, JSON5, JSON6, JSON7, JSON8, JSON9, JSON10, JSON11, JSON12,
+    ] {
+      let credential: Credential = Credential::from_json(json).unwrap();
+      let cbor: Vec<u8> = credential.to_cbor().unwrap();
+
+      assert_eq!(Credential::from_cbor(&cbor).unwrap(), credential);
+    }
+  }
+
+  #[cfg(feature = "cbor")]
+  #[test]
+  fn test_cbor_signature() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let mut credential: Credential = Credential::from_json(JSON1).unwrap();
+
+    credential.proof = OneOrMany::default();
+
+    CborEd25519::<Ed25519>::create_signature(&mut credential, "did:example:issuer#key-1", keypair.secret().as_ref())
+      .unwrap();
+
+    let credential: Credential = Credential::from_cbor(&credential.to_cbor().unwrap()).unwrap();
+
+    assert!(CborEd25519::<Ed25519>::verify_signature(&credential, keypair.public().as_ref()).is_ok());
+  }
+
+  #[cfg(feature = "bbs")]
+  #[test]
+  fn test_derive_proof() {
+    let keypair: KeyPair = KeyPair::new(KeyType::Bls12381G2).unwrap();
+
+    let subject: Subject = Subject::from_json_value(json!({
+      "id": "did:example:ebfeb1f712ebc6f1c276e12ec21",
+      "birthDate": "1990-01-01",
+      "degree": {
+        "type": "BachelorDegree",
+        "name": "Bachelor of Science and Arts"
+      }
+    }))
+    .unwrap();
+
+    let mut credential: Credential = Credential::builder(Default::default())
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .type_("UniversityDegreeCredential")
+      .subject(subject)
+      .build()
+      .unwrap();
+
+    JcsBbsBls12381Signature2021::create_signature(&mut credential, "did:example:issuer#key-1", keypair.secret())
+      .unwrap();
+
+    let derived: Credential = credential
+      .derive_proof(keypair.public(), &["/credentialSubject/degree"], b"nonce")
+      .unwrap();
+
+    assert_eq!(derived.issuer.url(), credential.issuer.url());
+    assert_eq!(derived.credential_subject.get(0).unwrap().id, None);
+    assert!(derived
+      .credential_subject
+      .get(0)
+      .unwrap()
+      .properties
+      .contains_key("degree"));
+    assert!(!derived
+      .credential_subject
+      .get(0)
+      .unwrap()
+      .properties
+      .contains_key("birthDate"));
+
+    let options: VerifierOptions = VerifierOptions::new().nonce(b"nonce".to_vec());
+
+    assert!(
+      JcsBbsBls12381SignatureProof2021::verify_signature_with_options(&derived, keypair.public(), &options).is_ok()
+    );
+  }
 }
@@ -9,6 +9,9 @@ pub type Result<T, E = Error> = ::core::result::Result<T, E>;
 /// This type represents all possible errors that can occur in the library.
 #[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
 pub enum Error {
+  /// Caused by errors from the [identity_core] crate.
+  #[error("{0}")]
+  CoreError(#[from] identity_core::Error),
   /// Caused by errors from the [identity_did] crate.
   #[error("{0}")]
   DIDError(#[from] identity_did::Error),
@@ -12,6 +12,7 @@ use identity_core::common::Url;
 use identity_core::convert::ToJson;
 use identity_core::crypto::SetSignature;
 use identity_core::crypto::Signature;
+use identity_core::crypto::SignatureSet;
 use identity_core::crypto::TrySignature;
 use identity_core::crypto::TrySignatureMut;
 use identity_did::verification::MethodUriType;
@@ -53,8 +54,8 @@ pub struct Presentation<T = Object, U = Object> {
   #[serde(flatten)]
   pub properties: T,
   /// Proof(s) used to verify a `Presentation`
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub proof: Option<Signature>,
+  #[serde(default, skip_serializing_if = "OneOrMany::is_empty")]
+  pub proof: OneOrMany<Signature>,
 }
 
 impl<T, U> Presentation<T, U> {
@@ -86,7 +87,7 @@ impl<T, U> Presentation<T, U> {
       refresh_service: builder.refresh.into(),
       terms_of_use: builder.policy.into(),
       properties: builder.properties,
-      proof: None,
+      proof: OneOrMany::default(),
     };
 
     this.check_structure()?;
@@ -116,13 +117,25 @@ impl<T, U> Presentation<T, U> {
   }
 
   /// Returns a reference to the `Presentation` proof.
+  ///
+  /// Returns `None` if the `Presentation` has no proof or a set of proofs.
   pub fn proof(&self) -> Option<&Signature> {
-    self.proof.as_ref()
+    if self.proof.len() == 1 {
+      self.proof.get(0)
+    } else {
+      None
+    }
   }
 
   /// Returns a mutable reference to the `Presentation` proof.
+  ///
+  /// Returns `None` if the `Presentation` has no proof or a set of proofs.
   pub fn proof_mut(&mut self) -> Option<&mut Signature> {
-    self.proof.as_mut()
+    if self.proof.len() == 1 {
+      self.proof.get_mut(0)
+    } else {
+      None
+    }
   }
 }
 
@@ -142,19 +155,38 @@ where
 
 impl<T, U> TrySignature for Presentation<T, U> {
   fn signature(&self) -> Option<&Signature> {
-    self.proof.as_ref()
+    self.proof()
+  }
+
+  fn try_signature(&self) -> identity_core::Result<&Signature> {
+    self.try_single_signature()
   }
 }
 
 impl<T, U> TrySignatureMut for Presentation<T, U> {
   fn signature_mut(&mut self) -> Option<&mut Signature> {
-    self.proof.as_mut()
+    self.proof_mut()
+  }
+
+  fn try_signature_mut(&mut self) -> identity_core::Result<&mut Signature> {
+    self.try_single_signature_mut()
   }
 }
 
 impl<T, U> SetSignature for Presentation<T, U> {
+  /// Replaces every proof of the set with the given [`Signature`].
   fn set_signature(&mut self, value: Signature) {
-    self.proof.replace(value);
+    self.proof = OneOrMany::One(value);
+  }
+}
+
+impl<T, U> SignatureSet for Presentation<T, U> {
+  fn signatures(&self) -> &OneOrMany<Signature> {
+    &self.proof
+  }
+
+  fn signatures_mut(&mut self) -> &mut OneOrMany<Signature> {
+    &mut self.proof
   }
 }
 
@@ -167,7 +199,9 @@ mod tests {
   use super::Presentation;
   use crate::credential::Credential;
   use crate::credential::Subject;
+  use identity_core::convert::FromCbor;
   use identity_core::convert::FromJson;
+  use identity_core::convert::ToCbor;
 
   const JSON: &str = include_str!("../../tests/fixtures/presentation-1.json");
 
@@ -216,4 +250,13 @@ mod tests {
       "Bachelor of Science in Mechanical Engineering"
     );
   }
+  #[cfg(feature = "cbor")]
+  #[test]
+  fn test_cbor() {
+    let presentation: Presentation = Presentation::from_json(JSON).unwrap();
+    let cbor: Vec<u8> = presentation.to_cbor().unwrap();
+
+    assert_eq!(Presentation::from_cbor(&cbor).unwrap(), presentation);
+    assert!(cbor.len() < JSON.len());
+  }
 }
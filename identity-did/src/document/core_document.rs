@@ -315,6 +315,41 @@ impl<T, U, V> CoreDocument<T, U, V> {
     self.resolve(query).ok_or(Error::QueryMethodNotFound)
   }
 
+  /// Returns the first verification [`method`][`VerificationMethod`] of the
+  /// verification relationship `scope` with an `id` property matching the
+  /// provided `query`.
+  pub fn resolve_with_scope<'query, Q>(&self, query: Q, scope: MethodScope) -> Option<&VerificationMethod<U>>
+  where
+    Q: Into<MethodQuery<'query>>,
+  {
+    let query: MethodQuery<'_> = query.into();
+
+    let method: &MethodRef<U> = match scope {
+      MethodScope::VerificationMethod => return self.verification_method.query(query),
+      MethodScope::Authentication => self.authentication.query(query)?,
+      MethodScope::AssertionMethod => self.assertion_method.query(query)?,
+      MethodScope::KeyAgreement => self.key_agreement.query(query)?,
+      MethodScope::CapabilityDelegation => self.capability_delegation.query(query)?,
+      MethodScope::CapabilityInvocation => self.capability_invocation.query(query)?,
+    };
+
+    self.resolve_ref(method)
+  }
+
+  /// Returns the first verification [`method`][`VerificationMethod`] of the
+  /// verification relationship `scope` with an `id` property matching the
+  /// provided `query`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if no matching `VerificationMethod` is found.
+  pub fn try_resolve_with_scope<'query, Q>(&self, query: Q, scope: MethodScope) -> Result<&VerificationMethod<U>>
+  where
+    Q: Into<MethodQuery<'query>>,
+  {
+    self.resolve_with_scope(query, scope).ok_or(Error::QueryMethodNotFound)
+  }
+
   /// Returns a mutable reference to the first verification [`method`][`VerificationMethod`]
   /// with an `id` property matching the provided `query`.
   pub fn resolve_mut<'query, Q>(&mut self, query: Q) -> Option<&mut VerificationMethod<U>>
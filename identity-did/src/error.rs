@@ -46,6 +46,10 @@ pub enum Error {
   InvalidMethodType,
   #[error("Invalid Verification Method - Duplicate")]
   InvalidMethodDuplicate,
+  #[error("Invalid DID Method Name")]
+  InvalidMethodName,
+  #[error("Invalid DID Method Id")]
+  InvalidMethodId,
 
   #[error("Unknown Method Scope")]
   UnknownMethodScope,
@@ -53,6 +57,8 @@ pub enum Error {
   UnknownMethodType,
   #[error("Unknown Signature Type")]
   UnknownSignatureType,
+  #[error("Invalid Batch: {0}")]
+  InvalidBatch(::identity_core::crypto::BatchError<Error>),
 
   #[error("Invalid Key Data")]
   InvalidKeyData,
@@ -62,6 +68,10 @@ pub enum Error {
   InvalidKeyDataBase58,
   #[error("Invalid Multibase Key Data")]
   InvalidKeyDataMultibase,
+  #[error("Invalid Multicodec Key Data")]
+  InvalidKeyDataMulticodec,
+  #[error("Invalid JWK Key Data")]
+  InvalidKeyDataJwk,
 
   #[error("Missing Resolution DID")]
   MissingResolutionDID,
@@ -71,10 +81,14 @@ pub enum Error {
   MissingResolutionDocument,
   #[error("Missing Resolution Document/Metadata")]
   MissingResolutionData,
+  #[error("Invalid DID Resolution Input")]
+  InvalidResolutionInput,
   #[error("Invalid DID Resolution Query")]
   InvalidDIDQuery,
   #[error("Invalid DID Resolution Fragment")]
   InvalidDIDFragment,
   #[error("Invalid DID Resolution Service")]
   InvalidServiceProtocol,
+  #[error("Failed to Fetch DID Document: {0}")]
+  FetchFailed(String),
 }
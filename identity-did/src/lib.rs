@@ -26,6 +26,7 @@ pub use did_url as did;
 pub mod diff;
 pub mod document;
 pub mod error;
+pub mod methods;
 pub mod resolution;
 pub mod service;
 pub mod utils;
@@ -0,0 +1,265 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_core::common::Object;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use identity_core::utils::decode_b64;
+use identity_core::utils::encode_b64;
+use libjose::jwk::EcxCurve;
+use libjose::jwk::Jwk;
+use libjose::jwk::JwkType;
+use libjose::jwk::JwkUse;
+
+use crate::did::DID;
+use crate::document::CoreDocument;
+use crate::document::DocumentBuilder;
+use crate::error::Error;
+use crate::error::Result;
+use crate::methods::key::signature_relationships;
+use crate::resolution::DocumentMetadata;
+use crate::resolution::InputMetadata;
+use crate::resolution::MetaDocument;
+use crate::resolution::ResolverMethod;
+use crate::verification::MethodData;
+use crate::verification::MethodType;
+use crate::verification::VerificationMethod;
+
+/// A resolver for the [`did:jwk`][SPEC] method.
+///
+/// A `did:jwk` DID encodes a single public JSON Web Key; the DID Document is
+/// expanded from the DID itself and requires no verifiable data registry.
+///
+/// [SPEC]: https://github.com/quartzjer/did-jwk/blob/main/spec.md
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JwkMethod;
+
+impl JwkMethod {
+  /// The name of the `did:jwk` method.
+  pub const NAME: &'static str = "jwk";
+
+  /// The fragment of the verification method of a `did:jwk` DID Document.
+  pub const FRAGMENT: &'static str = "0";
+
+  /// Creates a new `JwkMethod` resolver.
+  pub const fn new() -> Self {
+    Self
+  }
+
+  /// Creates a `did:jwk` DID from the public parameters of the given `jwk`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `jwk` is a symmetric (`oct`) key.
+  pub fn from_jwk(jwk: &Jwk) -> Result<DID> {
+    if jwk.kty() == JwkType::Oct {
+      return Err(Error::InvalidKeyDataJwk);
+    }
+
+    let method_id: String = encode_b64(&jwk.to_public().to_json_vec()?);
+
+    format!("did:{}:{}", Self::NAME, method_id.trim_end_matches('='))
+      .parse()
+      .map_err(Into::into)
+  }
+
+  /// Expands a `did:jwk` DID into a DID Document.
+  ///
+  /// The JWK is embedded as a `JsonWebKey2020` verification method with the
+  /// fragment `#0`. Keys with `"use": "sig"` are referenced by the signature
+  /// relationships only, keys with `"use": "enc"` and `X25519` keys by the
+  /// `keyAgreement` relationship only, and any other key by both.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `did` is not a valid `did:jwk` DID.
+  pub fn expand(did: &DID) -> Result<CoreDocument> {
+    if did.method() != Self::NAME {
+      return Err(Error::InvalidMethodName);
+    }
+
+    // Remove any path, query, or fragment from the input DID URL.
+    let did: DID = format!("did:{}:{}", Self::NAME, did.method_id()).parse()?;
+    let data: Vec<u8> = decode_b64(did.method_id()).map_err(|_| Error::InvalidMethodId)?;
+
+    let jwk: Jwk = Jwk::from_json_slice(&data).map_err(|_| Error::InvalidMethodId)?;
+    let object: Object = Object::from_json_slice(&data).map_err(|_| Error::InvalidMethodId)?;
+
+    // The JWK MUST NOT contain private or symmetric key material.
+    if jwk.kty() == JwkType::Oct || jwk.params() != &jwk.params().to_public() {
+      return Err(Error::InvalidMethodId);
+    }
+
+    let method: VerificationMethod = VerificationMethod::builder(Object::new())
+      .id(did.join(format!("#{}", Self::FRAGMENT))?)
+      .controller(did.clone())
+      .key_type(MethodType::JsonWebKey2020)
+      .key_data(MethodData::PublicKeyJwk(object))
+      .build()?;
+
+    let agreement_only: bool = matches!(jwk.try_ecx_curve(), Ok(EcxCurve::X25519) | Ok(EcxCurve::X448));
+
+    let mut builder: DocumentBuilder = CoreDocument::builder(Object::new())
+      .id(did)
+      .verification_method(method.clone());
+
+    if jwk.use_() != Some(JwkUse::Encryption) && !agreement_only {
+      builder = signature_relationships(builder, method.id());
+    }
+
+    if jwk.use_() != Some(JwkUse::Signature) {
+      builder = builder.key_agreement(method.id().clone());
+    }
+
+    builder.build()
+  }
+}
+
+#[async_trait(?Send)]
+impl ResolverMethod for JwkMethod {
+  fn is_supported(&self, did: &DID) -> bool {
+    did.method() == Self::NAME
+  }
+
+  async fn read(&self, did: &DID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
+    Ok(Some(MetaDocument::new(Self::expand(did)?, DocumentMetadata::new())))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Object;
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+  use identity_core::crypto::KeyPair;
+  use identity_core::utils::encode_b64;
+  use libjose::jwk::EdCurve;
+  use libjose::jwk::Jwk;
+  use libjose::jwk::JwkParamsOkp;
+  use libjose::jwk::JwkUse;
+
+  use crate::did::DID;
+  use crate::document::CoreDocument;
+  use crate::methods::JwkMethod;
+  use crate::resolution::resolve;
+  use crate::resolution::ErrorKind;
+  use crate::resolution::InputMetadata;
+  use crate::resolution::Resolution;
+  use crate::verifiable::Properties;
+  use crate::verification::MethodData;
+  use crate::verification::MethodType;
+  use crate::verification::VerificationMethod;
+
+  // The P-256 example of the did:jwk specification
+  const P256: &str = "did:jwk:eyJjcnYiOiJQLTI1NiIsImt0eSI6IkVDIiwieCI6ImFjYklRaXVNczNpOF91c3pFakoydHBUdFJNNEVVM3l6OTFQSDZDZEgyVjAiLCJ5IjoiX0tjeUxqOXZXTXB0bm1LdG00NkdxRHo4d2Y3NEk1TEtncmwyR3pIM25TRSJ9";
+
+  // The X25519 example of the did:jwk specification
+  const X25519: &str = "did:jwk:eyJrdHkiOiJPS1AiLCJjcnYiOiJYMjU1MTkiLCJ1c2UiOiJlbmMiLCJ4IjoiM3A3YmZYdDl3YlRUVzJIQzdPUTFOei1EUThoYmVHZE5yZngtRkctSUswOCJ9";
+
+  fn ed25519_jwk(public: &[u8], secret: Option<&[u8]>) -> Jwk {
+    Jwk::from_params(JwkParamsOkp {
+      crv: "Ed25519".into(),
+      x: encode_b64(public),
+      d: secret.map(encode_b64),
+    })
+  }
+
+  #[test]
+  fn test_expand_p256() {
+    let did: DID = P256.parse().unwrap();
+    let document: CoreDocument = JwkMethod::expand(&did.join("#0").unwrap()).unwrap();
+    let key: DID = did.join("#0").unwrap();
+
+    assert_eq!(document.id(), &did);
+    assert_eq!(document.verification_method().len(), 1);
+    assert_eq!(document.authentication().head().unwrap().id(), &key);
+    assert_eq!(document.assertion_method().head().unwrap().id(), &key);
+    assert_eq!(document.capability_delegation().head().unwrap().id(), &key);
+    assert_eq!(document.capability_invocation().head().unwrap().id(), &key);
+    assert_eq!(document.key_agreement().head().unwrap().id(), &key);
+
+    let method: &VerificationMethod = document.resolve("#0").unwrap();
+
+    assert_eq!(method.key_type(), MethodType::JsonWebKey2020);
+    assert_eq!(
+      method.try_key_type().unwrap(),
+      MethodType::EcdsaSecp256r1VerificationKey2019
+    );
+    assert_eq!(method.key_data().try_decode().unwrap().len(), 33);
+  }
+
+  #[test]
+  fn test_expand_x25519() {
+    let did: DID = X25519.parse().unwrap();
+    let document: CoreDocument = JwkMethod::expand(&did).unwrap();
+    let method: &VerificationMethod = document.resolve("#0").unwrap();
+
+    assert!(document.authentication().is_empty());
+    assert!(document.assertion_method().is_empty());
+    assert_eq!(document.key_agreement().len(), 1);
+    assert_eq!(method.try_key_type().unwrap(), MethodType::X25519KeyAgreementKey2019);
+    assert_eq!(method.key_data().try_decode().unwrap().len(), 32);
+  }
+
+  #[test]
+  fn test_from_jwk() {
+    let mut jwk: Jwk = Jwk::random(EdCurve::Ed25519).unwrap();
+
+    jwk.set_use(JwkUse::Signature);
+
+    let did: DID = JwkMethod::from_jwk(&jwk).unwrap();
+    let document: CoreDocument = JwkMethod::expand(&did).unwrap();
+    let method: &VerificationMethod = document.resolve("#0").unwrap();
+
+    assert!(!did.method_id().contains('='));
+    assert!(document.key_agreement().is_empty());
+    assert_eq!(document.authentication().len(), 1);
+    assert_eq!(
+      method.key_data(),
+      &MethodData::PublicKeyJwk(Object::from_json_value(jwk.to_public().to_json_value().unwrap()).unwrap())
+    );
+  }
+
+  #[test]
+  fn test_sign_verify() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let did: DID = JwkMethod::from_jwk(&ed25519_jwk(keypair.public().as_ref(), None)).unwrap();
+    let mut document: CoreDocument<Properties> = JwkMethod::expand(&did).unwrap().map(Properties::new);
+
+    document.sign_this("#0", keypair.secret()).unwrap();
+
+    assert!(document.verify_this().is_ok());
+  }
+
+  #[test]
+  fn test_expand_invalid() {
+    let invalid = |did: &str| JwkMethod::expand(&did.parse().unwrap()).is_err();
+
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let private: Jwk = ed25519_jwk(keypair.public().as_ref(), Some(keypair.secret().as_ref()));
+    let encoded: String = encode_b64(&private.to_json_vec().unwrap());
+
+    // Private keys are removed when creating the DID
+    assert!(!invalid(&JwkMethod::from_jwk(&private).unwrap().to_string()));
+    assert!(invalid(&format!("did:jwk:{}", encoded.trim_end_matches('='))));
+
+    assert!(invalid(&X25519.replace("did:jwk:", "did:example:")));
+    assert!(invalid("did:jwk:eyJrdHkiOiJPS1Ai"));
+    assert!(invalid("did:jwk:not-a-jwk"));
+  }
+
+  #[test]
+  fn test_resolve() {
+    let resolution: Resolution =
+      futures::executor::block_on(resolve(P256, InputMetadata::new(), JwkMethod::new())).unwrap();
+
+    assert!(resolution.metadata.error.is_none());
+    assert_eq!(resolution.document.unwrap().id().as_str(), P256);
+
+    let resolution: Resolution =
+      futures::executor::block_on(resolve("did:jwk:not-a-jwk", InputMetadata::new(), JwkMethod::new())).unwrap();
+
+    assert_eq!(resolution.metadata.error, Some(ErrorKind::InvalidDID));
+  }
+}
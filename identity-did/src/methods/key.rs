@@ -0,0 +1,266 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_core::common::Object;
+use identity_core::crypto::KeyPair;
+use identity_core::crypto::KeyType;
+use identity_core::crypto::X25519;
+use identity_core::utils::decode_multibase;
+use identity_core::utils::decode_multicodec;
+use identity_core::utils::encode_multibase;
+use identity_core::utils::encode_multicodec;
+use identity_core::utils::Multicodec;
+
+use crate::did::DID;
+use crate::document::CoreDocument;
+use crate::document::DocumentBuilder;
+use crate::error::Error;
+use crate::error::Result;
+use crate::resolution::DocumentMetadata;
+use crate::resolution::InputMetadata;
+use crate::resolution::MetaDocument;
+use crate::resolution::ResolverMethod;
+use crate::verification::MethodData;
+use crate::verification::MethodType;
+use crate::verification::VerificationMethod;
+
+/// A resolver for the [`did:key`][SPEC] method.
+///
+/// A `did:key` DID encodes a single public key; the DID Document is expanded
+/// from the DID itself and requires no verifiable data registry.
+///
+/// [SPEC]: https://w3c-ccg.github.io/did-method-key/
+#[derive(Clone, Copy, Debug, Default)]
+pub struct KeyMethod;
+
+impl KeyMethod {
+  /// The name of the `did:key` method.
+  pub const NAME: &'static str = "key";
+
+  /// Creates a new `KeyMethod` resolver.
+  pub const fn new() -> Self {
+    Self
+  }
+
+  /// Creates a `did:key` DID from the public key of the given `keypair`.
+  pub fn from_keypair(keypair: &KeyPair) -> Result<DID> {
+    Self::from_public_key(keypair.type_(), keypair.public())
+  }
+
+  /// Creates a `did:key` DID from a `public` key of the given `key_type`.
+  pub fn from_public_key<T>(key_type: KeyType, public: &T) -> Result<DID>
+  where
+    T: AsRef<[u8]> + ?Sized,
+  {
+    let method_id: String = encode_key(key_type.into(), public.as_ref());
+
+    format!("did:{}:{}", Self::NAME, method_id).parse().map_err(Into::into)
+  }
+
+  /// Expands a `did:key` DID into a DID Document.
+  ///
+  /// Signature keys are referenced by the `authentication`, `assertionMethod`,
+  /// `capabilityDelegation` and `capabilityInvocation` relationships. `Ed25519`
+  /// keys additionally derive an `X25519` key for the `keyAgreement` relationship.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `did` is not a valid `did:key` DID.
+  pub fn expand(did: &DID) -> Result<CoreDocument> {
+    if did.method() != Self::NAME {
+      return Err(Error::InvalidMethodName);
+    }
+
+    // Remove any path, query, or fragment from the input DID URL.
+    let did: DID = format!("did:{}:{}", Self::NAME, did.method_id()).parse()?;
+    let key: String = did.method_id().to_string();
+
+    expand_key(did, &key)
+  }
+}
+
+#[async_trait(?Send)]
+impl ResolverMethod for KeyMethod {
+  fn is_supported(&self, did: &DID) -> bool {
+    did.method() == Self::NAME
+  }
+
+  async fn read(&self, did: &DID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
+    Ok(Some(MetaDocument::new(Self::expand(did)?, DocumentMetadata::new())))
+  }
+}
+
+/// Expands a DID Document with the id `did` from a single multibase-encoded
+/// public `key`, as specified by the `did:key` method.
+pub(crate) fn expand_key(did: DID, key: &str) -> Result<CoreDocument> {
+  let (codec, public): (Multicodec, Vec<u8>) = decode_key(key)?;
+  let method: VerificationMethod = key_method(&did, key, codec, &public)?;
+
+  let mut builder: DocumentBuilder = CoreDocument::builder(Object::new())
+    .id(did.clone())
+    .verification_method(method.clone());
+
+  match codec {
+    Multicodec::X25519Pub => {
+      builder = builder.key_agreement(method.id().clone());
+    }
+    Multicodec::Ed25519Pub => {
+      let x25519: [u8; 32] = X25519::ed25519_to_x25519_public(&public)?;
+      let fragment: String = encode_key(Multicodec::X25519Pub, &x25519);
+      let agreement: VerificationMethod = key_method(&did, &fragment, Multicodec::X25519Pub, &x25519)?;
+
+      builder = signature_relationships(builder, method.id())
+        .verification_method(agreement.clone())
+        .key_agreement(agreement.id().clone());
+    }
+    _ => {
+      builder = signature_relationships(builder, method.id());
+    }
+  }
+
+  builder.build()
+}
+
+pub(crate) fn encode_key(codec: Multicodec, public: &[u8]) -> String {
+  encode_multibase(&encode_multicodec(codec, public), None)
+}
+
+pub(crate) fn decode_key(key: &str) -> Result<(Multicodec, Vec<u8>)> {
+  // The key MUST be base58-btc encoded.
+  if !key.starts_with('z') {
+    return Err(Error::InvalidMethodId);
+  }
+
+  let data: Vec<u8> = decode_multibase(key).map_err(|_| Error::InvalidMethodId)?;
+  let (codec, public): (Multicodec, &[u8]) = decode_multicodec(&data).map_err(|_| Error::InvalidKeyDataMulticodec)?;
+
+  Ok((codec, public.to_vec()))
+}
+
+pub(crate) fn key_method(did: &DID, fragment: &str, codec: Multicodec, public: &[u8]) -> Result<VerificationMethod> {
+  let (key_type, length): (MethodType, usize) = match codec {
+    Multicodec::Ed25519Pub => (MethodType::Ed25519VerificationKey2018, 32),
+    Multicodec::X25519Pub => (MethodType::X25519KeyAgreementKey2019, 32),
+    Multicodec::Secp256k1Pub => (MethodType::EcdsaSecp256k1VerificationKey2019, 33),
+    Multicodec::Bls12381G2Pub => (MethodType::Bls12381G2Key2020, 96),
+    Multicodec::P256Pub => (MethodType::EcdsaSecp256r1VerificationKey2019, 33),
+    _ => return Err(Error::InvalidKeyDataMulticodec),
+  };
+
+  if public.len() != length {
+    return Err(Error::InvalidKeyData);
+  }
+
+  VerificationMethod::builder(Object::new())
+    .id(did.join(format!("#{}", fragment))?)
+    .controller(did.clone())
+    .key_type(key_type)
+    .key_data(MethodData::new_multicodec(key_type, public))
+    .build()
+}
+
+pub(crate) fn signature_relationships(builder: DocumentBuilder, method: &DID) -> DocumentBuilder {
+  builder
+    .authentication(method.clone())
+    .assertion_method(method.clone())
+    .capability_delegation(method.clone())
+    .capability_invocation(method.clone())
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::crypto::KeyPair;
+  use identity_core::crypto::KeyType;
+
+  use crate::did::DID;
+  use crate::document::CoreDocument;
+  use crate::methods::KeyMethod;
+  use crate::resolution::resolve;
+  use crate::resolution::ErrorKind;
+  use crate::resolution::InputMetadata;
+  use crate::resolution::Resolution;
+  use crate::verifiable::Properties;
+
+  const ED25519: &str = "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK";
+  const X25519: &str = "z6LSj72tK8brWgZja8NLRwPigth2T9QRiG1uH9oKZuKjdh9p";
+
+  #[test]
+  fn test_expand_ed25519() {
+    let did: DID = ED25519.parse().unwrap();
+    let document: CoreDocument = KeyMethod::expand(&did.join("#fragment").unwrap()).unwrap();
+    let key: DID = did.join("#z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK").unwrap();
+    let agreement: DID = did.join(format!("#{}", X25519)).unwrap();
+
+    assert_eq!(document.id(), &did);
+    assert_eq!(document.verification_method().len(), 2);
+    assert_eq!(document.authentication().head().unwrap().id(), &key);
+    assert_eq!(document.assertion_method().head().unwrap().id(), &key);
+    assert_eq!(document.capability_delegation().head().unwrap().id(), &key);
+    assert_eq!(document.capability_invocation().head().unwrap().id(), &key);
+    assert_eq!(document.key_agreement().head().unwrap().id(), &agreement);
+    assert!(document.resolve(&*agreement.to_string()).is_some());
+  }
+
+  #[test]
+  fn test_from_keypair() {
+    for key_type in [KeyType::Ed25519, KeyType::Secp256k1, KeyType::P256, KeyType::X25519] {
+      let keypair: KeyPair = KeyPair::new(key_type).unwrap();
+      let did: DID = KeyMethod::from_keypair(&keypair).unwrap();
+      let document: CoreDocument = KeyMethod::expand(&did).unwrap();
+
+      assert_eq!(document.id(), &did);
+      assert_eq!(document.authentication().is_empty(), key_type == KeyType::X25519);
+      assert_eq!(
+        document.key_agreement().is_empty(),
+        !matches!(key_type, KeyType::Ed25519 | KeyType::X25519)
+      );
+    }
+  }
+
+  #[test]
+  fn test_sign_verify() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let did: DID = KeyMethod::from_keypair(&keypair).unwrap();
+    let mut document: CoreDocument<Properties> = KeyMethod::expand(&did).unwrap().map(Properties::new);
+    let method: String = document.authentication().head().unwrap().id().to_string();
+
+    document.sign_this(&*method, keypair.secret()).unwrap();
+
+    assert!(document.verify_this().is_ok());
+  }
+
+  #[test]
+  fn test_expand_invalid() {
+    let invalid = |did: &str| KeyMethod::expand(&did.parse().unwrap()).is_err();
+
+    assert!(invalid("did:example:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK"));
+    assert!(invalid("did:key:6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK"));
+    assert!(invalid("did:key:zQ3s"));
+    assert!(invalid("did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2d"));
+  }
+
+  #[test]
+  fn test_resolve() {
+    let resolution: Resolution =
+      futures::executor::block_on(resolve(ED25519, InputMetadata::new(), KeyMethod::new())).unwrap();
+
+    assert!(resolution.metadata.error.is_none());
+    assert_eq!(resolution.document.unwrap().id().as_str(), ED25519);
+  }
+
+  #[test]
+  fn test_resolve_invalid() {
+    for did in [
+      "did:key:6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK",
+      "did:key:zQ3s",
+      "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2d",
+    ] {
+      let resolution: Resolution =
+        futures::executor::block_on(resolve(did, InputMetadata::new(), KeyMethod::new())).unwrap();
+
+      assert_eq!(resolution.metadata.error, Some(ErrorKind::InvalidDID));
+      assert!(resolution.document.is_none());
+    }
+  }
+}
@@ -0,0 +1,16 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Resolvers for DID methods that don't require a distributed ledger.
+
+mod jwk;
+mod key;
+mod peer;
+mod web;
+
+pub use self::jwk::JwkMethod;
+pub use self::key::KeyMethod;
+pub use self::peer::PeerBuilder;
+pub use self::peer::PeerMethod;
+pub use self::web::WebFetcher;
+pub use self::web::WebMethod;
@@ -0,0 +1,438 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use identity_core::crypto::KeyPair;
+use identity_core::crypto::KeyType;
+use identity_core::utils::decode_b64;
+use identity_core::utils::encode_b64;
+use identity_core::utils::Multicodec;
+
+use crate::did::DID;
+use crate::document::CoreDocument;
+use crate::document::DocumentBuilder;
+use crate::error::Error;
+use crate::error::Result;
+use crate::methods::key::decode_key;
+use crate::methods::key::encode_key;
+use crate::methods::key::expand_key;
+use crate::methods::key::key_method;
+use crate::resolution::DocumentMetadata;
+use crate::resolution::InputMetadata;
+use crate::resolution::MetaDocument;
+use crate::resolution::ResolverMethod;
+use crate::service::Service;
+use crate::verification::VerificationMethod;
+
+// Abbreviations of service properties, as defined for numalgo 2.
+const ABBREVIATIONS: &[(&str, &str)] = &[
+  ("type", "t"),
+  ("serviceEndpoint", "s"),
+  ("routingKeys", "r"),
+  ("accept", "a"),
+];
+
+// The abbreviated `DIDCommMessaging` service type.
+const DIDCOMM_MESSAGING: (&str, &str) = ("DIDCommMessaging", "dm");
+
+/// A resolver for the [`did:peer`][SPEC] method.
+///
+/// Peer DIDs are used for pairwise relationships and are never published.
+/// Only the statically resolvable numeric algorithms `0` (a single inception
+/// key) and `2` (multiple keys and services) are supported.
+///
+/// [SPEC]: https://identity.foundation/peer-did-method-spec/
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PeerMethod;
+
+impl PeerMethod {
+  /// The name of the `did:peer` method.
+  pub const NAME: &'static str = "peer";
+
+  /// Creates a new `PeerMethod` resolver.
+  pub const fn new() -> Self {
+    Self
+  }
+
+  /// Creates a numalgo 0 `did:peer` DID from the public key of the given `keypair`.
+  pub fn from_keypair(keypair: &KeyPair) -> Result<DID> {
+    Self::from_public_key(keypair.type_(), keypair.public())
+  }
+
+  /// Creates a numalgo 0 `did:peer` DID from a `public` key of the given `key_type`.
+  pub fn from_public_key<T>(key_type: KeyType, public: &T) -> Result<DID>
+  where
+    T: AsRef<[u8]> + ?Sized,
+  {
+    let key: String = encode_key(key_type.into(), public.as_ref());
+
+    format!("did:{}:0{}", Self::NAME, key).parse().map_err(Into::into)
+  }
+
+  /// Creates a [`PeerBuilder`] to configure a numalgo 2 `did:peer` DID.
+  pub fn builder() -> PeerBuilder {
+    PeerBuilder::new()
+  }
+
+  /// Expands a `did:peer` DID into a DID Document.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `did` is not a valid numalgo 0 or numalgo 2 `did:peer` DID.
+  pub fn expand(did: &DID) -> Result<CoreDocument> {
+    if did.method() != Self::NAME {
+      return Err(Error::InvalidMethodName);
+    }
+
+    // Remove any path, query, or fragment from the input DID URL.
+    let did: DID = format!("did:{}:{}", Self::NAME, did.method_id()).parse()?;
+    let method_id: String = did.method_id().to_string();
+
+    if let Some(key) = method_id.strip_prefix('0') {
+      expand_key(did, key)
+    } else if let Some(elements) = method_id.strip_prefix("2.") {
+      expand_elements(did, elements)
+    } else {
+      Err(Error::InvalidMethodId)
+    }
+  }
+}
+
+#[async_trait(?Send)]
+impl ResolverMethod for PeerMethod {
+  fn is_supported(&self, did: &DID) -> bool {
+    did.method() == Self::NAME && (did.method_id().starts_with('0') || did.method_id().starts_with('2'))
+  }
+
+  async fn read(&self, did: &DID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
+    Ok(Some(MetaDocument::new(Self::expand(did)?, DocumentMetadata::new())))
+  }
+}
+
+// =============================================================================
+// Peer Builder
+// =============================================================================
+
+/// A `PeerBuilder` is used to generate a numalgo 2 `did:peer` DID.
+///
+/// Keys and services are encoded in the DID in the order they are added.
+#[derive(Clone, Debug, Default)]
+pub struct PeerBuilder {
+  pub(crate) keys: Vec<(char, String)>,
+  pub(crate) services: Vec<Object>,
+}
+
+impl PeerBuilder {
+  /// Creates a new `PeerBuilder`.
+  pub fn new() -> Self {
+    Self {
+      keys: Vec::new(),
+      services: Vec::new(),
+    }
+  }
+
+  /// Adds a key to the `authentication` relationship.
+  #[must_use]
+  pub fn authentication(self, key_type: KeyType, public: impl AsRef<[u8]>) -> Self {
+    self.key('V', key_type, public.as_ref())
+  }
+
+  /// Adds a key to the `assertionMethod` relationship.
+  #[must_use]
+  pub fn assertion_method(self, key_type: KeyType, public: impl AsRef<[u8]>) -> Self {
+    self.key('A', key_type, public.as_ref())
+  }
+
+  /// Adds a key to the `keyAgreement` relationship.
+  #[must_use]
+  pub fn key_agreement(self, key_type: KeyType, public: impl AsRef<[u8]>) -> Self {
+    self.key('E', key_type, public.as_ref())
+  }
+
+  /// Adds a key to the `capabilityDelegation` relationship.
+  #[must_use]
+  pub fn capability_delegation(self, key_type: KeyType, public: impl AsRef<[u8]>) -> Self {
+    self.key('D', key_type, public.as_ref())
+  }
+
+  /// Adds a key to the `capabilityInvocation` relationship.
+  #[must_use]
+  pub fn capability_invocation(self, key_type: KeyType, public: impl AsRef<[u8]>) -> Self {
+    self.key('I', key_type, public.as_ref())
+  }
+
+  /// Adds a service with the given `type_`, `endpoint`, and additional
+  /// `properties` (e.g. `routingKeys` and `accept`).
+  ///
+  /// Service ids are assigned when the DID is expanded.
+  #[must_use]
+  pub fn service(mut self, type_: impl Into<String>, endpoint: Url, mut properties: Object) -> Self {
+    properties.insert("type".into(), Value::String(type_.into()));
+    properties.insert("serviceEndpoint".into(), endpoint.into_string().into());
+    properties.remove("id");
+
+    self.services.push(abbreviate(properties));
+    self
+  }
+
+  /// Returns a new numalgo 2 `did:peer` DID based on the `PeerBuilder` configuration.
+  pub fn build(self) -> Result<DID> {
+    if self.keys.is_empty() {
+      return Err(Error::InvalidMethodId);
+    }
+
+    let mut output: String = format!("did:{}:2", PeerMethod::NAME);
+
+    for (purpose, key) in self.keys {
+      output.push('.');
+      output.push(purpose);
+      output.push_str(&key);
+    }
+
+    for service in self.services {
+      let service: String = encode_b64(&service.to_json_vec()?);
+
+      output.push_str(".S");
+      output.push_str(service.trim_end_matches('='));
+    }
+
+    output.parse().map_err(Into::into)
+  }
+
+  fn key(mut self, purpose: char, key_type: KeyType, public: &[u8]) -> Self {
+    self.keys.push((purpose, encode_key(key_type.into(), public)));
+    self
+  }
+}
+
+// =============================================================================
+// Numalgo 2
+// =============================================================================
+
+fn expand_elements(did: DID, elements: &str) -> Result<CoreDocument> {
+  let mut builder: DocumentBuilder = CoreDocument::builder(Object::new()).id(did.clone());
+  let mut keys: usize = 0;
+  let mut services: usize = 0;
+
+  for element in elements.split('.') {
+    let mut chars = element.chars();
+    let purpose: char = chars.next().ok_or(Error::InvalidMethodId)?;
+    let value: &str = chars.as_str();
+
+    if purpose == 'S' {
+      let fragment: String = match services {
+        0 => "service".to_string(),
+        index => format!("service-{}", index),
+      };
+
+      builder = builder.service(expand_service(&did, &fragment, value)?);
+      services += 1;
+
+      continue;
+    }
+
+    let (codec, public): (Multicodec, Vec<u8>) = decode_key(value)?;
+
+    keys += 1;
+
+    let method: VerificationMethod = key_method(&did, &format!("key-{}", keys), codec, &public)?;
+    let id: DID = method.id().clone();
+
+    builder = builder.verification_method(method);
+    builder = match purpose {
+      'V' => builder.authentication(id),
+      'A' => builder.assertion_method(id),
+      'E' => builder.key_agreement(id),
+      'D' => builder.capability_delegation(id),
+      'I' => builder.capability_invocation(id),
+      _ => return Err(Error::InvalidMethodId),
+    };
+  }
+
+  builder.build()
+}
+
+fn expand_service(did: &DID, fragment: &str, data: &str) -> Result<Service> {
+  let data: Vec<u8> = decode_b64(data).map_err(|_| Error::InvalidMethodId)?;
+  let mut properties: Object = expand(Object::from_json_slice(&data).map_err(|_| Error::InvalidMethodId)?);
+
+  let type_: String = match properties.remove("type") {
+    Some(Value::String(type_)) => type_,
+    _ => return Err(Error::BuilderInvalidServiceType),
+  };
+
+  let endpoint: Url = match properties.remove("serviceEndpoint") {
+    Some(Value::String(endpoint)) => Url::parse(endpoint).map_err(|_| Error::BuilderInvalidServiceEndpoint)?,
+    _ => return Err(Error::BuilderInvalidServiceEndpoint),
+  };
+
+  Service::builder(properties)
+    .id(did.join(format!("#{}", fragment))?)
+    .type_(type_)
+    .service_endpoint(endpoint)
+    .build()
+}
+
+fn abbreviate(properties: Object) -> Object {
+  properties
+    .into_iter()
+    .map(|(key, value)| {
+      let key: String = ABBREVIATIONS
+        .iter()
+        .find(|(name, _)| *name == key)
+        .map(|(_, short)| short.to_string())
+        .unwrap_or(key);
+
+      match value {
+        Value::String(type_) if key == "t" && type_ == DIDCOMM_MESSAGING.0 => (key, DIDCOMM_MESSAGING.1.into()),
+        value => (key, value),
+      }
+    })
+    .collect()
+}
+
+fn expand(properties: Object) -> Object {
+  properties
+    .into_iter()
+    .map(|(key, value)| {
+      let key: String = ABBREVIATIONS
+        .iter()
+        .find(|(_, short)| *short == key)
+        .map(|(name, _)| name.to_string())
+        .unwrap_or(key);
+
+      match value {
+        Value::String(type_) if key == "type" && type_ == DIDCOMM_MESSAGING.1 => (key, DIDCOMM_MESSAGING.0.into()),
+        value => (key, value),
+      }
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Object;
+  use identity_core::common::Url;
+  use identity_core::crypto::KeyPair;
+  use identity_core::crypto::KeyType;
+  use identity_core::json;
+
+  use crate::did::DID;
+  use crate::document::CoreDocument;
+  use crate::methods::PeerMethod;
+  use crate::resolution::resolve;
+  use crate::resolution::ErrorKind;
+  use crate::resolution::InputMetadata;
+  use crate::resolution::Resolution;
+  use crate::service::Service;
+
+  const NUMALGO_2: &str = "did:peer:2\
+    .Ez6LSbysY2xFMRpGMhb7tFTLMpeuPRaqaWM1yECx2AtzE3KCc\
+    .Vz6MkqRYqQiSgvZQdnBytw86Qbs2ZWUkGv22od935YF4s8M7V\
+    .Vz6MkgoLTnTypo3tDRwCkZXSccTPHRLhF4ZnjhueYAFpEX6vg\
+    .SeyJ0IjoiZG0iLCJzIjoiaHR0cHM6Ly9leGFtcGxlLmNvbS9lbmRwb2ludCIsInIiOlsiZGlkOmV4YW1wbGU6c29tZW1lZGlhdG9yI3NvbWVrZXkiXSwiYSI6WyJkaWRjb21tL3YyIiwiZGlkY29tbS9haXAyO2Vudj1yZmM1ODciXX0";
+
+  #[test]
+  fn test_numalgo_0() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let did: DID = PeerMethod::from_keypair(&keypair).unwrap();
+    let document: CoreDocument = PeerMethod::expand(&did).unwrap();
+
+    assert!(did.method_id().starts_with("0z6Mk"));
+    assert_eq!(document.id(), &did);
+    assert_eq!(document.verification_method().len(), 2);
+    assert_eq!(document.authentication().len(), 1);
+    assert_eq!(document.key_agreement().len(), 1);
+  }
+
+  #[test]
+  fn test_numalgo_2_expand() {
+    let did: DID = NUMALGO_2.parse().unwrap();
+    let document: CoreDocument = PeerMethod::expand(&did).unwrap();
+
+    assert_eq!(document.id(), &did);
+    assert_eq!(document.verification_method().len(), 3);
+    assert_eq!(
+      document.key_agreement().head().unwrap().id(),
+      &did.join("#key-1").unwrap()
+    );
+    assert_eq!(document.authentication().len(), 2);
+    assert_eq!(
+      document.authentication().tail().unwrap().id(),
+      &did.join("#key-3").unwrap()
+    );
+
+    let service: &Service = document.service().head().unwrap();
+
+    assert_eq!(service.id(), &did.join("#service").unwrap());
+    assert_eq!(service.type_(), "DIDCommMessaging");
+    assert_eq!(service.service_endpoint().as_str(), "https://example.com/endpoint");
+    assert_eq!(
+      service.properties()["routingKeys"],
+      json!(["did:example:somemediator#somekey"])
+    );
+    assert_eq!(
+      service.properties()["accept"],
+      json!(["didcomm/v2", "didcomm/aip2;env=rfc587"])
+    );
+  }
+
+  #[test]
+  fn test_numalgo_2_roundtrip() {
+    let agreement: KeyPair = KeyPair::new(KeyType::X25519).unwrap();
+    let authentication: KeyPair = KeyPair::new_ed25519().unwrap();
+
+    let mut properties: Object = Object::new();
+    properties.insert("routingKeys".into(), json!(["did:example:mediator#key-1"]));
+
+    let did: DID = PeerMethod::builder()
+      .key_agreement(KeyType::X25519, agreement.public())
+      .authentication(KeyType::Ed25519, authentication.public())
+      .service(
+        "DIDCommMessaging",
+        Url::parse("https://example.com/didcomm").unwrap(),
+        properties.clone(),
+      )
+      .service(
+        "LinkedDomains",
+        Url::parse("https://example.com").unwrap(),
+        Object::new(),
+      )
+      .build()
+      .unwrap();
+
+    let document: CoreDocument = PeerMethod::expand(&did).unwrap();
+    let services: Vec<&Service> = document.service().iter().map(|service| &**service).collect();
+
+    assert_eq!(document.verification_method().len(), 2);
+    assert_eq!(services.len(), 2);
+    assert_eq!(services[0].type_(), "DIDCommMessaging");
+    assert_eq!(services[0].properties(), &properties);
+    assert_eq!(services[1].id(), &did.join("#service-1").unwrap());
+    assert_eq!(services[1].type_(), "LinkedDomains");
+
+    assert!(PeerMethod::builder().build().is_err());
+  }
+
+  #[test]
+  fn test_resolve() {
+    let resolve_peer = |did: &str| -> Resolution {
+      futures::executor::block_on(resolve(did, InputMetadata::new(), PeerMethod::new())).unwrap()
+    };
+
+    let resolution: Resolution = resolve_peer(NUMALGO_2);
+    assert!(resolution.metadata.error.is_none());
+    assert_eq!(resolution.document.unwrap().id().as_str(), NUMALGO_2);
+
+    let resolution: Resolution = resolve_peer("did:peer:2.Xz6MkqRYqQiSgvZQdnBytw86Qbs2ZWUkGv22od935YF4s8M7V");
+    assert_eq!(resolution.metadata.error, Some(ErrorKind::InvalidDID));
+
+    let resolution: Resolution = resolve_peer("did:peer:1zQmZMygzYqNwU6Uhmewx5Xepf2VLp5S4HLSwwgf2aiKZuwa");
+    assert_eq!(resolution.metadata.error, Some(ErrorKind::NotSupported));
+  }
+}
@@ -0,0 +1,331 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_core::common::Url;
+use identity_core::convert::FromJson;
+
+use crate::did::DID;
+use crate::document::CoreDocument;
+use crate::error::Error;
+use crate::error::Result;
+use crate::resolution::DocumentMetadata;
+use crate::resolution::InputMetadata;
+use crate::resolution::MetaDocument;
+use crate::resolution::ResolverMethod;
+
+/// A trait for HTTP clients used to fetch `did:web` DID Documents.
+#[async_trait(?Send)]
+pub trait WebFetcher {
+  /// Fetches the content at the given `url`.
+  ///
+  /// Returns `None` if there is no content at the `url` (e.g. `404 Not Found`).
+  /// Other failures should be reported as [`Error::FetchFailed`].
+  async fn fetch(&self, url: &Url) -> Result<Option<Vec<u8>>>;
+}
+
+#[async_trait(?Send)]
+impl<T> WebFetcher for &'_ T
+where
+  T: WebFetcher + ?Sized,
+{
+  async fn fetch(&self, url: &Url) -> Result<Option<Vec<u8>>> {
+    (**self).fetch(url).await
+  }
+}
+
+#[cfg(feature = "web-fetcher")]
+#[async_trait(?Send)]
+impl WebFetcher for reqwest::Client {
+  async fn fetch(&self, url: &Url) -> Result<Option<Vec<u8>>> {
+    let response: reqwest::Response = self
+      .get(url.as_str())
+      .send()
+      .await
+      .map_err(|error| Error::FetchFailed(error.to_string()))?;
+
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+      return Ok(None);
+    }
+
+    response
+      .error_for_status()
+      .map_err(|error| Error::FetchFailed(error.to_string()))?
+      .bytes()
+      .await
+      .map(|data| Some(data.to_vec()))
+      .map_err(|error| Error::FetchFailed(error.to_string()))
+  }
+}
+
+/// A resolver for the [`did:web`][SPEC] method.
+///
+/// DID Documents are fetched over HTTPS with the given [`WebFetcher`]. With
+/// the `web-fetcher` feature enabled, a `reqwest::Client` can be used as the
+/// fetcher.
+///
+/// [SPEC]: https://w3c-ccg.github.io/did-method-web/
+#[derive(Clone, Debug)]
+pub struct WebMethod<F> {
+  fetcher: F,
+}
+
+impl<F> WebMethod<F> {
+  /// The name of the `did:web` method.
+  pub const NAME: &'static str = "web";
+
+  /// Creates a new `WebMethod` resolver using the given `fetcher`.
+  pub const fn new(fetcher: F) -> Self {
+    Self { fetcher }
+  }
+
+  /// Returns a reference to the HTTP client of the resolver.
+  pub fn fetcher(&self) -> &F {
+    &self.fetcher
+  }
+
+  /// Returns the HTTPS URL of the DID Document identified by a `did:web` DID.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `did` is not a valid `did:web` DID.
+  pub fn url(did: &DID) -> Result<Url> {
+    if did.method() != Self::NAME {
+      return Err(Error::InvalidMethodName);
+    }
+
+    let mut segments = did.method_id().split(':');
+
+    // The domain name may contain a percent-encoded port.
+    let domain: String = segments
+      .next()
+      .map(|domain| domain.replace("%3A", ":").replace("%3a", ":"))
+      .ok_or(Error::InvalidMethodId)?;
+
+    let path: Vec<&str> = segments.collect();
+
+    if !is_valid_segment(&domain) || !path.iter().all(|segment| is_valid_segment(segment)) {
+      return Err(Error::InvalidMethodId);
+    }
+
+    // Documents without a path are located in the `.well-known` directory.
+    let path: String = if path.is_empty() {
+      ".well-known".to_string()
+    } else {
+      path.join("/")
+    };
+
+    let url: Url = Url::parse(format!("https://{}/{}/did.json", domain, path)).map_err(|_| Error::InvalidMethodId)?;
+
+    if url.host_str().is_none() || !url.username().is_empty() {
+      return Err(Error::InvalidMethodId);
+    }
+
+    Ok(url)
+  }
+}
+
+#[async_trait(?Send)]
+impl<F> ResolverMethod for WebMethod<F>
+where
+  F: WebFetcher,
+{
+  fn is_supported(&self, did: &DID) -> bool {
+    did.method() == Self::NAME
+  }
+
+  /// Fetches the DID Document of a `did:web` DID.
+  ///
+  /// Content that is not a valid DID Document, or a DID Document with an `id`
+  /// other than the requested DID is treated as not found.
+  async fn read(&self, did: &DID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
+    let url: Url = Self::url(did)?;
+
+    let data: Vec<u8> = match self.fetcher.fetch(&url).await? {
+      Some(data) => data,
+      None => return Ok(None),
+    };
+
+    let document: CoreDocument = match CoreDocument::from_json_slice(&data) {
+      Ok(document) => document,
+      Err(_) => return Ok(None),
+    };
+
+    if document.id().method() != did.method() || document.id().method_id() != did.method_id() {
+      return Ok(None);
+    }
+
+    Ok(Some(MetaDocument::new(document, DocumentMetadata::new())))
+  }
+}
+
+fn is_valid_segment(segment: &str) -> bool {
+  !segment.is_empty()
+    && segment != "."
+    && segment != ".."
+    && !segment.contains(|ch: char| matches!(ch, '/' | '\\' | '?' | '#' | '@'))
+}
+
+#[cfg(test)]
+mod tests {
+  use async_trait::async_trait;
+  use identity_core::common::Url;
+  use identity_core::convert::ToJson;
+  use std::collections::BTreeMap;
+
+  use crate::did::DID;
+  use crate::document::CoreDocument;
+  use crate::error::Result;
+  use crate::methods::WebFetcher;
+  use crate::methods::WebMethod;
+  use crate::resolution::resolve;
+  use crate::resolution::ErrorKind;
+  use crate::resolution::InputMetadata;
+  use crate::resolution::Resolution;
+
+  #[derive(Debug, Default)]
+  struct MockFetcher(BTreeMap<String, Vec<u8>>);
+
+  impl MockFetcher {
+    fn insert(&mut self, url: &str, document: &CoreDocument) {
+      self.0.insert(url.to_string(), document.to_json_vec().unwrap());
+    }
+  }
+
+  #[async_trait(?Send)]
+  impl WebFetcher for MockFetcher {
+    async fn fetch(&self, url: &Url) -> Result<Option<Vec<u8>>> {
+      Ok(self.0.get(url.as_str()).cloned())
+    }
+  }
+
+  fn document(did: &str) -> CoreDocument {
+    CoreDocument::builder(Default::default())
+      .id(did.parse().unwrap())
+      .build()
+      .unwrap()
+  }
+
+  fn url(did: &str) -> Option<String> {
+    let did: DID = did.parse().unwrap();
+
+    WebMethod::<MockFetcher>::url(&did).ok().map(Url::into_string)
+  }
+
+  fn resolve_web(did: &str, fetcher: &MockFetcher) -> Resolution {
+    futures::executor::block_on(resolve(did, InputMetadata::new(), WebMethod::new(fetcher))).unwrap()
+  }
+
+  #[test]
+  fn test_url() {
+    assert_eq!(
+      url("did:web:w3c-ccg.github.io").unwrap(),
+      "https://w3c-ccg.github.io/.well-known/did.json"
+    );
+    assert_eq!(
+      url("did:web:w3c-ccg.github.io:user:alice").unwrap(),
+      "https://w3c-ccg.github.io/user/alice/did.json"
+    );
+    assert_eq!(
+      url("did:web:example.com%3A3000:user:alice").unwrap(),
+      "https://example.com:3000/user/alice/did.json"
+    );
+    assert_eq!(
+      url("did:web:example.com:user:alice#key-1").unwrap(),
+      "https://example.com/user/alice/did.json"
+    );
+
+    assert!(url("did:example:example.com").is_none());
+    assert!(url("did:web:example.com:..:alice").is_none());
+  }
+
+  #[test]
+  fn test_resolve() {
+    let mut fetcher: MockFetcher = MockFetcher::default();
+
+    fetcher.insert(
+      "https://example.com/.well-known/did.json",
+      &document("did:web:example.com"),
+    );
+    fetcher.insert(
+      "https://example.com:3000/user/alice/did.json",
+      &document("did:web:example.com%3A3000:user:alice"),
+    );
+    fetcher.insert(
+      "https://example.com/user/bob/did.json",
+      &document("did:web:example.com:user:alice"),
+    );
+
+    for did in ["did:web:example.com", "did:web:example.com%3A3000:user:alice"] {
+      let resolution: Resolution = resolve_web(did, &fetcher);
+
+      assert!(resolution.metadata.error.is_none());
+      assert_eq!(resolution.document.unwrap().id().as_str(), did);
+    }
+
+    // The document doesn't exist
+    let resolution: Resolution = resolve_web("did:web:example.com:user:carol", &fetcher);
+    assert_eq!(resolution.metadata.error, Some(ErrorKind::NotFound));
+
+    // The content is not a DID Document
+    fetcher.0.insert(
+      "https://example.com/user/dave/did.json".to_string(),
+      b"<html></html>".to_vec(),
+    );
+
+    let resolution: Resolution = resolve_web("did:web:example.com:user:dave", &fetcher);
+    assert_eq!(resolution.metadata.error, Some(ErrorKind::NotFound));
+    assert!(resolution.document.is_none());
+
+    // The document belongs to a different DID
+    let resolution: Resolution = resolve_web("did:web:example.com:user:bob", &fetcher);
+    assert_eq!(resolution.metadata.error, Some(ErrorKind::NotFound));
+    assert!(resolution.document.is_none());
+
+    // The DID can't be mapped to a URL
+    let resolution: Resolution = resolve_web("did:web:example.com:..:alice", &fetcher);
+    assert_eq!(resolution.metadata.error, Some(ErrorKind::InvalidDID));
+  }
+  #[cfg(feature = "web-fetcher")]
+  #[tokio::test]
+  async fn test_reqwest_fetcher() {
+    use std::io::Read;
+    use std::io::Write;
+    use std::net::TcpListener;
+    use std::net::TcpStream;
+
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address: String = format!("http://{}", listener.local_addr().unwrap());
+
+    // Serves a single document, fails requests below `/error` and responds to
+    // every other request with `404 Not Found`
+    std::thread::spawn(move || {
+      for stream in listener.incoming().take(3) {
+        let mut stream: TcpStream = stream.unwrap();
+        let mut request: [u8; 1024] = [0; 1024];
+        let size: usize = stream.read(&mut request).unwrap();
+
+        let response: &str = if request[..size].starts_with(b"GET /.well-known/did.json ") {
+          "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}"
+        } else if request[..size].starts_with(b"GET /error/did.json ") {
+          "HTTP/1.1 500 Internal Server Error\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+        } else {
+          "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+        };
+
+        stream.write_all(response.as_bytes()).unwrap();
+      }
+    });
+
+    let client: reqwest::Client = reqwest::Client::new();
+    let url = |path: &str| Url::parse(format!("{}/{}", address, path)).unwrap();
+
+    let data: Option<Vec<u8>> = client.fetch(&url(".well-known/did.json")).await.unwrap();
+    assert_eq!(data.as_deref(), Some(&b"{}"[..]));
+
+    let data: Option<Vec<u8>> = client.fetch(&url("user/alice/did.json")).await.unwrap();
+    assert!(data.is_none());
+
+    assert!(client.fetch(&url("error/did.json")).await.is_err());
+  }
+}
@@ -0,0 +1,227 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::time::Duration;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+use std::time::Instant;
+
+/// A size-limited cache of resolved values keyed by DID, where each entry
+/// expires after a fixed time-to-live.
+///
+/// Once the cache is full, expired entries are evicted first and then the
+/// least recently inserted entry.
+pub struct ResolutionCache<T> {
+  ttl: Duration,
+  capacity: usize,
+  state: Mutex<State<T>>,
+}
+
+struct State<T> {
+  entries: HashMap<String, Entry<T>>,
+  inserts: u64,
+}
+
+struct Entry<T> {
+  value: T,
+  inserted: Instant,
+  index: u64,
+}
+
+impl<T> ResolutionCache<T> {
+  /// The default time-to-live of cached entries.
+  pub const DEFAULT_TTL: Duration = Duration::from_secs(60);
+
+  /// The default maximum number of cached entries.
+  pub const DEFAULT_CAPACITY: usize = 256;
+
+  /// Creates a new `ResolutionCache` with the default TTL and capacity.
+  pub fn new() -> Self {
+    Self::with_config(Self::DEFAULT_TTL, Self::DEFAULT_CAPACITY)
+  }
+
+  /// Creates a new `ResolutionCache` with the given `ttl` and `capacity`.
+  ///
+  /// A `capacity` of zero disables caching.
+  pub fn with_config(ttl: Duration, capacity: usize) -> Self {
+    Self {
+      ttl,
+      capacity,
+      state: Mutex::new(State {
+        entries: HashMap::new(),
+        inserts: 0,
+      }),
+    }
+  }
+
+  /// Returns the time-to-live of cached entries.
+  pub fn ttl(&self) -> Duration {
+    self.ttl
+  }
+
+  /// Returns the maximum number of cached entries.
+  pub fn capacity(&self) -> usize {
+    self.capacity
+  }
+
+  /// Returns the number of cached entries, including expired entries that
+  /// have not been evicted yet.
+  pub fn len(&self) -> usize {
+    self.state().entries.len()
+  }
+
+  /// Returns `true` if the cache has no entries.
+  pub fn is_empty(&self) -> bool {
+    self.state().entries.is_empty()
+  }
+
+  /// Returns a clone of the unexpired entry for `did`, if any.
+  pub fn get(&self, did: &str) -> Option<T>
+  where
+    T: Clone,
+  {
+    let mut state: MutexGuard<'_, State<T>> = self.state();
+
+    match state.entries.get(did) {
+      Some(entry) if entry.inserted.elapsed() < self.ttl => Some(entry.value.clone()),
+      Some(_) => {
+        state.entries.remove(did);
+        None
+      }
+      None => None,
+    }
+  }
+
+  /// Inserts the `value` resolved for `did`, replacing any existing entry.
+  pub fn insert(&self, did: impl Into<String>, value: T) {
+    if self.capacity == 0 {
+      return;
+    }
+
+    let did: String = did.into();
+    let mut state: MutexGuard<'_, State<T>> = self.state();
+
+    if !state.entries.contains_key(&did) && state.entries.len() >= self.capacity {
+      let ttl: Duration = self.ttl;
+
+      state.entries.retain(|_, entry| entry.inserted.elapsed() < ttl);
+
+      if state.entries.len() >= self.capacity {
+        let oldest: Option<String> = state
+          .entries
+          .iter()
+          .min_by_key(|(_, entry)| entry.index)
+          .map(|(did, _)| did.clone());
+
+        if let Some(oldest) = oldest {
+          state.entries.remove(&oldest);
+        }
+      }
+    }
+
+    let entry: Entry<T> = Entry {
+      value,
+      inserted: Instant::now(),
+      index: state.inserts,
+    };
+
+    state.inserts += 1;
+    state.entries.insert(did, entry);
+  }
+
+  /// Removes the entry for `did`, returning `true` if it was cached.
+  pub fn invalidate(&self, did: &str) -> bool {
+    self.state().entries.remove(did).is_some()
+  }
+
+  /// Removes all entries from the cache.
+  pub fn clear(&self) {
+    self.state().entries.clear();
+  }
+
+  fn state(&self) -> MutexGuard<'_, State<T>> {
+    // The cache is never left in an inconsistent state - ignore poisoning.
+    self.state.lock().unwrap_or_else(PoisonError::into_inner)
+  }
+}
+
+impl<T> Default for ResolutionCache<T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T> Debug for ResolutionCache<T> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.debug_struct("ResolutionCache")
+      .field("ttl", &self.ttl)
+      .field("capacity", &self.capacity)
+      .field("len", &self.len())
+      .finish()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use core::time::Duration;
+
+  use crate::resolution::ResolutionCache;
+
+  #[test]
+  fn test_get_insert() {
+    let cache: ResolutionCache<u32> = ResolutionCache::new();
+
+    assert!(cache.get("did:example:1").is_none());
+
+    cache.insert("did:example:1", 1);
+    cache.insert("did:example:2", 2);
+    cache.insert("did:example:1", 3);
+
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.get("did:example:1"), Some(3));
+    assert!(cache.invalidate("did:example:1"));
+    assert!(!cache.invalidate("did:example:1"));
+    assert!(cache.get("did:example:1").is_none());
+
+    cache.clear();
+
+    assert!(cache.is_empty());
+  }
+
+  #[test]
+  fn test_capacity() {
+    let cache: ResolutionCache<u32> = ResolutionCache::with_config(ResolutionCache::<u32>::DEFAULT_TTL, 2);
+
+    cache.insert("did:example:1", 1);
+    cache.insert("did:example:2", 2);
+    cache.insert("did:example:3", 3);
+
+    // The oldest entry is evicted
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get("did:example:1").is_none());
+    assert_eq!(cache.get("did:example:3"), Some(3));
+
+    let cache: ResolutionCache<u32> = ResolutionCache::with_config(ResolutionCache::<u32>::DEFAULT_TTL, 0);
+
+    cache.insert("did:example:1", 1);
+
+    assert!(cache.is_empty());
+  }
+
+  #[test]
+  fn test_ttl() {
+    let cache: ResolutionCache<u32> = ResolutionCache::with_config(Duration::from_secs(0), 8);
+
+    cache.insert("did:example:1", 1);
+
+    // Expired entries are evicted on access
+    assert_eq!(cache.len(), 1);
+    assert!(cache.get("did:example:1").is_none());
+    assert!(cache.is_empty());
+  }
+}
@@ -0,0 +1,14 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Indicates whether a resolved DID Document was served from a cache.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+pub enum CacheStatus {
+  /// The DID Document was served from the cache.
+  #[serde(rename = "hit")]
+  Hit,
+  /// The DID Document was read from the verifiable data registry, either
+  /// because it was not cached or because the cache was bypassed.
+  #[serde(rename = "miss")]
+  Miss,
+}
@@ -0,0 +1,220 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use core::time::Duration;
+
+use crate::did::DID;
+use crate::error::Result;
+use crate::resolution::CacheStatus;
+use crate::resolution::InputMetadata;
+use crate::resolution::MetaDocument;
+use crate::resolution::ResolutionCache;
+use crate::resolution::ResolverMethod;
+
+/// A [`ResolverMethod`] that caches the DID Documents read by another
+/// [`ResolverMethod`].
+///
+/// Cached DID Documents are bypassed if the `no-cache` input property is set,
+/// and whether a DID Document was cached is reported in the `cache` property
+/// of the resolution metadata. Requests for a specific version of a DID
+/// Document are never cached.
+#[derive(Debug)]
+pub struct CachedResolver<R> {
+  method: R,
+  cache: ResolutionCache<MetaDocument>,
+}
+
+impl<R> CachedResolver<R> {
+  /// Creates a new `CachedResolver` wrapping `method` with the default TTL
+  /// and capacity.
+  pub fn new(method: R) -> Self {
+    Self {
+      method,
+      cache: ResolutionCache::new(),
+    }
+  }
+
+  /// Creates a new `CachedResolver` wrapping `method` with the given `ttl`
+  /// and `capacity`.
+  pub fn with_config(method: R, ttl: Duration, capacity: usize) -> Self {
+    Self {
+      method,
+      cache: ResolutionCache::with_config(ttl, capacity),
+    }
+  }
+
+  /// Returns a reference to the wrapped [`ResolverMethod`].
+  pub fn method(&self) -> &R {
+    &self.method
+  }
+
+  /// Returns a reference to the DID Document cache.
+  pub fn cache(&self) -> &ResolutionCache<MetaDocument> {
+    &self.cache
+  }
+
+  /// Removes the cached DID Document of the DID URL `did`, returning `true`
+  /// if it was cached.
+  pub fn invalidate(&self, did: &DID) -> bool {
+    self.cache.invalidate(&cache_key(did))
+  }
+
+  /// Removes all cached DID Documents.
+  pub fn clear(&self) {
+    self.cache.clear();
+  }
+}
+
+#[async_trait(?Send)]
+impl<R> ResolverMethod for CachedResolver<R>
+where
+  R: ResolverMethod,
+{
+  fn is_supported(&self, did: &DID) -> bool {
+    self.method.is_supported(did)
+  }
+
+  async fn read(&self, did: &DID, input: InputMetadata) -> Result<Option<MetaDocument>> {
+    if input.version_id.is_some() || input.version_time.is_some() {
+      return self.method.read(did, input).await;
+    }
+
+    let key: String = cache_key(did);
+
+    if !input.no_cache {
+      if let Some(mut document) = self.cache.get(&key) {
+        document.cache = Some(CacheStatus::Hit);
+        return Ok(Some(document));
+      }
+    }
+
+    let mut document: MetaDocument = match self.method.read(did, input).await? {
+      Some(document) => document,
+      None => return Ok(None),
+    };
+
+    document.cache = Some(CacheStatus::Miss);
+
+    self.cache.insert(key, document.clone());
+
+    Ok(Some(document))
+  }
+}
+
+// DID URLs are cached without their fragment, which is only used to
+// dereference a secondary resource - the path and query can select a
+// different DID Document.
+fn cache_key(did: &DID) -> String {
+  let mut key: String = format!("did:{}:{}{}", did.method(), did.method_id(), did.path());
+
+  if let Some(query) = did.query() {
+    key.push('?');
+    key.push_str(query);
+  }
+
+  key
+}
+
+#[cfg(test)]
+mod tests {
+  use async_trait::async_trait;
+  use core::cell::Cell;
+  use identity_core::common::Object;
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+
+  use crate::did::DID;
+  use crate::document::CoreDocument;
+  use crate::error::Result;
+  use crate::resolution::resolve;
+  use crate::resolution::CacheStatus;
+  use crate::resolution::CachedResolver;
+  use crate::resolution::DocumentMetadata;
+  use crate::resolution::InputMetadata;
+  use crate::resolution::MetaDocument;
+  use crate::resolution::Resolution;
+  use crate::resolution::ResolverMethod;
+
+  #[derive(Debug, Default)]
+  struct CountingMethod(Cell<usize>);
+
+  #[async_trait(?Send)]
+  impl ResolverMethod for CountingMethod {
+    fn is_supported(&self, did: &DID) -> bool {
+      did.method() == "example"
+    }
+
+    async fn read(&self, did: &DID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
+      self.0.set(self.0.get() + 1);
+
+      Ok(Some(MetaDocument::new(
+        CoreDocument::builder(Object::new()).id(did.clone()).build()?,
+        DocumentMetadata::new(),
+      )))
+    }
+  }
+
+  fn resolve_cached(did: &str, input: InputMetadata, resolver: &CachedResolver<CountingMethod>) -> Resolution {
+    futures::executor::block_on(resolve(did, input, resolver)).unwrap()
+  }
+
+  #[test]
+  fn test_cache() {
+    let resolver: CachedResolver<CountingMethod> = CachedResolver::new(CountingMethod::default());
+    let mut no_cache: InputMetadata = InputMetadata::new();
+
+    no_cache.no_cache = true;
+
+    let resolution: Resolution = resolve_cached("did:example:123", InputMetadata::new(), &resolver);
+    assert_eq!(resolution.metadata.cache, Some(CacheStatus::Miss));
+    assert_eq!(resolver.method().0.get(), 1);
+
+    // DID URLs share the cache entry of the DID URL without the fragment
+    let resolution: Resolution = resolve_cached("did:example:123#key-1", InputMetadata::new(), &resolver);
+    assert_eq!(resolution.metadata.cache, Some(CacheStatus::Hit));
+    assert_eq!(resolution.document.unwrap().id().as_str(), "did:example:123");
+    assert_eq!(resolver.method().0.get(), 1);
+
+    // A query can select a different DID Document
+    let resolution: Resolution = resolve_cached("did:example:123?service=files", InputMetadata::new(), &resolver);
+    assert_eq!(resolution.metadata.cache, Some(CacheStatus::Miss));
+    assert_eq!(resolver.method().0.get(), 2);
+
+    let resolution: Resolution = resolve_cached("did:example:123?service=files#key-1", InputMetadata::new(), &resolver);
+    assert_eq!(resolution.metadata.cache, Some(CacheStatus::Hit));
+    assert_eq!(resolver.method().0.get(), 2);
+
+    let resolution: Resolution = resolve_cached("did:example:123", InputMetadata::new(), &resolver);
+    assert_eq!(resolution.metadata.cache, Some(CacheStatus::Hit));
+    assert_eq!(resolver.method().0.get(), 2);
+
+    // `no-cache` always reads a fresh DID Document
+    let resolution: Resolution = resolve_cached("did:example:123", no_cache, &resolver);
+    assert_eq!(resolution.metadata.cache, Some(CacheStatus::Miss));
+    assert_eq!(resolver.method().0.get(), 3);
+
+    assert!(resolver.invalidate(&"did:example:123".parse().unwrap()));
+
+    let resolution: Resolution = resolve_cached("did:example:123", InputMetadata::new(), &resolver);
+    assert_eq!(resolution.metadata.cache, Some(CacheStatus::Miss));
+    assert_eq!(resolver.method().0.get(), 4);
+
+    // Versioned DID Documents are never cached
+    let mut versioned: InputMetadata = InputMetadata::new();
+
+    versioned.version_id = Some("1".into());
+
+    let resolution: Resolution = resolve_cached("did:example:123", versioned, &resolver);
+    assert_eq!(resolution.metadata.cache, None);
+    assert_eq!(resolver.method().0.get(), 5);
+  }
+
+  #[test]
+  fn test_no_cache_input() {
+    let input: InputMetadata = InputMetadata::from_json(r#"{"no-cache":true}"#).unwrap();
+
+    assert!(input.no_cache);
+    assert_eq!(InputMetadata::new().to_json().unwrap(), "{}");
+  }
+}
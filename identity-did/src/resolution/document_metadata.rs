@@ -17,6 +17,26 @@ pub struct DocumentMetadata {
   /// [More Info](https://www.w3.org/TR/did-spec-registries/#updated)
   #[serde(skip_serializing_if = "Option::is_none")]
   pub updated: Option<Timestamp>,
+  /// The version of the resolved DID Document.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-spec-registries/#versionid)
+  #[serde(rename = "versionId", skip_serializing_if = "Option::is_none")]
+  pub version_id: Option<String>,
+  /// The version of the next update of the resolved DID Document.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-spec-registries/#nextversionid)
+  #[serde(rename = "nextVersionId", skip_serializing_if = "Option::is_none")]
+  pub next_version_id: Option<String>,
+  /// The timestamp of the next update of the resolved DID Document.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-spec-registries/#nextupdate)
+  #[serde(rename = "nextUpdate", skip_serializing_if = "Option::is_none")]
+  pub next_update: Option<Timestamp>,
+  /// Whether the DID has been deactivated.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-spec-registries/#deactivated)
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub deactivated: Option<bool>,
   /// Additional document metadata properties.
   #[serde(flatten)]
   pub properties: Object,
@@ -28,7 +48,16 @@ impl DocumentMetadata {
     Self {
       created: None,
       updated: None,
+      version_id: None,
+      next_version_id: None,
+      next_update: None,
+      deactivated: None,
       properties: Object::new(),
     }
   }
+
+  /// Returns `true` if the DID of the resolved DID Document has been deactivated.
+  pub fn is_deactivated(&self) -> bool {
+    self.deactivated.unwrap_or(false)
+  }
 }
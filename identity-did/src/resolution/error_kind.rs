@@ -17,4 +17,8 @@ pub enum ErrorKind {
   /// this resolution request.
   #[serde(rename = "not-found")]
   NotFound,
+  /// The input metadata supplied to the DID resolution function is invalid,
+  /// e.g. a malformed `versionId`.
+  #[serde(rename = "invalid-input")]
+  InvalidInput,
 }
@@ -2,12 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_core::common::Url;
+use identity_core::error::Error as CoreError;
 use std::time::Instant;
 
 use crate::did::DID;
 use crate::document::CoreDocument;
 use crate::error::Error;
 use crate::error::Result;
+use crate::resolution::CacheStatus;
 use crate::resolution::Dereference;
 use crate::resolution::DocumentMetadata;
 use crate::resolution::ErrorKind;
@@ -46,9 +48,13 @@ where
 
   // 3. Obtain the DID document for the input DID by executing the Read
   //    operation against the input DID's verifiable data registry.
-  let doc: MetaDocument = match method.read(&did, input).await? {
-    Some(doc) => doc,
-    None => return Ok(context.finish_error(ErrorKind::NotFound)),
+  let doc: MetaDocument = match method.read(&did, input).await {
+    Ok(Some(doc)) => doc,
+    Ok(None) => return Ok(context.finish_error(ErrorKind::NotFound)),
+    Err(Error::InvalidMethodName) => return Ok(context.finish_error(ErrorKind::NotSupported)),
+    Err(Error::InvalidResolutionInput) => return Ok(context.finish_error(ErrorKind::InvalidInput)),
+    Err(error) if is_invalid_did(&error) => return Ok(context.finish_error(ErrorKind::InvalidDID)),
+    Err(error) => return Err(error),
   };
 
   // 4. Validate that the output DID document conforms to a conformant
@@ -57,16 +63,46 @@ where
   //   return Ok(context.finish_error(ErrorKind::InvalidDID));
   // }
 
-  // TODO: Handle deactivated DIDs
+  // Deactivated DIDs still resolve to their final DID Document - the DID
+  // method reports the deactivation with the `deactivated` property of the
+  // document metadata, which is returned unchanged.
   // TODO: Handle signature verification
 
   context.set_document(doc.data);
   context.set_metadata(doc.meta);
+  context.set_cache(doc.cache);
   context.set_resolved(did);
 
   Ok(context.finish())
 }
 
+// Returns `true` if a DID method failed to read a DID because its method
+// specific id is malformed, e.g. it does not encode a valid public key.
+fn is_invalid_did(error: &Error) -> bool {
+  matches!(
+    error,
+    Error::DIDError(_)
+      | Error::InvalidMethodId
+      | Error::InvalidKeyData
+      | Error::InvalidKeyDataBase16
+      | Error::InvalidKeyDataBase58
+      | Error::InvalidKeyDataMultibase
+      | Error::InvalidKeyDataMulticodec
+      | Error::InvalidKeyDataJwk
+      | Error::BuilderInvalidServiceType
+      | Error::BuilderInvalidServiceEndpoint
+      | Error::CoreError(
+        CoreError::DecodeBase16(_)
+          | CoreError::DecodeBase58(_)
+          | CoreError::DecodeBase64(_)
+          | CoreError::DecodeMultibase(_)
+          | CoreError::DecodeMulticodec(_)
+          | CoreError::InvalidKeyFormat
+          | CoreError::InvalidKeyLength(..)
+      )
+  )
+}
+
 /// Dereferences a DID URL into a primary or secondary resource.
 ///
 /// See [DID Url Dereferencing][SPEC] for more information.
@@ -163,6 +199,10 @@ impl ResolveContext {
     self.0.metadata.resolved = Some(value);
   }
 
+  fn set_cache(&mut self, value: Option<CacheStatus>) {
+    self.0.metadata.cache = value;
+  }
+
   fn set_error(&mut self, value: ErrorKind) {
     self.0.metadata.error = Some(value);
   }
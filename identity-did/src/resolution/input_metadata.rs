@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_core::common::Object;
+use identity_core::common::Timestamp;
 
 /// The content type of a JSON DID Document.
 pub const MIME_DID: &str = "application/did+json";
@@ -9,9 +10,6 @@ pub const MIME_DID: &str = "application/did+json";
 /// The content type of a JSON-LD DID Document.
 pub const MIME_DID_LD: &str = "application/did+ld+json";
 
-// TODO: Support versioning via `version-id`/`version-time`
-// TODO: Support caching via `no-cache`
-
 /// Input options used to configure a [DID resolution][SPEC] process.
 ///
 /// [SPEC]: https://www.w3.org/TR/did-core/#dfn-did-resolution
@@ -24,6 +22,20 @@ pub struct InputMetadata {
   /// [More Info](https://www.w3.org/TR/did-spec-registries/#accept)
   #[serde(skip_serializing_if = "Option::is_none")]
   pub accept: Option<String>,
+  /// If `true`, cached DID Documents are not used and a fresh DID Document is
+  /// read from the verifiable data registry.
+  #[serde(rename = "no-cache", default, skip_serializing_if = "core::ops::Not::not")]
+  pub no_cache: bool,
+  /// The version of the DID Document to resolve.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-core/#did-parameters)
+  #[serde(rename = "versionId", skip_serializing_if = "Option::is_none")]
+  pub version_id: Option<String>,
+  /// Resolves the latest version of the DID Document at the given time.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-core/#did-parameters)
+  #[serde(rename = "versionTime", skip_serializing_if = "Option::is_none")]
+  pub version_time: Option<Timestamp>,
   /// Additional input metadata properties.
   #[serde(flatten)]
   pub properties: Object,
@@ -34,6 +46,9 @@ impl InputMetadata {
   pub fn new() -> Self {
     Self {
       accept: None,
+      no_cache: false,
+      version_id: None,
+      version_time: None,
       properties: Object::new(),
     }
   }
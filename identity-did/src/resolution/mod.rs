@@ -5,6 +5,9 @@
 
 #![allow(clippy::module_inception)]
 
+mod cache;
+mod cache_status;
+mod cached_resolver;
 mod dereference;
 mod document_metadata;
 mod error_kind;
@@ -12,9 +15,13 @@ mod impls;
 mod input_metadata;
 mod resolution;
 mod resolution_metadata;
+mod resolver;
 mod resource;
 mod traits;
 
+pub use self::cache::ResolutionCache;
+pub use self::cache_status::CacheStatus;
+pub use self::cached_resolver::CachedResolver;
 pub use self::dereference::Dereference;
 pub use self::document_metadata::DocumentMetadata;
 pub use self::error_kind::ErrorKind;
@@ -25,6 +32,7 @@ pub use self::input_metadata::MIME_DID;
 pub use self::input_metadata::MIME_DID_LD;
 pub use self::resolution::Resolution;
 pub use self::resolution_metadata::ResolutionMetadata;
+pub use self::resolver::Resolver;
 pub use self::resource::PrimaryResource;
 pub use self::resource::Resource;
 pub use self::resource::SecondaryResource;
@@ -5,6 +5,7 @@ use core::time::Duration;
 use identity_core::common::Object;
 
 use crate::did::DID;
+use crate::resolution::CacheStatus;
 use crate::resolution::ErrorKind;
 
 /// Metadata associated with a [DID resolution][SPEC] process.
@@ -29,6 +30,9 @@ pub struct ResolutionMetadata {
   /// The parsed DID that was used for resolution.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub resolved: Option<DID>,
+  /// Whether the DID Document was served from a cache, if cached at all.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cache: Option<CacheStatus>,
   /// Additional resolution metadata properties.
   #[serde(flatten)]
   pub properties: Object,
@@ -42,6 +46,7 @@ impl ResolutionMetadata {
       content_type: None,
       duration: Duration::from_secs(0),
       resolved: None,
+      cache: None,
       properties: Object::new(),
     }
   }
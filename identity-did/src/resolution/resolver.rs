@@ -0,0 +1,229 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use std::collections::BTreeMap;
+
+use crate::did::DID;
+use crate::error::Error;
+use crate::error::Result;
+use crate::resolution::dereference;
+use crate::resolution::resolve;
+use crate::resolution::Dereference;
+use crate::resolution::InputMetadata;
+use crate::resolution::MetaDocument;
+use crate::resolution::Resolution;
+use crate::resolution::ResolverMethod;
+
+/// A DID Resolver supporting multiple DID methods.
+///
+/// Each [`ResolverMethod`] is registered under the name of the DID method it
+/// resolves (e.g. `"iota"` or `"key"`) and DIDs are dispatched on their method
+/// name. The `Resolver` is itself a [`ResolverMethod`], so it can be used with
+/// [`resolve`] and [`dereference`] like any single-method resolver.
+#[derive(Default)]
+pub struct Resolver {
+  methods: BTreeMap<String, Box<dyn ResolverMethod>>,
+}
+
+impl Resolver {
+  /// Creates a new `Resolver` without any registered DID methods.
+  pub fn new() -> Self {
+    Self {
+      methods: BTreeMap::new(),
+    }
+  }
+
+  /// Registers `method` as the resolver of the DID method `name`.
+  #[must_use]
+  pub fn method<R>(mut self, name: impl Into<String>, method: R) -> Self
+  where
+    R: ResolverMethod + 'static,
+  {
+    self.set_method(name, method);
+    self
+  }
+
+  /// Registers `method` as the resolver of the DID method `name`, returning
+  /// the previously registered resolver, if any.
+  pub fn set_method<R>(&mut self, name: impl Into<String>, method: R) -> Option<Box<dyn ResolverMethod>>
+  where
+    R: ResolverMethod + 'static,
+  {
+    self.methods.insert(name.into(), Box::new(method))
+  }
+
+  /// Removes the resolver of the DID method `name`.
+  pub fn remove_method(&mut self, name: &str) -> Option<Box<dyn ResolverMethod>> {
+    self.methods.remove(name)
+  }
+
+  /// Returns the resolver of the DID method `name`, if any.
+  pub fn get_method(&self, name: &str) -> Option<&dyn ResolverMethod> {
+    self.methods.get(name).map(|method| &**method)
+  }
+
+  /// Returns an iterator over the names of the registered DID methods.
+  pub fn methods(&self) -> impl Iterator<Item = &str> + '_ {
+    self.methods.keys().map(String::as_str)
+  }
+
+  /// Resolves `did` with the resolver of its DID method.
+  ///
+  /// See [`resolve`] for more information.
+  pub async fn resolve(&self, did: &str, input: InputMetadata) -> Result<Resolution> {
+    resolve(did, input, self).await
+  }
+
+  /// Dereferences the DID URL `did` with the resolver of its DID method.
+  ///
+  /// See [`dereference`] for more information.
+  pub async fn dereference(&self, did: &str, input: InputMetadata) -> Result<Dereference> {
+    dereference(did, input, self).await
+  }
+}
+
+#[async_trait(?Send)]
+impl ResolverMethod for Resolver {
+  fn is_supported(&self, did: &DID) -> bool {
+    self
+      .get_method(did.method())
+      .map_or(false, |method| method.is_supported(did))
+  }
+
+  async fn read(&self, did: &DID, input: InputMetadata) -> Result<Option<MetaDocument>> {
+    match self.get_method(did.method()) {
+      Some(method) if method.is_supported(did) => method.read(did, input).await,
+      _ => Err(Error::InvalidMethodName),
+    }
+  }
+}
+
+impl Debug for Resolver {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.debug_struct("Resolver")
+      .field("methods", &self.methods.keys().collect::<Vec<_>>())
+      .finish()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::crypto::KeyPair;
+  use libjose::jwk::EdCurve;
+  use libjose::jwk::Jwk;
+
+  use crate::did::DID;
+  use crate::methods::JwkMethod;
+  use crate::methods::KeyMethod;
+  use crate::methods::PeerMethod;
+  use crate::resolution::dereference;
+  use crate::resolution::Dereference;
+  use crate::resolution::ErrorKind;
+  use crate::resolution::InputMetadata;
+  use crate::resolution::Resolution;
+  use crate::resolution::Resolver;
+  use crate::resolution::ResolverMethod;
+  use crate::resolution::Resource;
+  use crate::resolution::SecondaryResource;
+
+  fn resolver() -> Resolver {
+    Resolver::new()
+      .method(KeyMethod::NAME, KeyMethod::new())
+      .method(PeerMethod::NAME, PeerMethod::new())
+  }
+
+  #[test]
+  fn test_resolve() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let resolver: Resolver = resolver();
+
+    for did in [
+      KeyMethod::from_keypair(&keypair).unwrap(),
+      PeerMethod::from_keypair(&keypair).unwrap(),
+    ] {
+      let resolution: Resolution =
+        futures::executor::block_on(resolver.resolve(did.as_str(), InputMetadata::new())).unwrap();
+
+      assert!(resolution.metadata.error.is_none());
+      assert_eq!(resolution.document.unwrap().id(), &did);
+    }
+  }
+
+  #[test]
+  fn test_not_supported() {
+    let mut resolver: Resolver = resolver();
+    let did: DID = JwkMethod::from_jwk(&Jwk::random(EdCurve::Ed25519).unwrap()).unwrap();
+
+    let resolve = |resolver: &Resolver, did: &str| -> Resolution {
+      futures::executor::block_on(resolver.resolve(did, InputMetadata::new())).unwrap()
+    };
+
+    // Unregistered DID methods are not supported
+    assert!(!resolver.is_supported(&did));
+    assert_eq!(
+      resolve(&resolver, did.as_str()).metadata.error,
+      Some(ErrorKind::NotSupported)
+    );
+    assert_eq!(
+      resolve(&resolver, "did:example:123").metadata.error,
+      Some(ErrorKind::NotSupported)
+    );
+
+    // Registered methods can reject DIDs of their DID method
+    assert_eq!(
+      resolve(&resolver, "did:peer:1zQmZMygzYqNwU6Uhmewx5Xepf2VLp5S4HLSwwgf2aiKZuwa")
+        .metadata
+        .error,
+      Some(ErrorKind::NotSupported)
+    );
+
+    assert!(resolver.set_method(JwkMethod::NAME, JwkMethod::new()).is_none());
+    assert!(resolver.is_supported(&did));
+    assert!(resolve(&resolver, did.as_str()).metadata.error.is_none());
+
+    assert!(resolver.remove_method(KeyMethod::NAME).is_some());
+    assert_eq!(
+      resolver.methods().collect::<Vec<_>>(),
+      [JwkMethod::NAME, PeerMethod::NAME]
+    );
+  }
+
+  #[test]
+  fn test_invalid_did() {
+    let resolver: Resolver = resolver();
+
+    // Malformed DIDs of registered methods are reported in the metadata
+    for did in [
+      "did:key:zQ3s",
+      "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2d",
+      "did:peer:0z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2d",
+      "did:peer:2.Ez6LSj72tK8brWgZja8NLRwPigth2T9QRiG1uH9oKZuKjdh9p.Sabc",
+    ] {
+      let resolution: Resolution = futures::executor::block_on(resolver.resolve(did, InputMetadata::new())).unwrap();
+
+      assert_eq!(resolution.metadata.error, Some(ErrorKind::InvalidDID), "{}", did);
+      assert!(resolution.document.is_none());
+    }
+  }
+
+  #[test]
+  fn test_dereference() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let did: DID = KeyMethod::from_keypair(&keypair).unwrap();
+    let url: String = format!("{}#{}", did, did.method_id());
+    let resolver: Resolver = resolver();
+
+    // The resolver can be used wherever a single method is accepted
+    let output: Dereference = futures::executor::block_on(dereference(&url, InputMetadata::new(), &resolver)).unwrap();
+
+    assert!(output.metadata.error.is_none());
+    assert!(matches!(
+      output.content,
+      Some(Resource::Secondary(SecondaryResource::VerificationKey(_)))
+    ));
+  }
+}
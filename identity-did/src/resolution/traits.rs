@@ -6,16 +6,33 @@ use async_trait::async_trait;
 use crate::did::DID;
 use crate::document::CoreDocument;
 use crate::error::Result;
+use crate::resolution::CacheStatus;
 use crate::resolution::DocumentMetadata;
 use crate::resolution::InputMetadata;
 
 /// A resolved [`Document`] and associated [`DocumentMetadata`].
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct MetaDocument {
   /// A resolved DID Document.
   pub data: CoreDocument,
   /// Information regarding the associated Documents resolution process.
   pub meta: DocumentMetadata,
+  /// Whether the DID Document was served from a cache, if cached at all.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cache: Option<CacheStatus>,
+}
+
+impl MetaDocument {
+  /// Creates a new `MetaDocument` from the resolved DID Document `data` and
+  /// its metadata `meta`.
+  pub fn new(data: CoreDocument, meta: DocumentMetadata) -> Self {
+    Self {
+      data,
+      meta,
+      cache: None,
+    }
+  }
 }
 
 /// A trait for generic DID Resolvers.
@@ -31,7 +48,21 @@ pub trait ResolverMethod {
 #[async_trait(?Send)]
 impl<T> ResolverMethod for &'_ T
 where
-  T: ResolverMethod + Send + Sync,
+  T: ResolverMethod + ?Sized,
+{
+  fn is_supported(&self, did: &DID) -> bool {
+    (**self).is_supported(did)
+  }
+
+  async fn read(&self, did: &DID, input: InputMetadata) -> Result<Option<MetaDocument>> {
+    (**self).read(did, input).await
+  }
+}
+
+#[async_trait(?Send)]
+impl<T> ResolverMethod for Box<T>
+where
+  T: ResolverMethod + ?Sized,
 {
   fn is_supported(&self, did: &DID) -> bool {
     (**self).is_supported(did)
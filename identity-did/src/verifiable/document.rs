@@ -3,6 +3,10 @@
 
 use core::any::Any;
 use identity_core::common::BitSet;
+use identity_core::common::OneOrMany;
+use identity_core::crypto::bbs::BbsBls12381;
+use identity_core::crypto::bbs::JcsBbsBls12381Signature2021;
+use identity_core::crypto::bbs::JcsBbsBls12381SignatureProof2021;
 use identity_core::crypto::merkle_key::Blake2b256;
 use identity_core::crypto::merkle_key::MerkleDigest;
 use identity_core::crypto::merkle_key::MerkleDigestTag;
@@ -15,19 +19,38 @@ use identity_core::crypto::merkle_key::Sha256;
 use identity_core::crypto::merkle_key::SigningKey;
 use identity_core::crypto::merkle_key::VerificationKey;
 use identity_core::crypto::merkle_tree::Proof;
+use identity_core::crypto::BatchError;
+use identity_core::crypto::CborEd25519;
 use identity_core::crypto::Ed25519;
+use identity_core::crypto::Ed25519Signature2020;
+use identity_core::crypto::ExternalSign;
+use identity_core::crypto::ExternalSigner;
+use identity_core::crypto::JcsEcdsaP256;
+use identity_core::crypto::JcsEcdsaSecp256k1;
 use identity_core::crypto::JcsEd25519;
+use identity_core::crypto::JsonWebSignature2020;
+use identity_core::crypto::Named;
 use identity_core::crypto::PublicKey;
+use identity_core::crypto::Secp256k1;
 use identity_core::crypto::SecretKey;
 use identity_core::crypto::SetSignature;
 use identity_core::crypto::Sign;
 use identity_core::crypto::Signature;
+use identity_core::crypto::SignatureAppend;
+use identity_core::crypto::SignatureOptions;
+use identity_core::crypto::SignatureSet;
+use identity_core::crypto::SignatureView;
 use identity_core::crypto::Signer;
 use identity_core::crypto::TrySignature;
 use identity_core::crypto::TrySignatureMut;
 use identity_core::crypto::Verifier;
+use identity_core::crypto::VerifierOptions;
 use identity_core::crypto::Verify;
+use identity_core::crypto::P256;
 use identity_core::error::Error as CoreError;
+use libjose::jwk::Jwk;
+use libjose::jwk::JwkParams;
+use libjose::utils::encode_b64;
 use serde::Serialize;
 
 use crate::document::CoreDocument;
@@ -36,6 +59,7 @@ use crate::error::Result;
 use crate::verifiable::Properties;
 use crate::verifiable::Revocation;
 use crate::verification::MethodQuery;
+use crate::verification::MethodScope;
 use crate::verification::MethodType;
 use crate::verification::MethodUriType;
 use crate::verification::TryMethod;
@@ -87,10 +111,27 @@ impl<T, U, V> SetSignature for CoreDocument<Properties<T>, U, V> {
   }
 }
 
+impl<T, U, V> SignatureSet for CoreDocument<Properties<T>, U, V> {
+  fn signatures(&self) -> &OneOrMany<Signature> {
+    self.properties().proofs()
+  }
+
+  fn signatures_mut(&mut self) -> &mut OneOrMany<Signature> {
+    self.properties_mut().proofs_mut()
+  }
+}
+
 impl<T, U, V> TryMethod for CoreDocument<Properties<T>, U, V> {
   const TYPE: MethodUriType = MethodUriType::Relative;
 }
 
+impl<T> TryMethod for SignatureAppend<'_, T>
+where
+  T: TryMethod,
+{
+  const TYPE: MethodUriType = T::TYPE;
+}
+
 // =============================================================================
 // Signature Extensions
 // =============================================================================
@@ -108,14 +149,72 @@ where
     let method: &VerificationMethod<U> = self.try_resolve(query)?;
     let fragment: String = method.try_into_fragment()?;
 
-    match method.key_type() {
+    match method.try_key_type()? {
       MethodType::Ed25519VerificationKey2018 => {
         JcsEd25519::<Ed25519>::create_signature(self, &fragment, secret.as_ref())?;
       }
+      MethodType::Ed25519VerificationKey2020 => {
+        Ed25519Signature2020::<Ed25519>::create_signature(self, &fragment, secret.as_ref())?;
+      }
+      MethodType::EcdsaSecp256k1VerificationKey2019 => {
+        JcsEcdsaSecp256k1::<Secp256k1>::create_signature(self, &fragment, secret.as_ref())?;
+      }
+      MethodType::EcdsaSecp256r1VerificationKey2019 => {
+        JcsEcdsaP256::<P256>::create_signature(self, &fragment, secret.as_ref())?;
+      }
+      MethodType::Bls12381G2Key2020 => {
+        JcsBbsBls12381Signature2021::<[u8]>::create_signature(self, &fragment, secret.as_ref())?;
+      }
       MethodType::MerkleKeyCollection2021 => {
         // CoreDocuments can't be signed with Merkle Key Collections
         return Err(Error::InvalidMethodType);
       }
+      MethodType::X25519KeyAgreementKey2019 => {
+        // X25519 keys are used for key agreement - not signatures
+        return Err(Error::InvalidMethodType);
+      }
+      MethodType::JsonWebKey2020 => {
+        // JSON Web Keys are resolved to the type of the encoded key
+        return Err(Error::InvalidMethodType);
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Signs the DID Document with the verification method matching `query`,
+  /// delegating the signature operation to `signer`.
+  pub async fn sign_this_external<'query, Q>(&mut self, query: Q, signer: &dyn ExternalSigner) -> Result<()>
+  where
+    Q: Into<MethodQuery<'query>>,
+    T: Send,
+    U: Send,
+    V: Send,
+  {
+    let method: &VerificationMethod<U> = self.try_resolve(query)?;
+    let fragment: String = method.try_into_fragment()?;
+    let options: SignatureOptions = SignatureOptions::new();
+
+    match method.try_key_type()? {
+      MethodType::Ed25519VerificationKey2018 => {
+        JcsEd25519::<Ed25519>::create_signature_external(self, &fragment, signer, options).await?;
+      }
+      MethodType::Ed25519VerificationKey2020 => {
+        Ed25519Signature2020::<Ed25519>::create_signature_external(self, &fragment, signer, options).await?;
+      }
+      MethodType::EcdsaSecp256k1VerificationKey2019 => {
+        JcsEcdsaSecp256k1::<Secp256k1>::create_signature_external(self, &fragment, signer, options).await?;
+      }
+      MethodType::EcdsaSecp256r1VerificationKey2019 => {
+        JcsEcdsaP256::<P256>::create_signature_external(self, &fragment, signer, options).await?;
+      }
+      MethodType::Bls12381G2Key2020
+      | MethodType::MerkleKeyCollection2021
+      | MethodType::X25519KeyAgreementKey2019
+      | MethodType::JsonWebKey2020 => {
+        // External signers only support single-message signature suites
+        return Err(Error::InvalidMethodType);
+      }
     }
 
     Ok(())
@@ -124,16 +223,37 @@ where
   pub fn verify_this(&self) -> Result<()> {
     let signature: &Signature = self.try_signature()?;
     let method: &VerificationMethod<U> = self.try_resolve(signature)?;
-    let public: PublicKey = method.key_data().try_decode()?.into();
+    let key_type: MethodType = method.try_key_type()?;
+    let public: PublicKey = method.key_data().try_decode_key(key_type)?.into();
 
-    match method.key_type() {
+    match key_type {
       MethodType::Ed25519VerificationKey2018 => {
-        JcsEd25519::<Ed25519>::verify_signature(self, public.as_ref())?;
+        ed25519_verify(self, public.as_ref())?;
+      }
+      MethodType::Ed25519VerificationKey2020 => {
+        Ed25519Signature2020::<Ed25519>::verify_signature(self, public.as_ref())?;
+      }
+      MethodType::EcdsaSecp256k1VerificationKey2019 => {
+        JcsEcdsaSecp256k1::<Secp256k1>::verify_signature(self, public.as_ref())?;
+      }
+      MethodType::EcdsaSecp256r1VerificationKey2019 => {
+        JcsEcdsaP256::<P256>::verify_signature(self, public.as_ref())?;
+      }
+      MethodType::Bls12381G2Key2020 => {
+        bbs_verify(self, public.as_ref(), &VerifierOptions::new())?;
       }
       MethodType::MerkleKeyCollection2021 => {
         // CoreDocuments can't be signed with Merkle Key Collections
         return Err(Error::InvalidMethodType);
       }
+      MethodType::X25519KeyAgreementKey2019 => {
+        // X25519 keys are used for key agreement - not signatures
+        return Err(Error::InvalidMethodType);
+      }
+      MethodType::JsonWebKey2020 => {
+        // JSON Web Keys are resolved to the type of the encoded key
+        return Err(Error::InvalidMethodType);
+      }
     }
 
     Ok(())
@@ -147,6 +267,16 @@ impl<T, U, V> CoreDocument<T, U, V> {
     DocumentSigner::new(self, secret)
   }
 
+  /// Creates a new [`ExternalDocumentSigner`] that can be used to create digital
+  /// signatures from verification methods in this DID Document with an
+  /// [`ExternalSigner`].
+  pub fn external_signer<'base>(
+    &'base self,
+    signer: &'base dyn ExternalSigner,
+  ) -> ExternalDocumentSigner<'base, '_, T, U, V> {
+    ExternalDocumentSigner::new(self, signer)
+  }
+
   /// Creates a new [`DocumentVerifier`] that can be used to verify signatures
   /// created with this DID Document.
   pub fn verifier(&self) -> DocumentVerifier<'_, T, U, V> {
@@ -163,6 +293,8 @@ pub struct DocumentSigner<'base, 'query, 'proof, T, U, V> {
   secret: &'base SecretKey,
   method: Option<MethodQuery<'query>>,
   merkle_key: Option<(&'proof PublicKey, &'proof dyn Any)>,
+  options: SignatureOptions,
+  cbor: bool,
 }
 
 impl<'base, T, U, V> DocumentSigner<'base, '_, '_, T, U, V> {
@@ -172,8 +304,25 @@ impl<'base, T, U, V> DocumentSigner<'base, '_, '_, T, U, V> {
       secret,
       method: None,
       merkle_key: None,
+      options: SignatureOptions::new(),
+      cbor: false,
     }
   }
+
+  /// Sets the proof options of created signatures.
+  pub fn options(mut self, value: SignatureOptions) -> Self {
+    self.options = value;
+    self
+  }
+
+  /// Sets whether created signatures cover the deterministic CBOR encoding of
+  /// the data (`CborEd25519Signature2021`).
+  ///
+  /// CBOR signatures require an `Ed25519VerificationKey2018` method.
+  pub fn cbor(mut self, value: bool) -> Self {
+    self.cbor = value;
+    self
+  }
 }
 
 impl<'base, 'query, T, U, V> DocumentSigner<'base, 'query, '_, T, U, V> {
@@ -211,12 +360,76 @@ impl<T, U, V> DocumentSigner<'_, '_, '_, T, U, V> {
     let method: &VerificationMethod<U> = self.document.try_resolve(query)?;
     let method_uri: String = X::try_method(method)?;
 
-    match method.key_type() {
+    if self.cbor {
+      if method.try_key_type()? != MethodType::Ed25519VerificationKey2018 {
+        return Err(Error::InvalidMethodType);
+      }
+
+      CborEd25519::<Ed25519>::create_signature_with_options(
+        that,
+        &method_uri,
+        self.secret.as_ref(),
+        self.options.clone(),
+      )?;
+
+      return Ok(());
+    }
+
+    // JsonWebKey2020 methods create JsonWebSignature2020 proofs
+    if method.key_type() == MethodType::JsonWebKey2020 {
+      let secret: Jwk = secret_jwk(method, self.secret.as_ref())?;
+
+      JsonWebSignature2020::create_signature_with_options(that, &method_uri, &secret, self.options.clone())?;
+
+      return Ok(());
+    }
+
+    match method.try_key_type()? {
       MethodType::Ed25519VerificationKey2018 => {
-        JcsEd25519::<Ed25519>::create_signature(that, &method_uri, self.secret.as_ref())?;
+        JcsEd25519::<Ed25519>::create_signature_with_options(
+          that,
+          &method_uri,
+          self.secret.as_ref(),
+          self.options.clone(),
+        )?;
+      }
+      MethodType::Ed25519VerificationKey2020 => {
+        Ed25519Signature2020::<Ed25519>::create_signature_with_options(
+          that,
+          &method_uri,
+          self.secret.as_ref(),
+          self.options.clone(),
+        )?;
+      }
+      MethodType::EcdsaSecp256k1VerificationKey2019 => {
+        JcsEcdsaSecp256k1::<Secp256k1>::create_signature_with_options(
+          that,
+          &method_uri,
+          self.secret.as_ref(),
+          self.options.clone(),
+        )?;
+      }
+      MethodType::EcdsaSecp256r1VerificationKey2019 => {
+        JcsEcdsaP256::<P256>::create_signature_with_options(
+          that,
+          &method_uri,
+          self.secret.as_ref(),
+          self.options.clone(),
+        )?;
+      }
+      MethodType::Bls12381G2Key2020 => {
+        JcsBbsBls12381Signature2021::<[u8]>::create_signature_with_options(
+          that,
+          &method_uri,
+          self.secret.as_ref(),
+          self.options.clone(),
+        )?;
+      }
+      MethodType::X25519KeyAgreementKey2019 | MethodType::JsonWebKey2020 => {
+        return Err(Error::InvalidMethodType);
       }
       MethodType::MerkleKeyCollection2021 => {
-        let data: Vec<u8> = method.key_data().try_decode()?;
+        let data: Vec<u8> = method.key_data().try_decode_key(method.key_type())?;
 
         match MerkleKey::extract_tags(&data)? {
           (MerkleSignatureTag::ED25519, MerkleDigestTag::SHA256) => {
@@ -225,6 +438,24 @@ impl<T, U, V> DocumentSigner<'_, '_, '_, T, U, V> {
           (MerkleSignatureTag::ED25519, MerkleDigestTag::BLAKE2B_256) => {
             self.merkle_key_sign::<X, Blake2b256, Ed25519>(that, method_uri)?;
           }
+          (MerkleSignatureTag::SECP256K1, MerkleDigestTag::SHA256) => {
+            self.merkle_key_sign::<X, Sha256, Secp256k1>(that, method_uri)?;
+          }
+          (MerkleSignatureTag::SECP256K1, MerkleDigestTag::BLAKE2B_256) => {
+            self.merkle_key_sign::<X, Blake2b256, Secp256k1>(that, method_uri)?;
+          }
+          (MerkleSignatureTag::P256, MerkleDigestTag::SHA256) => {
+            self.merkle_key_sign::<X, Sha256, P256>(that, method_uri)?;
+          }
+          (MerkleSignatureTag::P256, MerkleDigestTag::BLAKE2B_256) => {
+            self.merkle_key_sign::<X, Blake2b256, P256>(that, method_uri)?;
+          }
+          (MerkleSignatureTag::BBS_BLS12381, MerkleDigestTag::SHA256) => {
+            self.merkle_key_sign::<X, Sha256, BbsBls12381>(that, method_uri)?;
+          }
+          (MerkleSignatureTag::BBS_BLS12381, MerkleDigestTag::BLAKE2B_256) => {
+            self.merkle_key_sign::<X, Blake2b256, BbsBls12381>(that, method_uri)?;
+          }
           (_, _) => {
             return Err(Error::InvalidMethodType);
           }
@@ -235,6 +466,34 @@ impl<T, U, V> DocumentSigner<'_, '_, '_, T, U, V> {
     Ok(())
   }
 
+  /// Signs the provided data with the configured verification method and
+  /// adds the signature to the proof set of the data.
+  ///
+  /// # Errors
+  ///
+  /// Fails if an unsupported verification method is used, document
+  /// serialization fails, or the signature operation fails.
+  pub fn append<X>(&self, that: &mut X) -> Result<()>
+  where
+    X: Serialize + SignatureSet + TryMethod,
+  {
+    SignatureAppend::set(that)?.apply(|target| self.sign(target))
+  }
+
+  /// Signs the provided data and its last signature with the configured
+  /// verification method and adds the signature to the proof chain of the data.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the data has no signature, an unsupported verification method
+  /// is used, document serialization fails, or the signature operation fails.
+  pub fn chain<X>(&self, that: &mut X) -> Result<()>
+  where
+    X: Serialize + SignatureSet + TryMethod,
+  {
+    SignatureAppend::chain(that)?.apply(|target| self.sign(target))
+  }
+
   fn merkle_key_sign<X, D, S>(&self, that: &mut X, method: String) -> Result<()>
   where
     X: Serialize + SetSignature,
@@ -250,7 +509,7 @@ impl<T, U, V> DocumentSigner<'_, '_, '_, T, U, V> {
 
         let skey: SigningKey<'_, D> = SigningKey::from_borrowed(public, self.secret, proof);
 
-        MerkleSigner::<D, S>::create_signature(that, &method, &skey)?;
+        MerkleSigner::<D, S>::create_signature_with_options(that, &method, &skey, self.options.clone())?;
 
         Ok(())
       }
@@ -259,17 +518,189 @@ impl<T, U, V> DocumentSigner<'_, '_, '_, T, U, V> {
   }
 }
 
+// =============================================================================
+// External Document Signer
+// =============================================================================
+
+/// Creates digital signatures from verification methods in a DID Document,
+/// delegating the signature operation to an [`ExternalSigner`].
+pub struct ExternalDocumentSigner<'base, 'query, T, U, V> {
+  document: &'base CoreDocument<T, U, V>,
+  signer: &'base dyn ExternalSigner,
+  method: Option<MethodQuery<'query>>,
+  options: SignatureOptions,
+}
+
+impl<'base, T, U, V> ExternalDocumentSigner<'base, '_, T, U, V> {
+  pub fn new(document: &'base CoreDocument<T, U, V>, signer: &'base dyn ExternalSigner) -> Self {
+    Self {
+      document,
+      signer,
+      method: None,
+      options: SignatureOptions::new(),
+    }
+  }
+
+  /// Sets the proof options of created signatures.
+  pub fn options(mut self, value: SignatureOptions) -> Self {
+    self.options = value;
+    self
+  }
+}
+
+impl<'base, 'query, T, U, V> ExternalDocumentSigner<'base, 'query, T, U, V> {
+  pub fn method<Q>(mut self, value: Q) -> Self
+  where
+    Q: Into<MethodQuery<'query>>,
+  {
+    self.method = Some(value.into());
+    self
+  }
+}
+
+impl<T, U, V> ExternalDocumentSigner<'_, '_, T, U, V> {
+  /// Signs the provided data with the configured verification method.
+  ///
+  /// # Errors
+  ///
+  /// Fails if an unsupported verification method is used, document
+  /// serialization fails, or the external signer fails.
+  pub async fn sign<X>(&self, that: &mut X) -> Result<()>
+  where
+    X: Serialize + SetSignature + TryMethod + Send,
+  {
+    let query: MethodQuery<'_> = self.method.ok_or(Error::QueryMethodNotFound)?;
+    let method: &VerificationMethod<U> = self.document.try_resolve(query)?;
+    let method_uri: String = X::try_method(method)?;
+    let options: SignatureOptions = self.options.clone();
+
+    match method.try_key_type()? {
+      MethodType::Ed25519VerificationKey2018 => {
+        JcsEd25519::<Ed25519>::create_signature_external(that, &method_uri, self.signer, options).await?;
+      }
+      MethodType::Ed25519VerificationKey2020 => {
+        Ed25519Signature2020::<Ed25519>::create_signature_external(that, &method_uri, self.signer, options).await?;
+      }
+      MethodType::EcdsaSecp256k1VerificationKey2019 => {
+        JcsEcdsaSecp256k1::<Secp256k1>::create_signature_external(that, &method_uri, self.signer, options).await?;
+      }
+      MethodType::EcdsaSecp256r1VerificationKey2019 => {
+        JcsEcdsaP256::<P256>::create_signature_external(that, &method_uri, self.signer, options).await?;
+      }
+      MethodType::Bls12381G2Key2020
+      | MethodType::MerkleKeyCollection2021
+      | MethodType::X25519KeyAgreementKey2019
+      | MethodType::JsonWebKey2020 => {
+        return Err(Error::InvalidMethodType);
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Signs the provided data with the configured verification method and
+  /// adds the signature to the proof set of the data.
+  ///
+  /// # Errors
+  ///
+  /// Fails if an unsupported verification method is used, document
+  /// serialization fails, or the external signer fails.
+  pub async fn append<X>(&self, that: &mut X) -> Result<()>
+  where
+    X: Serialize + SignatureSet + TryMethod + Send,
+  {
+    let mut target: SignatureAppend<'_, X> = SignatureAppend::set(that)?;
+    let result: Result<()> = self.sign(&mut target).await;
+
+    if result.is_err() {
+      target.rollback();
+    }
+
+    result
+  }
+
+  /// Signs the provided data and its last signature with the configured
+  /// verification method and adds the signature to the proof chain of the data.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the data has no signature, an unsupported verification method
+  /// is used, document serialization fails, or the external signer fails.
+  pub async fn chain<X>(&self, that: &mut X) -> Result<()>
+  where
+    X: Serialize + SignatureSet + TryMethod + Send,
+  {
+    let mut target: SignatureAppend<'_, X> = SignatureAppend::chain(that)?;
+    let result: Result<()> = self.sign(&mut target).await;
+
+    if result.is_err() {
+      target.rollback();
+    }
+
+    result
+  }
+}
+
 // =============================================================================
 // Document Verifier - Simplifying Digital Signature Verification Since 2021
 // =============================================================================
 
 pub struct DocumentVerifier<'base, T, U, V> {
   document: &'base CoreDocument<T, U, V>,
+  others: Vec<&'base CoreDocument<T, U, V>>,
+  options: VerifierOptions,
 }
 
 impl<'base, T, U, V> DocumentVerifier<'base, T, U, V> {
   pub fn new(document: &'base CoreDocument<T, U, V>) -> Self {
-    Self { document }
+    Self {
+      document,
+      others: Vec::new(),
+      options: VerifierOptions::new(),
+    }
+  }
+
+  /// Sets the proof options enforced when verifying signatures, e.g. the
+  /// expected challenge and domain of a presentation.
+  pub fn options(mut self, value: VerifierOptions) -> Self {
+    self.options = value;
+    self
+  }
+
+  /// Adds a DID Document used to verify proofs created by another controller,
+  /// e.g. the co-signer of a credential.
+  pub fn document(mut self, document: &'base CoreDocument<T, U, V>) -> Self {
+    self.others.push(document);
+    self
+  }
+
+  // Absolute method URIs are resolved with the DID Document of the matching
+  // controller; relative method URIs are resolved with the base document.
+  fn resolve_proof(&self, signature: &Signature) -> Result<&'base VerificationMethod<U>> {
+    let method: &str = signature.verification_method();
+
+    let document: &'base CoreDocument<T, U, V> = match method.split_once('#') {
+      Some((did, _)) if !did.is_empty() => Some(self.document)
+        .into_iter()
+        .chain(self.others.iter().copied())
+        .find(|document| document.id().as_str() == did)
+        .ok_or(Error::QueryMethodNotFound)?,
+      _ => self.document,
+    };
+
+    Self::resolve_method(document, signature)
+  }
+
+  // A signature with a proof purpose is only valid for a verification method
+  // of the matching verification relationship.
+  fn resolve_method(
+    document: &'base CoreDocument<T, U, V>,
+    signature: &Signature,
+  ) -> Result<&'base VerificationMethod<U>> {
+    match signature.purpose() {
+      Some(purpose) => document.try_resolve_with_scope(signature, MethodScope::from(purpose)),
+      None => document.try_resolve(signature),
+    }
   }
 }
 
@@ -281,16 +712,145 @@ where
   ///
   /// # Errors
   ///
-  /// Fails if an unsupported verification method is used, document
-  /// serialization fails, or the verification operation fails.
+  /// Fails if an unsupported verification method is used, the proof options
+  /// don't match, document serialization fails, or the verification operation
+  /// fails.
   pub fn verify<X>(&self, that: &X) -> Result<()>
   where
     X: Serialize + TrySignature,
   {
     let signature: &Signature = that.try_signature()?;
-    let method: &VerificationMethod<U> = self.document.try_resolve(signature)?;
+    let method: &VerificationMethod<U> = Self::resolve_method(self.document, signature)?;
+
+    signature.verify_options(&self.options)?;
+
+    Self::verify_method(method, that, &self.options)
+  }
+
+  /// Verifies the signature at the specified `index` of the proof set of the
+  /// provided data.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the signature is not found, an unsupported verification method
+  /// is used, the proof options don't match, document serialization fails, or
+  /// the verification operation fails.
+  pub fn verify_at<X>(&self, that: &X, index: usize) -> Result<()>
+  where
+    X: Serialize + SignatureSet,
+  {
+    let view: SignatureView<'_, X> = SignatureView::new(that, index);
+    let signature: &Signature = view.try_signature()?;
+    let method: &VerificationMethod<U> = self.resolve_proof(signature)?;
+
+    signature.verify_options(&self.options)?;
+
+    Self::verify_method(method, &view, &self.options)
+  }
+
+  /// Verifies every signature in the proof set of the provided data.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the data has no signatures or any signature is invalid.
+  pub fn verify_all<X>(&self, that: &X) -> Result<()>
+  where
+    X: Serialize + SignatureSet,
+  {
+    if that.signatures().is_empty() {
+      return Err(Error::CoreError(CoreError::MissingSignature));
+    }
+
+    for index in 0..that.signatures().len() {
+      self.verify_at(that, index)?;
+    }
+
+    Ok(())
+  }
+
+  /// Verifies that at least one signature in the proof set of the provided
+  /// data is valid.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the data has no signatures or every signature is invalid.
+  pub fn verify_any<X>(&self, that: &X) -> Result<()>
+  where
+    X: Serialize + SignatureSet,
+  {
+    let mut error: Error = Error::CoreError(CoreError::MissingSignature);
+
+    for index in 0..that.signatures().len() {
+      match self.verify_at(that, index) {
+        Ok(()) => return Ok(()),
+        Err(inner) => error = inner,
+      }
+    }
+
+    Err(error)
+  }
+
+  /// Verifies the signatures of a batch of provided data.
+  ///
+  /// `JcsEd25519Signature2020` signatures created with Ed25519 methods are
+  /// verified together; all other signatures are verified one by one.
+  ///
+  /// # Errors
+  ///
+  /// Fails with [`Error::InvalidBatch`] holding the result of each item if any
+  /// signature is invalid.
+  pub fn verify_batch<X>(&self, items: &[&X]) -> Result<()>
+  where
+    X: Serialize + TrySignature,
+  {
+    let mut results: Vec<Result<()>> = Vec::with_capacity(items.len());
+    let mut batch: Vec<(usize, &X, Vec<u8>)> = Vec::new();
+
+    for (index, that) in items.iter().copied().enumerate() {
+      match self.batch_key(that) {
+        Ok(Some(public)) => {
+          batch.push((index, that, public));
+          results.push(Ok(()));
+        }
+        Ok(None) => results.push(self.verify(that)),
+        Err(error) => results.push(Err(error)),
+      }
+    }
+
+    let queued: Vec<(&X, &[u8])> = batch.iter().map(|(_, that, public)| (*that, &public[..])).collect();
+
+    match JcsEd25519::<Ed25519>::verify_signature_batch(&queued, &self.options) {
+      Ok(()) => {}
+      Err(CoreError::InvalidBatch(error)) => {
+        for ((index, _, _), result) in batch.iter().zip(error.into_results()) {
+          results[*index] = result.map_err(Into::into);
+        }
+      }
+      Err(error) => return Err(error.into()),
+    }
+
+    BatchError::check(results).map_err(Error::InvalidBatch)
+  }
+
+  // Returns the public key of the method that created the signature of `that`
+  // if the signature can be verified in a batch.
+  fn batch_key<X>(&self, that: &X) -> Result<Option<Vec<u8>>>
+  where
+    X: TrySignature,
+  {
+    let signature: &Signature = that.try_signature()?;
+
+    if signature.type_() != JcsEd25519::<Ed25519>::NAME {
+      return Ok(None);
+    }
+
+    let method: &VerificationMethod<U> = Self::resolve_method(self.document, signature)?;
 
-    Self::do_verify(method, that)
+    if method.key_type() != MethodType::Ed25519VerificationKey2018 {
+      return Ok(None);
+    }
+
+    method.key_data().try_decode_key(method.key_type()).map(Some)
   }
 
   /// Verifies the signature of the provided data.
@@ -303,11 +863,39 @@ where
   where
     X: Serialize + TrySignature,
   {
-    let data: Vec<u8> = method.key_data().try_decode()?;
+    Self::verify_method(method, that, &VerifierOptions::new())
+  }
+
+  // Selective disclosure proofs are verified against the nonce of `options`.
+  fn verify_method<X>(method: &VerificationMethod<U>, that: &X, options: &VerifierOptions) -> Result<()>
+  where
+    X: Serialize + TrySignature,
+  {
+    if that.try_signature()?.type_() == JsonWebSignature2020::NAME {
+      return jws_verify(that, method);
+    }
+
+    let key_type: MethodType = method.try_key_type()?;
+    let data: Vec<u8> = method.key_data().try_decode_key(key_type)?;
 
-    match method.key_type() {
+    match key_type {
       MethodType::Ed25519VerificationKey2018 => {
-        JcsEd25519::<Ed25519>::verify_signature(that, &data)?;
+        ed25519_verify(that, &data)?;
+      }
+      MethodType::Ed25519VerificationKey2020 => {
+        Ed25519Signature2020::<Ed25519>::verify_signature(that, &data)?;
+      }
+      MethodType::EcdsaSecp256k1VerificationKey2019 => {
+        JcsEcdsaSecp256k1::<Secp256k1>::verify_signature(that, &data)?;
+      }
+      MethodType::EcdsaSecp256r1VerificationKey2019 => {
+        JcsEcdsaP256::<P256>::verify_signature(that, &data)?;
+      }
+      MethodType::Bls12381G2Key2020 => {
+        bbs_verify(that, &data, options)?;
+      }
+      MethodType::X25519KeyAgreementKey2019 | MethodType::JsonWebKey2020 => {
+        return Err(Error::InvalidMethodType);
       }
       MethodType::MerkleKeyCollection2021 => match MerkleKey::extract_tags(&data)? {
         (MerkleSignatureTag::ED25519, MerkleDigestTag::SHA256) => {
@@ -316,6 +904,24 @@ where
         (MerkleSignatureTag::ED25519, MerkleDigestTag::BLAKE2B_256) => {
           merkle_key_verify::<X, Blake2b256, Ed25519, U>(that, method, &data)?;
         }
+        (MerkleSignatureTag::SECP256K1, MerkleDigestTag::SHA256) => {
+          merkle_key_verify::<X, Sha256, Secp256k1, U>(that, method, &data)?;
+        }
+        (MerkleSignatureTag::SECP256K1, MerkleDigestTag::BLAKE2B_256) => {
+          merkle_key_verify::<X, Blake2b256, Secp256k1, U>(that, method, &data)?;
+        }
+        (MerkleSignatureTag::P256, MerkleDigestTag::SHA256) => {
+          merkle_key_verify::<X, Sha256, P256, U>(that, method, &data)?;
+        }
+        (MerkleSignatureTag::P256, MerkleDigestTag::BLAKE2B_256) => {
+          merkle_key_verify::<X, Blake2b256, P256, U>(that, method, &data)?;
+        }
+        (MerkleSignatureTag::BBS_BLS12381, MerkleDigestTag::SHA256) => {
+          merkle_key_verify::<X, Sha256, BbsBls12381, U>(that, method, &data)?;
+        }
+        (MerkleSignatureTag::BBS_BLS12381, MerkleDigestTag::BLAKE2B_256) => {
+          merkle_key_verify::<X, Blake2b256, BbsBls12381, U>(that, method, &data)?;
+        }
         (_, _) => {
           return Err(Error::InvalidMethodType);
         }
@@ -344,3 +950,61 @@ where
 
   Ok(())
 }
+
+// Ed25519VerificationKey2018 keys verify JCS signatures and CBOR signatures;
+// Linked Data signatures require an Ed25519VerificationKey2020 method.
+fn ed25519_verify<X>(that: &X, public: &[u8]) -> Result<()>
+where
+  X: Serialize + TrySignature,
+{
+  match that.try_signature()?.type_() {
+    name if name == CborEd25519::<Ed25519>::NAME => {
+      CborEd25519::<Ed25519>::verify_signature(that, public)?;
+    }
+    _ => {
+      JcsEd25519::<Ed25519>::verify_signature(that, public)?;
+    }
+  }
+
+  Ok(())
+}
+
+// JSON Web Signatures are verified with the key of any Ed25519 or P-256 method
+// converted to a JSON Web Key.
+fn jws_verify<X, U>(that: &X, method: &VerificationMethod<U>) -> Result<()>
+where
+  X: Serialize + TrySignature,
+{
+  let public: Jwk = method.key_data().try_decode_jwk(method.try_key_type()?)?;
+
+  JsonWebSignature2020::verify_signature(that, &public)?;
+
+  Ok(())
+}
+
+// Returns the JSON Web Key of `method` with the private key `secret`.
+fn secret_jwk<U>(method: &VerificationMethod<U>, secret: &[u8]) -> Result<Jwk> {
+  let mut jwk: Jwk = method.key_data().try_decode_jwk(method.try_key_type()?)?;
+
+  match jwk.params_mut() {
+    JwkParams::Ec(params) => params.d = Some(encode_b64(secret)),
+    JwkParams::Okp(params) => params.d = Some(encode_b64(secret)),
+    _ => return Err(Error::InvalidMethodType),
+  }
+
+  Ok(jwk)
+}
+
+// BBS+ keys verify both regular signatures and derived selective disclosure proofs.
+fn bbs_verify<X>(that: &X, public: &[u8], options: &VerifierOptions) -> Result<()>
+where
+  X: Serialize + TrySignature,
+{
+  if that.try_signature()?.type_() == JcsBbsBls12381SignatureProof2021::<[u8]>::NAME {
+    JcsBbsBls12381SignatureProof2021::<[u8]>::verify_signature_with_options(that, public, options)?;
+  } else {
+    JcsBbsBls12381Signature2021::<[u8]>::verify_signature(that, public)?;
+  }
+
+  Ok(())
+}
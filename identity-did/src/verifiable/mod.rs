@@ -7,6 +7,7 @@ mod traits;
 
 pub use self::document::DocumentSigner;
 pub use self::document::DocumentVerifier;
+pub use self::document::ExternalDocumentSigner;
 pub use self::properties::Properties;
 pub use self::traits::Revocation;
 
@@ -4,23 +4,24 @@
 use core::ops::Deref;
 use core::ops::DerefMut;
 use identity_core::common::Object;
+use identity_core::common::OneOrMany;
 use identity_core::crypto::SetSignature;
 use identity_core::crypto::Signature;
+use identity_core::crypto::SignatureSet;
 use identity_core::crypto::TrySignature;
 use identity_core::crypto::TrySignatureMut;
 
 use crate::verification::MethodUriType;
 use crate::verification::TryMethod;
 
-/// A generic container for a set of properties (`T`) and a
-/// [`digital signature`][Signature].
+/// A generic container for a set of properties (`T`) and one or more
+/// [`digital signatures`][Signature].
 #[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
 pub struct Properties<T = Object> {
   #[serde(flatten)]
   pub(crate) properties: T,
-  // TODO: Support multiple signatures (?)
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub(crate) proof: Option<Signature>,
+  #[serde(default, skip_serializing_if = "OneOrMany::is_empty")]
+  pub(crate) proof: OneOrMany<Signature>,
 }
 
 impl<T> Properties<T> {
@@ -28,7 +29,7 @@ impl<T> Properties<T> {
   pub const fn new(properties: T) -> Self {
     Self {
       properties,
-      proof: None,
+      proof: OneOrMany::Many(Vec::new()),
     }
   }
 
@@ -36,23 +37,45 @@ impl<T> Properties<T> {
   pub const fn with_proof(properties: T, proof: Signature) -> Self {
     Self {
       properties,
-      proof: Some(proof),
+      proof: OneOrMany::One(proof),
     }
   }
 
   /// Returns a reference to the [`proof`][`Signature`].
+  ///
+  /// Returns `None` if the object has no proof or a set of proofs.
   pub fn proof(&self) -> Option<&Signature> {
-    self.proof.as_ref()
+    if self.proof.len() == 1 {
+      self.proof.get(0)
+    } else {
+      None
+    }
   }
 
   /// Returns a mutable reference to the [`proof`][`Signature`].
+  ///
+  /// Returns `None` if the object has no proof or a set of proofs.
   pub fn proof_mut(&mut self) -> Option<&mut Signature> {
-    self.proof.as_mut()
+    if self.proof.len() == 1 {
+      self.proof.get_mut(0)
+    } else {
+      None
+    }
   }
 
-  /// Sets the value of the [`proof`][`Signature`].
+  /// Sets the value of the [`proof`][`Signature`], replacing any existing proofs.
   pub fn set_proof(&mut self, signature: Signature) {
-    self.proof = Some(signature);
+    self.proof = OneOrMany::One(signature);
+  }
+
+  /// Returns a reference to the set of [`proofs`][`Signature`].
+  pub fn proofs(&self) -> &OneOrMany<Signature> {
+    &self.proof
+  }
+
+  /// Returns a mutable reference to the set of [`proofs`][`Signature`].
+  pub fn proofs_mut(&mut self) -> &mut OneOrMany<Signature> {
+    &mut self.proof
   }
 }
 
@@ -74,12 +97,20 @@ impl<T> TrySignature for Properties<T> {
   fn signature(&self) -> Option<&Signature> {
     self.proof()
   }
+
+  fn try_signature(&self) -> identity_core::Result<&Signature> {
+    self.try_single_signature()
+  }
 }
 
 impl<T> TrySignatureMut for Properties<T> {
   fn signature_mut(&mut self) -> Option<&mut Signature> {
     self.proof_mut()
   }
+
+  fn try_signature_mut(&mut self) -> identity_core::Result<&mut Signature> {
+    self.try_single_signature_mut()
+  }
 }
 
 impl<T> SetSignature for Properties<T> {
@@ -88,6 +119,16 @@ impl<T> SetSignature for Properties<T> {
   }
 }
 
+impl<T> SignatureSet for Properties<T> {
+  fn signatures(&self) -> &OneOrMany<Signature> {
+    self.proofs()
+  }
+
+  fn signatures_mut(&mut self) -> &mut OneOrMany<Signature> {
+    self.proofs_mut()
+  }
+}
+
 impl<T> TryMethod for Properties<T> {
   const TYPE: MethodUriType = MethodUriType::Relative;
 }
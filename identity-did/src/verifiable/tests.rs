@@ -1,24 +1,47 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::Object;
+use identity_core::common::OneOrMany;
+use identity_core::common::Timestamp;
+use identity_core::convert::FromCbor;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToCbor;
+use identity_core::convert::ToJson;
+use identity_core::crypto::bbs::JcsBbsBls12381SignatureProof2021;
 use identity_core::crypto::merkle_key::MerkleKey;
 use identity_core::crypto::merkle_key::Sha256;
 use identity_core::crypto::merkle_tree::Hash;
 use identity_core::crypto::merkle_tree::Proof;
+use identity_core::crypto::CborEd25519;
 use identity_core::crypto::Ed25519;
+use identity_core::crypto::JsonWebSignature2020;
 use identity_core::crypto::KeyCollection;
 use identity_core::crypto::KeyPair;
+use identity_core::crypto::KeyType;
+use identity_core::crypto::ProofPurpose;
 use identity_core::crypto::PublicKey;
 use identity_core::crypto::SecretKey;
 use identity_core::crypto::SetSignature;
 use identity_core::crypto::Signature;
+use identity_core::crypto::SignatureOptions;
+use identity_core::crypto::SignatureSet;
+use identity_core::crypto::Signer;
 use identity_core::crypto::TrySignature;
 use identity_core::crypto::TrySignatureMut;
+use identity_core::crypto::VerifierOptions;
+use identity_core::crypto::X25519;
+use identity_core::json;
+use libjose::jwk::Jwk;
+use libjose::utils::encode_b64;
 
 use crate::did::DID;
 use crate::document::CoreDocument;
+use crate::error::Error;
+use crate::methods::JwkMethod;
 use crate::verifiable::Properties;
 use crate::verification::MethodData;
+use crate::verification::MethodScope;
 use crate::verification::MethodType;
 use crate::verification::MethodUriType;
 use crate::verification::TryMethod;
@@ -59,21 +82,386 @@ impl TryMethod for That {
   const TYPE: MethodUriType = MethodUriType::Relative;
 }
 
+#[derive(Debug, Serialize)]
+struct Many {
+  data: u32,
+  #[serde(skip_serializing_if = "OneOrMany::is_empty")]
+  proof: OneOrMany<Signature>,
+}
+
+impl Many {
+  fn new(data: u32) -> Self {
+    Self {
+      data,
+      proof: OneOrMany::default(),
+    }
+  }
+}
+
+impl SignatureSet for Many {
+  fn signatures(&self) -> &OneOrMany<Signature> {
+    &self.proof
+  }
+
+  fn signatures_mut(&mut self) -> &mut OneOrMany<Signature> {
+    &mut self.proof
+  }
+}
+
+impl TryMethod for Many {
+  const TYPE: MethodUriType = MethodUriType::Absolute;
+}
+
+fn ed25519_document(controller: &str, key: &KeyPair) -> CoreDocument<Properties> {
+  let controller: DID = controller.parse().unwrap();
+
+  let method: VerificationMethod = VerificationMethod::builder(Default::default())
+    .id(controller.join("#key-1").unwrap())
+    .controller(controller.clone())
+    .key_type(MethodType::Ed25519VerificationKey2018)
+    .key_data(MethodData::new_b58(key.public()))
+    .build()
+    .unwrap();
+
+  CoreDocument::builder(Default::default())
+    .id(controller)
+    .authentication(method.id().clone())
+    .verification_method(method)
+    .build()
+    .unwrap()
+}
+
 // ===========================================================================
 // ===========================================================================
 
 #[test]
 fn test_sign_verify_this_ed25519() {
-  for method_data_base in [MethodData::new_b58, MethodData::new_multibase] {
-    let key: KeyPair = KeyPair::new_ed25519().unwrap();
+  let key: KeyPair = KeyPair::new_ed25519().unwrap();
+
+  for method_data in [
+    MethodData::new_b58(key.public()),
+    MethodData::new_multicodec(MethodType::Ed25519VerificationKey2018, key.public()),
+  ] {
     let controller: DID = "did:example:1234".parse().unwrap();
-    let public_key = key.public().as_ref().to_vec();
 
     let method: VerificationMethod = VerificationMethod::builder(Default::default())
       .id(controller.join("#key-1").unwrap())
       .controller(controller.clone())
       .key_type(MethodType::Ed25519VerificationKey2018)
-      .key_data(method_data_base(public_key))
+      .key_data(method_data)
+      .build()
+      .unwrap();
+
+    let mut document: CoreDocument<Properties> = CoreDocument::builder(Default::default())
+      .id(controller)
+      .verification_method(method)
+      .build()
+      .unwrap();
+
+    assert!(document.verify_this().is_err());
+
+    document.sign_this("#key-1", key.secret()).unwrap();
+
+    assert!(document.verify_this().is_ok());
+  }
+}
+
+#[test]
+fn test_multicodec_key_type_mismatch() {
+  let key: KeyPair = KeyPair::new_ed25519().unwrap();
+  let controller: DID = "did:example:1234".parse().unwrap();
+
+  for method_data in [
+    MethodData::new_multibase(&key.public().as_ref()[..31]),
+    MethodData::new_multicodec(MethodType::X25519KeyAgreementKey2019, key.public()),
+  ] {
+    let method: VerificationMethod = VerificationMethod::builder(Default::default())
+      .id(controller.join("#key-1").unwrap())
+      .controller(controller.clone())
+      .key_type(MethodType::Ed25519VerificationKey2018)
+      .key_data(method_data)
+      .build()
+      .unwrap();
+
+    assert!(method.key_data().try_decode_key(method.key_type()).is_err());
+
+    let mut document: CoreDocument<Properties> = CoreDocument::builder(Default::default())
+      .id(controller.clone())
+      .verification_method(method)
+      .build()
+      .unwrap();
+
+    assert!(document.sign_this("#key-1", key.secret()).is_ok());
+    assert!(document.verify_this().is_err());
+  }
+}
+
+#[test]
+fn test_sign_verify_this_raw_multibase() {
+  let key: KeyPair = KeyPair::new_ed25519().unwrap();
+  let controller: DID = "did:example:1234".parse().unwrap();
+
+  // Keys encoded without a multicodec header are decoded as raw keys
+  let method: VerificationMethod = VerificationMethod::builder(Default::default())
+    .id(controller.join("#key-1").unwrap())
+    .controller(controller.clone())
+    .key_type(MethodType::Ed25519VerificationKey2018)
+    .key_data(MethodData::new_multibase(key.public()))
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    method.key_data().try_decode_key(method.key_type()).unwrap(),
+    key.public().as_ref()
+  );
+
+  let mut document: CoreDocument<Properties> = CoreDocument::builder(Default::default())
+    .id(controller)
+    .verification_method(method)
+    .build()
+    .unwrap();
+
+  assert!(document.sign_this("#key-1", key.secret()).is_ok());
+  assert!(document.verify_this().is_ok());
+}
+
+#[test]
+fn test_sign_verify_that_ed25519_signature_2020() {
+  let key: KeyPair = KeyPair::new_ed25519().unwrap();
+  let controller: DID = "did:example:1234".parse().unwrap();
+
+  let method: VerificationMethod = VerificationMethod::builder(Default::default())
+    .id(controller.join("#key-1").unwrap())
+    .controller(controller.clone())
+    .key_type(MethodType::Ed25519VerificationKey2020)
+    .key_data(MethodData::new_multicodec(
+      MethodType::Ed25519VerificationKey2020,
+      key.public(),
+    ))
+    .build()
+    .unwrap();
+
+  let document: CoreDocument<Properties> = CoreDocument::builder(Default::default())
+    .id(controller)
+    .verification_method(method)
+    .build()
+    .unwrap();
+
+  let object: Object = Object::from_json_value(json!({
+    "@context": [
+      "https://www.w3.org/2018/credentials/v1",
+      "https://w3id.org/security/suites/ed25519-2020/v1",
+    ],
+    "type": "VerifiableCredential",
+    "issuer": "did:example:1234",
+    "issuanceDate": "2021-01-01T00:00:00Z",
+  }))
+  .unwrap();
+
+  let mut that: Properties = Properties::new(object);
+
+  document.signer(key.secret()).method("#key-1").sign(&mut that).unwrap();
+
+  assert_eq!(that.signature().unwrap().type_(), "Ed25519Signature2020");
+  assert!(document.verifier().verify(&that).is_ok());
+
+  // Linked Data signatures are not verified with Ed25519VerificationKey2018 methods
+  let legacy: CoreDocument<Properties> = ed25519_document("did:example:1234", &key);
+
+  assert!(legacy.verifier().verify(&that).is_err());
+
+  that.insert("issuer".into(), "did:example:5678".into());
+
+  assert!(document.verifier().verify(&that).is_err());
+}
+
+fn jws_credential(issuer: &DID) -> Properties {
+  let object: Object = Object::from_json_value(json!({
+    "@context": [
+      "https://www.w3.org/2018/credentials/v1",
+      "https://w3id.org/security/suites/jws-2020/v1",
+    ],
+    "type": "VerifiableCredential",
+    "issuer": issuer.as_str(),
+    "issuanceDate": "2021-01-01T00:00:00Z",
+  }))
+  .unwrap();
+
+  Properties::new(object)
+}
+
+#[test]
+fn test_sign_verify_that_json_web_signature_2020() {
+  let key: KeyPair = KeyPair::new_ed25519().unwrap();
+  let public: Jwk = MethodData::new_b58(key.public())
+    .try_decode_jwk(MethodType::Ed25519VerificationKey2018)
+    .unwrap();
+
+  let did: DID = JwkMethod::from_jwk(&public).unwrap();
+  let document: CoreDocument<Properties> = JwkMethod::expand(&did).unwrap().map(Properties::new);
+
+  let mut that: Properties = jws_credential(&did);
+
+  document.signer(key.secret()).method("#0").sign(&mut that).unwrap();
+
+  assert_eq!(that.signature().unwrap().type_(), "JsonWebSignature2020");
+  assert!(that.signature().unwrap().value().is_jws());
+  assert!(document.verifier().verify(&that).is_ok());
+
+  that.insert("issuer".into(), "did:example:5678".into());
+
+  assert!(document.verifier().verify(&that).is_err());
+}
+
+#[cfg(feature = "p256")]
+#[test]
+fn test_verify_that_json_web_signature_2020_p256() {
+  let key: KeyPair = KeyPair::new(KeyType::P256).unwrap();
+  let controller: DID = "did:example:1234".parse().unwrap();
+
+  let method: VerificationMethod = VerificationMethod::builder(Default::default())
+    .id(controller.join("#key-1").unwrap())
+    .controller(controller.clone())
+    .key_type(MethodType::EcdsaSecp256r1VerificationKey2019)
+    .key_data(MethodData::new_b58(key.public()))
+    .build()
+    .unwrap();
+
+  // EC methods verify JSON Web Signatures of the same key in JWK form
+  let mut secret: Jwk = method
+    .key_data()
+    .try_decode_jwk(MethodType::EcdsaSecp256r1VerificationKey2019)
+    .unwrap();
+
+  secret.try_ec_params_mut().unwrap().d = Some(encode_b64(key.secret()));
+
+  let document: CoreDocument<Properties> = CoreDocument::builder(Default::default())
+    .id(controller.clone())
+    .verification_method(method)
+    .build()
+    .unwrap();
+
+  let mut that: Properties = jws_credential(&controller);
+
+  JsonWebSignature2020::create_signature(&mut that, "#key-1", &secret).unwrap();
+
+  assert!(document.verifier().verify(&that).is_ok());
+
+  that.insert("issuer".into(), "did:example:5678".into());
+
+  assert!(document.verifier().verify(&that).is_err());
+}
+
+#[cfg(feature = "cbor")]
+#[test]
+fn test_sign_verify_that_cbor_ed25519() {
+  let key: KeyPair = KeyPair::new_ed25519().unwrap();
+  let document: CoreDocument<Properties> = ed25519_document("did:example:1234", &key);
+
+  let mut that: Properties = Properties::new(Object::new());
+
+  that.insert("issuer".into(), "did:example:1234".into());
+  that.insert("values".into(), json!([1, 2, 3]));
+
+  CborEd25519::<Ed25519>::create_signature(&mut that, "did:example:1234#key-1", key.secret().as_ref()).unwrap();
+
+  assert_eq!(that.signature().unwrap().type_(), "CborEd25519Signature2021");
+  assert!(document.verifier().verify(&that).is_ok());
+
+  // The signature survives a round-trip through the binary encoding
+  let bytes: Vec<u8> = that.to_cbor().unwrap();
+  let mut that: Properties = Properties::from_cbor(&bytes).unwrap();
+
+  assert!(document.verifier().verify(&that).is_ok());
+
+  that.insert("issuer".into(), "did:example:5678".into());
+
+  assert!(document.verifier().verify(&that).is_err());
+}
+
+#[cfg(feature = "secp256k1")]
+#[test]
+fn test_sign_verify_this_secp256k1() {
+  let key: KeyPair = KeyPair::new(KeyType::Secp256k1).unwrap();
+
+  for method_data in [
+    MethodData::new_b58(key.public()),
+    MethodData::new_multicodec(MethodType::EcdsaSecp256k1VerificationKey2019, key.public()),
+  ] {
+    let controller: DID = "did:example:1234".parse().unwrap();
+
+    let method: VerificationMethod = VerificationMethod::builder(Default::default())
+      .id(controller.join("#key-1").unwrap())
+      .controller(controller.clone())
+      .key_type(MethodType::EcdsaSecp256k1VerificationKey2019)
+      .key_data(method_data)
+      .build()
+      .unwrap();
+
+    let mut document: CoreDocument<Properties> = CoreDocument::builder(Default::default())
+      .id(controller)
+      .verification_method(method)
+      .build()
+      .unwrap();
+
+    assert!(document.verify_this().is_err());
+
+    document.sign_this("#key-1", key.secret()).unwrap();
+
+    assert!(document.verify_this().is_ok());
+  }
+}
+
+#[cfg(feature = "secp256k1")]
+#[test]
+fn test_sign_verify_that_secp256k1() {
+  let key: KeyPair = KeyPair::new(KeyType::Secp256k1).unwrap();
+  let controller: DID = "did:example:1234".parse().unwrap();
+
+  let method: VerificationMethod = VerificationMethod::builder(Default::default())
+    .id(controller.join("#key-1").unwrap())
+    .controller(controller.clone())
+    .key_type(MethodType::EcdsaSecp256k1VerificationKey2019)
+    .key_data(MethodData::new_b58(key.public()))
+    .build()
+    .unwrap();
+
+  let document: CoreDocument<Properties> = CoreDocument::builder(Default::default())
+    .id(controller)
+    .verification_method(method)
+    .build()
+    .unwrap();
+
+  let mut that: That = That::new(123);
+
+  assert!(document.verifier().verify(&that).is_err());
+
+  document.signer(key.secret()).method("#key-1").sign(&mut that).unwrap();
+
+  assert!(document.verifier().verify(&that).is_ok());
+
+  that.data = 456;
+
+  assert!(document.verifier().verify(&that).is_err());
+}
+
+#[cfg(feature = "p256")]
+#[test]
+fn test_sign_verify_this_p256() {
+  let key: KeyPair = KeyPair::new(KeyType::P256).unwrap();
+
+  for method_data in [
+    MethodData::new_b58(key.public()),
+    MethodData::new_multicodec(MethodType::EcdsaSecp256r1VerificationKey2019, key.public()),
+    MethodData::new_p256_jwk(key.public()).unwrap(),
+  ] {
+    let controller: DID = "did:example:1234".parse().unwrap();
+
+    let method: VerificationMethod = VerificationMethod::builder(Default::default())
+      .id(controller.join("#key-1").unwrap())
+      .controller(controller.clone())
+      .key_type(MethodType::EcdsaSecp256r1VerificationKey2019)
+      .key_data(method_data)
       .build()
       .unwrap();
 
@@ -91,6 +479,116 @@ fn test_sign_verify_this_ed25519() {
   }
 }
 
+#[cfg(feature = "p256")]
+#[test]
+fn test_decode_p256_jwk_invalid() {
+  let key: KeyPair = KeyPair::new(KeyType::P256).unwrap();
+  let valid: MethodData = MethodData::new_p256_jwk(key.public()).unwrap();
+
+  assert_eq!(
+    valid
+      .try_decode_key(MethodType::EcdsaSecp256r1VerificationKey2019)
+      .unwrap(),
+    key.public().as_ref()
+  );
+
+  let mut jwk: Object = match valid {
+    MethodData::PublicKeyJwk(jwk) => jwk,
+    _ => unreachable!(),
+  };
+
+  // A coordinate of the wrong length.
+  let mut short: Object = jwk.clone();
+  short.insert("x".into(), "AQID".into());
+  assert!(MethodData::PublicKeyJwk(short).try_decode().is_err());
+
+  // A point that is not on the P-256 curve.
+  let mut invalid: Object = jwk.clone();
+  invalid.insert("y".into(), jwk["x"].clone());
+  assert!(MethodData::PublicKeyJwk(invalid).try_decode().is_err());
+
+  // A key type that does not match the key parameters.
+  jwk.insert("kty".into(), "OKP".into());
+  assert!(MethodData::PublicKeyJwk(jwk).try_decode().is_err());
+}
+
+#[cfg(feature = "bbs")]
+#[test]
+fn test_sign_verify_that_bls12381() {
+  let key: KeyPair = KeyPair::new(KeyType::Bls12381G2).unwrap();
+  let controller: DID = "did:example:1234".parse().unwrap();
+
+  let method: VerificationMethod = VerificationMethod::builder(Default::default())
+    .id(controller.join("#key-1").unwrap())
+    .controller(controller.clone())
+    .key_type(MethodType::Bls12381G2Key2020)
+    .key_data(MethodData::new_b58(key.public()))
+    .build()
+    .unwrap();
+
+  let document: CoreDocument<Properties> = CoreDocument::builder(Default::default())
+    .id(controller)
+    .verification_method(method)
+    .build()
+    .unwrap();
+
+  let mut object: Object = Object::new();
+  object.insert("name".into(), "Alice".into());
+  object.insert("age".into(), 42.into());
+
+  let mut that: Properties = Properties::new(object);
+
+  document.signer(key.secret()).method("#key-1").sign(&mut that).unwrap();
+
+  assert!(document.verifier().verify(&that).is_ok());
+
+  // Derive a proof that only discloses the `age` property
+  let derived: Object = JcsBbsBls12381SignatureProof2021::derive(&that, key.public(), &["/age"], b"nonce").unwrap();
+  let mut derived: Properties = Properties::from_json_value(derived.to_json_value().unwrap()).unwrap();
+
+  let options: VerifierOptions = VerifierOptions::new().nonce(b"nonce".to_vec());
+
+  assert!(!derived.contains_key("name"));
+  assert!(document.verifier().verify(&derived).is_err());
+  assert!(document.verifier().options(options.clone()).verify(&derived).is_ok());
+
+  derived.insert("age".into(), 21.into());
+
+  assert!(document.verifier().options(options).verify(&derived).is_err());
+}
+
+#[test]
+fn test_key_agreement_x25519() {
+  let key: KeyPair = KeyPair::new(KeyType::X25519).unwrap();
+  let remote: KeyPair = KeyPair::new(KeyType::X25519).unwrap();
+  let controller: DID = "did:example:1234".parse().unwrap();
+
+  let method: VerificationMethod = VerificationMethod::builder(Default::default())
+    .id(controller.join("#key-1").unwrap())
+    .controller(controller.clone())
+    .key_type(MethodType::X25519KeyAgreementKey2019)
+    .key_data(MethodData::new_b58(key.public()))
+    .build()
+    .unwrap();
+
+  let mut document: CoreDocument<Properties> = CoreDocument::builder(Default::default())
+    .id(controller)
+    .key_agreement(method)
+    .build()
+    .unwrap();
+
+  // The key agreement method can be resolved and used for ECDH
+  let public: Vec<u8> = document.try_resolve("#key-1").unwrap().key_data().try_decode().unwrap();
+
+  assert_eq!(
+    X25519::key_exchange(remote.secret(), &public).unwrap(),
+    X25519::key_exchange(key.secret(), remote.public()).unwrap()
+  );
+
+  // X25519 keys can't be used to create signatures
+  assert!(document.sign_this("#key-1", key.secret()).is_err());
+}
+
 #[test]
 fn test_sign_verify_that_merkle_key_ed25519_sha256() {
   for method_data_base in [MethodData::new_b58, MethodData::new_multibase] {
@@ -135,3 +633,248 @@ fn test_sign_verify_that_merkle_key_ed25519_sha256() {
     assert!(document.verifier().verify(&that).is_ok());
   }
 }
+
+#[test]
+fn test_sign_verify_proof_set() {
+  let key1: KeyPair = KeyPair::new_ed25519().unwrap();
+  let key2: KeyPair = KeyPair::new_ed25519().unwrap();
+  let issuer: CoreDocument<Properties> = ed25519_document("did:example:1234", &key1);
+  let notary: CoreDocument<Properties> = ed25519_document("did:example:5678", &key2);
+
+  let mut that: Many = Many::new(123);
+
+  assert!(issuer.verifier().verify_all(&that).is_err());
+  assert!(issuer.verifier().verify_any(&that).is_err());
+  assert!(that.try_single_signature().is_err());
+
+  issuer.signer(key1.secret()).method("#key-1").append(&mut that).unwrap();
+
+  assert!(that.try_single_signature().is_ok());
+
+  notary.signer(key2.secret()).method("#key-1").append(&mut that).unwrap();
+
+  assert_eq!(that.proof.len(), 2);
+  assert!(that.try_single_signature().is_err());
+  assert!(that.try_single_signature_mut().is_err());
+  assert_ne!(that.proof[0].id(), that.proof[1].id());
+  assert!(that.proof[1].previous_proof().is_none());
+
+  // The co-signer document is required to verify every proof
+  assert!(issuer.verifier().verify_all(&that).is_err());
+  assert!(issuer.verifier().verify_any(&that).is_ok());
+  assert!(issuer.verifier().document(&notary).verify_all(&that).is_ok());
+
+  // Proofs in a set are independent of each other
+  that.proof = OneOrMany::One(that.proof[1].clone());
+
+  assert!(notary.verifier().verify_all(&that).is_ok());
+
+  that.data = 456;
+
+  assert!(notary.verifier().verify_any(&that).is_err());
+}
+
+#[test]
+fn test_sign_verify_proof_chain() {
+  let key1: KeyPair = KeyPair::new_ed25519().unwrap();
+  let key2: KeyPair = KeyPair::new_ed25519().unwrap();
+  let issuer: CoreDocument<Properties> = ed25519_document("did:example:1234", &key1);
+  let notary: CoreDocument<Properties> = ed25519_document("did:example:5678", &key2);
+
+  let mut that: Many = Many::new(123);
+
+  // A proof chain requires an existing proof
+  assert!(notary.signer(key2.secret()).method("#key-1").chain(&mut that).is_err());
+  assert!(that.proof.is_empty());
+
+  issuer.signer(key1.secret()).method("#key-1").append(&mut that).unwrap();
+  notary.signer(key2.secret()).method("#key-1").chain(&mut that).unwrap();
+
+  assert_eq!(that.proof.len(), 2);
+  assert_eq!(that.proof[1].previous_proof(), that.proof[0].id());
+
+  let verifier = issuer.verifier().document(&notary);
+
+  assert!(verifier.verify_all(&that).is_ok());
+
+  // The chained proof can't be verified without the previous proof
+  assert!(verifier.verify_at(&that, 0).is_ok());
+
+  that.proof = OneOrMany::One(that.proof[1].clone());
+
+  assert!(verifier.verify_all(&that).is_err());
+}
+
+#[test]
+fn test_sign_verify_proof_options() {
+  let key: KeyPair = KeyPair::new_ed25519().unwrap();
+  let document: CoreDocument<Properties> = ed25519_document("did:example:1234", &key);
+
+  let options: SignatureOptions = SignatureOptions::new()
+    .created(Timestamp::now_utc())
+    .expires(Timestamp::from_unix(Timestamp::now_utc().to_unix() + 3600))
+    .purpose(ProofPurpose::Authentication)
+    .challenge("1f44d55f-f161-4938-a659-f8026467f126")
+    .domain("verifier.example");
+
+  let expected: VerifierOptions = VerifierOptions::new()
+    .purpose(ProofPurpose::Authentication)
+    .challenge("1f44d55f-f161-4938-a659-f8026467f126")
+    .domain("verifier.example");
+
+  let mut that: That = That::new(123);
+
+  document
+    .signer(key.secret())
+    .method("#key-1")
+    .options(options)
+    .sign(&mut that)
+    .unwrap();
+
+  assert_eq!(that.proof.as_ref().unwrap().domain(), Some("verifier.example"));
+  assert!(document.verifier().verify(&that).is_ok());
+  assert!(document.verifier().options(expected.clone()).verify(&that).is_ok());
+
+  // Fails when the proof was created for a different challenge or verifier
+  let options: VerifierOptions = expected.clone().challenge("c0ae1c8e-c7e7-469f-b252-86e6a0e7387e");
+  assert!(document.verifier().options(options).verify(&that).is_err());
+
+  let options: VerifierOptions = expected.clone().domain("attacker.example");
+  assert!(document.verifier().options(options).verify(&that).is_err());
+
+  let options: VerifierOptions = expected.clone().purpose(ProofPurpose::AssertionMethod);
+  assert!(document.verifier().options(options).verify(&that).is_err());
+
+  // Fails when the proof options are mutated
+  let proof: Signature = that.proof.take().unwrap();
+  let mut value: Object = Object::from_json_value(proof.to_json_value().unwrap()).unwrap();
+  value.insert("domain".into(), "attacker.example".into());
+  that.proof = Some(Signature::from_json_value(value.to_json_value().unwrap()).unwrap());
+
+  assert!(document.verifier().verify(&that).is_err());
+
+  // Fails when the method is not in the verification relationship of the purpose
+  let options: SignatureOptions = SignatureOptions::new().purpose(ProofPurpose::AssertionMethod);
+
+  document
+    .signer(key.secret())
+    .method("#key-1")
+    .options(options)
+    .sign(&mut that)
+    .unwrap();
+
+  assert!(document.verifier().verify(&that).is_err());
+
+  // Fails when the proof was created in the future
+  let options: SignatureOptions =
+    SignatureOptions::new().created(Timestamp::from_unix(Timestamp::now_utc().to_unix() + 3600));
+
+  document
+    .signer(key.secret())
+    .method("#key-1")
+    .options(options)
+    .sign(&mut that)
+    .unwrap();
+
+  assert!(document.verifier().verify(&that).is_err());
+
+  // Fails when the proof has expired
+  let options: SignatureOptions =
+    SignatureOptions::new().expires(Timestamp::from_unix(Timestamp::now_utc().to_unix() - 1));
+
+  document
+    .signer(key.secret())
+    .method("#key-1")
+    .options(options)
+    .sign(&mut that)
+    .unwrap();
+
+  assert!(document.verifier().verify(&that).is_err());
+}
+
+#[test]
+fn test_verify_batch() {
+  let key: KeyPair = KeyPair::new_ed25519().unwrap();
+  let mut document: CoreDocument<Properties> = ed25519_document("did:example:1234", &key);
+
+  let method: VerificationMethod = VerificationMethod::builder(Default::default())
+    .id(document.id().join("#key-2").unwrap())
+    .controller(document.id().clone())
+    .key_type(MethodType::Ed25519VerificationKey2020)
+    .key_data(MethodData::new_multicodec(
+      MethodType::Ed25519VerificationKey2020,
+      key.public(),
+    ))
+    .build()
+    .unwrap();
+
+  document.insert_method(MethodScope::VerificationMethod, method);
+
+  let mut items: Vec<That> = (0..4).map(That::new).collect();
+
+  for that in items.iter_mut().take(3) {
+    document.signer(key.secret()).method("#key-1").sign(that).unwrap();
+  }
+
+  // Signatures of other suites are verified one by one
+  document
+    .signer(key.secret())
+    .method("#key-2")
+    .sign(&mut items[3])
+    .unwrap();
+
+  let refs: Vec<&That> = items.iter().collect();
+  assert!(document.verifier().verify_batch(&refs).is_ok());
+  assert!(document.verifier().verify_batch::<That>(&[]).is_ok());
+
+  items[1].data = 100;
+  items[3].data = 300;
+
+  let refs: Vec<&That> = items.iter().collect();
+
+  match document.verifier().verify_batch(&refs) {
+    Err(Error::InvalidBatch(error)) => {
+      assert_eq!(error.failed().collect::<Vec<_>>(), [1, 3]);
+      assert_eq!(error.results().len(), 4);
+    }
+    result => panic!("unexpected result: {:?}", result),
+  }
+
+  // Proof options are enforced for every item
+  let options: VerifierOptions = VerifierOptions::new().challenge("1f44d55f-f161-4938-a659-f8026467f126");
+  let error: Error = document.verifier().options(options).verify_batch(&refs).unwrap_err();
+
+  assert!(matches!(error, Error::InvalidBatch(error) if error.failed().count() == 4));
+}
+
+#[test]
+fn test_sign_verify_external() {
+  let key1: KeyPair = KeyPair::new_ed25519().unwrap();
+  let key2: KeyPair = KeyPair::new_ed25519().unwrap();
+  let mut issuer: CoreDocument<Properties> = ed25519_document("did:example:1234", &key1);
+  let notary: CoreDocument<Properties> = ed25519_document("did:example:5678", &key2);
+
+  futures::executor::block_on(issuer.sign_this_external("#key-1", &key1)).unwrap();
+
+  assert!(issuer.verify_this().is_ok());
+
+  let mut that: That = That::new(123);
+
+  futures::executor::block_on(issuer.external_signer(&key1).method("#key-1").sign(&mut that)).unwrap();
+
+  assert!(issuer.verifier().verify(&that).is_ok());
+
+  let mut that: Many = Many::new(123);
+
+  futures::executor::block_on(issuer.external_signer(&key1).method("#key-1").append(&mut that)).unwrap();
+
+  // A failed signature operation doesn't leave a proof behind
+  let invalid: KeyPair = KeyPair::new(KeyType::X25519).unwrap();
+
+  assert!(futures::executor::block_on(notary.external_signer(&invalid).method("#key-1").append(&mut that)).is_err());
+  assert_eq!(that.proof.len(), 1);
+
+  futures::executor::block_on(notary.external_signer(&key2).method("#key-1").chain(&mut that)).unwrap();
+
+  assert!(issuer.verifier().document(&notary).verify_all(&that).is_ok());
+}
@@ -6,13 +6,30 @@ use core::fmt::Formatter;
 use core::fmt::Result as FmtResult;
 
 use identity_core::common::Object;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
 use identity_core::utils::decode_b58;
+use identity_core::utils::decode_b64;
 use identity_core::utils::decode_multibase;
+use identity_core::utils::decode_multicodec;
+use identity_core::utils::decode_p256_jwk;
 use identity_core::utils::encode_b58;
 use identity_core::utils::encode_multibase;
+use identity_core::utils::encode_multicodec;
+use identity_core::utils::encode_p256_jwk;
+use identity_core::utils::Multicodec;
+use libjose::jwk::EcCurve;
+use libjose::jwk::EcxCurve;
+use libjose::jwk::EdCurve;
+use libjose::jwk::Jwk;
+use libjose::jwk::JwkParams;
+use libjose::jwk::JwkParamsEc;
+use libjose::jwk::JwkParamsOkp;
+use libjose::utils::encode_b64;
 
 use crate::error::Error;
 use crate::error::Result;
+use crate::verification::MethodType;
 
 /// Supported verification method data formats.
 #[derive(Clone, PartialEq, Deserialize, Serialize)]
@@ -37,9 +54,44 @@ impl MethodData {
     Self::PublicKeyMultibase(encode_multibase(&data, None))
   }
 
+  /// Creates a new `MethodData` variant with [Multibase]-encoded content
+  /// prefixed with the [Multicodec] of the given `key_type`.
+  ///
+  /// Method types without a multicodec (e.g. Merkle Key Collections) are
+  /// encoded as with [`MethodData::new_multibase`].
+  ///
+  /// [Multibase]: https://datatracker.ietf.org/doc/html/draft-multiformats-multibase-03
+  /// [Multicodec]: https://github.com/multiformats/multicodec
+  pub fn new_multicodec(key_type: MethodType, data: impl AsRef<[u8]>) -> Self {
+    match key_type.multicodec() {
+      Some(codec) => Self::new_multibase(encode_multicodec(codec, &data)),
+      None => Self::new_multibase(data),
+    }
+  }
+
+  /// Creates a new `MethodData` variant with an `EC` JSON Web Key from a
+  /// SEC1-encoded `P-256` public key.
+  ///
+  /// The key parameters match the `ES256` keys produced by `libjose`.
+  pub fn new_p256_jwk(data: impl AsRef<[u8]>) -> Result<Self> {
+    let (x, y): (String, String) = encode_p256_jwk(&data).map_err(|_| Error::InvalidKeyDataJwk)?;
+
+    let jwk: Jwk = Jwk::from_params(JwkParamsEc {
+      crv: EcCurve::P256.name().into(),
+      x,
+      y,
+      d: None,
+    });
+
+    Object::from_json_slice(&jwk.to_json_vec()?)
+      .map(Self::PublicKeyJwk)
+      .map_err(|_| Error::InvalidKeyDataJwk)
+  }
+
   /// Returns a `Vec<u8>` containing the decoded bytes of the `MethodData`.
   ///
   /// This is generally a public key identified by a `MethodType` value.
+  /// Multicodec headers are not removed, see [`MethodData::try_decode_key`].
   ///
   /// # Errors
   ///
@@ -49,9 +101,153 @@ impl MethodData {
     match self {
       Self::PublicKeyMultibase(input) => decode_multibase(input).map_err(|_| Error::InvalidKeyDataMultibase),
       Self::PublicKeyBase58(input) => decode_b58(input).map_err(|_| Error::InvalidKeyDataBase58),
-      Self::PublicKeyJwk(_) => Err(Error::InvalidKeyData),
+      Self::PublicKeyJwk(input) => decode_jwk(input),
     }
   }
+
+  /// Returns the `MethodType` of the public key of a JSON Web Key.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the `MethodData` is not a JWK or has an unsupported `kty` or `crv`.
+  pub(crate) fn try_jwk_key_type(&self) -> Result<MethodType> {
+    match self {
+      Self::PublicKeyJwk(input) => jwk_key_type(input),
+      _ => Err(Error::InvalidKeyDataJwk),
+    }
+  }
+
+  /// Returns a `Vec<u8>` containing the public key of the given `key_type`
+  /// encoded in the `MethodData`.
+  ///
+  /// The [Multicodec] header of multibase-encoded content is checked against
+  /// the `key_type` and removed. Multibase-encoded content without a header
+  /// (see [`MethodData::new_multibase`]) is accepted if it has the raw key
+  /// length of the `key_type`. Base58 and JWK content is decoded as-is.
+  ///
+  /// [Multicodec]: https://github.com/multiformats/multicodec
+  ///
+  /// # Errors
+  ///
+  /// Decoding can fail if `MethodData` has invalid content or the multicodec
+  /// does not match the `key_type`.
+  pub fn try_decode_key(&self, key_type: MethodType) -> Result<Vec<u8>> {
+    let data: Vec<u8> = self.try_decode()?;
+
+    match (self, key_type.multicodec()) {
+      (Self::PublicKeyMultibase(_), Some(expected)) => {
+        // A key with a multicodec header is always longer than the raw key.
+        if Some(data.len()) == key_type.key_length() {
+          return Ok(data);
+        }
+
+        let (codec, key): (Multicodec, &[u8]) =
+          decode_multicodec(&data).map_err(|_| Error::InvalidKeyDataMulticodec)?;
+
+        if codec != expected {
+          return Err(Error::InvalidKeyDataMulticodec);
+        }
+
+        Ok(key.to_vec())
+      }
+      _ => Ok(data),
+    }
+  }
+
+  /// Returns the public key of the given `key_type` encoded in the
+  /// `MethodData` as a JSON Web Key.
+  ///
+  /// JWK content is returned without private key parameters; Ed25519 and
+  /// P-256 keys of other formats are converted to `OKP` and `EC` keys.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the `MethodData` has invalid content or the `key_type` is not
+  /// an Ed25519 or P-256 key type.
+  pub fn try_decode_jwk(&self, key_type: MethodType) -> Result<Jwk> {
+    match key_type {
+      MethodType::Ed25519VerificationKey2018
+      | MethodType::Ed25519VerificationKey2020
+      | MethodType::EcdsaSecp256r1VerificationKey2019 => {}
+      _ => return Err(Error::InvalidMethodType),
+    }
+
+    if let Self::PublicKeyJwk(input) = self {
+      return parse_jwk(input).map(|jwk| jwk.to_public());
+    }
+
+    let public: Vec<u8> = self.try_decode_key(key_type)?;
+
+    if key_type == MethodType::EcdsaSecp256r1VerificationKey2019 {
+      let (x, y): (String, String) = encode_p256_jwk(&public).map_err(|_| Error::InvalidKeyData)?;
+
+      Ok(Jwk::from_params(JwkParamsEc {
+        crv: EcCurve::P256.name().into(),
+        x,
+        y,
+        d: None,
+      }))
+    } else {
+      if public.len() != 32 {
+        return Err(Error::InvalidKeyData);
+      }
+
+      Ok(Jwk::from_params(JwkParamsOkp {
+        crv: EdCurve::Ed25519.name().into(),
+        x: encode_b64(&public),
+        d: None,
+      }))
+    }
+  }
+}
+
+fn parse_jwk(jwk: &Object) -> Result<Jwk> {
+  let jwk: Jwk = Jwk::from_json_slice(&jwk.to_json_vec()?).map_err(|_| Error::InvalidKeyDataJwk)?;
+
+  // The `kty` member is not used to select the (untagged) key parameters.
+  if jwk.kty() != jwk.params().kty() {
+    return Err(Error::InvalidKeyDataJwk);
+  }
+
+  Ok(jwk)
+}
+
+fn decode_jwk(jwk: &Object) -> Result<Vec<u8>> {
+  let jwk: Jwk = parse_jwk(jwk)?;
+
+  match jwk.params() {
+    JwkParams::Ec(params) if params.try_ec_curve().ok() == Some(EcCurve::P256) => {
+      // The coordinates are validated as a point on the P-256 curve.
+      decode_p256_jwk(&params.x, &params.y).map_err(|_| Error::InvalidKeyDataJwk)
+    }
+    JwkParams::Okp(params)
+      if params.try_ed_curve().ok() == Some(EdCurve::Ed25519)
+        || params.try_ecx_curve().ok() == Some(EcxCurve::X25519) =>
+    {
+      let public: Vec<u8> = decode_b64(&params.x).map_err(|_| Error::InvalidKeyDataJwk)?;
+
+      if public.len() != 32 {
+        return Err(Error::InvalidKeyDataJwk);
+      }
+
+      Ok(public)
+    }
+    _ => Err(Error::InvalidKeyData),
+  }
+}
+
+fn jwk_key_type(jwk: &Object) -> Result<MethodType> {
+  let jwk: Jwk = parse_jwk(jwk)?;
+
+  if jwk.try_ec_curve().ok() == Some(EcCurve::P256) {
+    Ok(MethodType::EcdsaSecp256r1VerificationKey2019)
+  } else if jwk.try_ed_curve().ok() == Some(EdCurve::Ed25519) {
+    Ok(MethodType::Ed25519VerificationKey2018)
+  } else if jwk.try_ecx_curve().ok() == Some(EcxCurve::X25519) {
+    Ok(MethodType::X25519KeyAgreementKey2019)
+  } else {
+    Err(Error::InvalidKeyDataJwk)
+  }
 }
 
 impl Debug for MethodData {
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use core::str::FromStr;
+use identity_core::crypto::ProofPurpose;
 
 use crate::error::Error;
 use crate::error::Result;
@@ -36,6 +37,18 @@ impl Default for MethodScope {
   }
 }
 
+impl From<ProofPurpose> for MethodScope {
+  fn from(other: ProofPurpose) -> Self {
+    match other {
+      ProofPurpose::AssertionMethod => Self::AssertionMethod,
+      ProofPurpose::Authentication => Self::Authentication,
+      ProofPurpose::CapabilityInvocation => Self::CapabilityInvocation,
+      ProofPurpose::CapabilityDelegation => Self::CapabilityDelegation,
+      ProofPurpose::KeyAgreement => Self::KeyAgreement,
+    }
+  }
+}
+
 impl FromStr for MethodScope {
   type Err = Error;
 
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use core::str::FromStr;
+use identity_core::utils::Multicodec;
 
 use crate::error::Error;
 use crate::error::Result;
@@ -12,6 +13,12 @@ use crate::error::Result;
 pub enum MethodType {
   Ed25519VerificationKey2018 = 0,
   MerkleKeyCollection2021 = 1,
+  EcdsaSecp256k1VerificationKey2019 = 2,
+  EcdsaSecp256r1VerificationKey2019 = 3,
+  Bls12381G2Key2020 = 4,
+  X25519KeyAgreementKey2019 = 5,
+  JsonWebKey2020 = 6,
+  Ed25519VerificationKey2020 = 7,
 }
 
 impl MethodType {
@@ -19,6 +26,12 @@ impl MethodType {
     match value {
       0 => Some(Self::Ed25519VerificationKey2018),
       1 => Some(Self::MerkleKeyCollection2021),
+      2 => Some(Self::EcdsaSecp256k1VerificationKey2019),
+      3 => Some(Self::EcdsaSecp256r1VerificationKey2019),
+      4 => Some(Self::Bls12381G2Key2020),
+      5 => Some(Self::X25519KeyAgreementKey2019),
+      6 => Some(Self::JsonWebKey2020),
+      7 => Some(Self::Ed25519VerificationKey2020),
       _ => None,
     }
   }
@@ -31,6 +44,42 @@ impl MethodType {
     match self {
       Self::Ed25519VerificationKey2018 => "Ed25519VerificationKey2018",
       Self::MerkleKeyCollection2021 => "MerkleKeyCollection2021",
+      Self::EcdsaSecp256k1VerificationKey2019 => "EcdsaSecp256k1VerificationKey2019",
+      Self::EcdsaSecp256r1VerificationKey2019 => "EcdsaSecp256r1VerificationKey2019",
+      Self::Bls12381G2Key2020 => "Bls12381G2Key2020",
+      Self::X25519KeyAgreementKey2019 => "X25519KeyAgreementKey2019",
+      Self::JsonWebKey2020 => "JsonWebKey2020",
+      Self::Ed25519VerificationKey2020 => "Ed25519VerificationKey2020",
+    }
+  }
+
+  /// Returns the [`Multicodec`] of the public keys identified by the
+  /// `MethodType`, if any.
+  pub const fn multicodec(self) -> Option<Multicodec> {
+    match self {
+      Self::Ed25519VerificationKey2018 => Some(Multicodec::Ed25519Pub),
+      Self::MerkleKeyCollection2021 => None,
+      Self::EcdsaSecp256k1VerificationKey2019 => Some(Multicodec::Secp256k1Pub),
+      Self::EcdsaSecp256r1VerificationKey2019 => Some(Multicodec::P256Pub),
+      Self::Bls12381G2Key2020 => Some(Multicodec::Bls12381G2Pub),
+      Self::X25519KeyAgreementKey2019 => Some(Multicodec::X25519Pub),
+      Self::JsonWebKey2020 => None,
+      Self::Ed25519VerificationKey2020 => Some(Multicodec::Ed25519Pub),
+    }
+  }
+
+  /// Returns the length of the raw public keys identified by the
+  /// `MethodType`, if the length is fixed.
+  pub const fn key_length(self) -> Option<usize> {
+    match self {
+      Self::Ed25519VerificationKey2018 => Some(32),
+      Self::MerkleKeyCollection2021 => None,
+      Self::EcdsaSecp256k1VerificationKey2019 => Some(33),
+      Self::EcdsaSecp256r1VerificationKey2019 => Some(33),
+      Self::Bls12381G2Key2020 => Some(96),
+      Self::X25519KeyAgreementKey2019 => Some(32),
+      Self::JsonWebKey2020 => None,
+      Self::Ed25519VerificationKey2020 => Some(32),
     }
   }
 }
@@ -42,6 +91,12 @@ impl FromStr for MethodType {
     match string {
       "Ed25519VerificationKey2018" => Ok(Self::Ed25519VerificationKey2018),
       "MerkleKeyCollection2021" => Ok(Self::MerkleKeyCollection2021),
+      "EcdsaSecp256k1VerificationKey2019" => Ok(Self::EcdsaSecp256k1VerificationKey2019),
+      "EcdsaSecp256r1VerificationKey2019" => Ok(Self::EcdsaSecp256r1VerificationKey2019),
+      "Bls12381G2Key2020" => Ok(Self::Bls12381G2Key2020),
+      "X25519KeyAgreementKey2019" => Ok(Self::X25519KeyAgreementKey2019),
+      "JsonWebKey2020" => Ok(Self::JsonWebKey2020),
+      "Ed25519VerificationKey2020" => Ok(Self::Ed25519VerificationKey2020),
       _ => Err(Error::UnknownMethodType),
     }
   }
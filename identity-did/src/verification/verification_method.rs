@@ -82,6 +82,17 @@ impl<T> VerificationMethod<T> {
     &mut self.key_type
   }
 
+  /// Returns the type of the public key of the verification `Method`.
+  ///
+  /// This is the verification `Method` type, except for `JsonWebKey2020`
+  /// methods where the key type is determined by the JWK `kty` and `crv`.
+  pub fn try_key_type(&self) -> Result<MethodType> {
+    match self.key_type {
+      MethodType::JsonWebKey2020 => self.key_data.try_jwk_key_type(),
+      key_type => Ok(key_type),
+    }
+  }
+
   /// Returns a reference to the verification `Method` data.
   pub fn key_data(&self) -> &MethodData {
     &self.key_data
@@ -55,18 +55,27 @@ impl DiffChain {
     integration_document: &IotaDocument,
     mut index: MessageIndex<DocumentDiff>,
   ) -> Result<Self> {
-    if index.is_empty() {
+    // Deactivated documents cannot be updated with diffs.
+    if index.is_empty() || integration_document.is_deactivated() {
       return Ok(Self::new());
     }
 
     let mut this: Self = Self::new();
+    let mut document: IotaDocument = integration_document.clone();
+
     while let Some(mut list) = index.remove(
       this
         .current_message_id()
         .unwrap_or_else(|| integration_document.message_id()),
     ) {
       'inner: while let Some(next) = list.pop() {
-        if integration_document.verify_data(&next).is_ok() {
+        if integration_document.verify_data(&next).is_err() {
+          continue;
+        }
+
+        // Diffs that can't be merged (e.g. backdated updates) are ignored.
+        if let Ok(merged) = next.merge(&document) {
+          document = merged;
           this.inner.push(next);
           break 'inner;
         }
@@ -155,6 +164,12 @@ impl DiffChain {
     document: &IotaDocument,
     expected_prev_message_id: &MessageId,
   ) -> Result<()> {
+    if document.is_deactivated() {
+      return Err(Error::ChainError {
+        error: "Document Deactivated",
+      });
+    }
+
     if document.verify_data(diff).is_err() {
       return Err(Error::ChainError {
         error: "Invalid Signature",
@@ -208,6 +223,8 @@ impl From<DiffChain> for Vec<DocumentDiff> {
 #[cfg(test)]
 mod test {
   use identity_core::common::Timestamp;
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
   use identity_core::crypto::KeyPair;
   use identity_did::verification::MethodBuilder;
   use identity_did::verification::MethodData;
@@ -296,4 +313,94 @@ mod test {
       assert!(chain.try_push_diff(diff).is_ok());
     }
   }
+
+  #[test]
+  fn test_deactivation() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let mut document: IotaDocument = IotaDocument::from_keypair(&keypair).unwrap();
+
+    // Unpublished documents cannot be deactivated
+    assert!(document.deactivate(keypair.secret()).is_err());
+
+    document.sign(keypair.secret()).unwrap();
+    document.set_message_id(MessageId::new([8; 32]));
+
+    let mut chain: DocumentChain = DocumentChain::new(IntegrationChain::new(document.clone()).unwrap());
+
+    let mut tombstone: IotaDocument = document.deactivate(keypair.secret()).unwrap();
+    tombstone.set_message_id(MessageId::new([9; 32]));
+
+    assert!(tombstone.is_deactivated());
+    assert_eq!(tombstone.methods().count(), 0);
+    assert_eq!(tombstone.previous_message_id(), &MessageId::new([8; 32]));
+    assert!(tombstone.verify().is_err());
+    assert!(document.verify_data(&tombstone).is_ok());
+
+    // The tombstone is a valid DID Document
+    let json: String = tombstone.to_json().unwrap();
+    let decoded: IotaDocument = IotaDocument::from_json(&json).unwrap();
+    assert!(decoded.is_deactivated());
+    assert!(decoded.deactivate(keypair.secret()).is_err());
+
+    assert!(chain.try_push_integration(tombstone).is_ok());
+    assert!(chain.current().is_deactivated());
+
+    // Integration chain updates are rejected
+    let mut update: IotaDocument = document.clone();
+    update.set_updated(Timestamp::now_utc());
+    update.set_previous_message_id(MessageId::new([9; 32]));
+    update.set_message_id(MessageId::new([10; 32]));
+    document.sign_data(&mut update, keypair.secret()).unwrap();
+
+    assert!(!chain.integration_chain().is_valid_addition(&update));
+    assert!(chain.try_push_integration(update).is_err());
+
+    // Diff chain updates are rejected
+    let mut update: IotaDocument = document.clone();
+    update.properties_mut().insert("foo".into(), 123.into());
+
+    let mut diff: DocumentDiff = document
+      .diff(&update, MessageId::new([9; 32]), keypair.secret())
+      .unwrap();
+    diff.set_message_id(MessageId::new([10; 32]));
+
+    assert!(chain.try_push_diff(diff).is_err());
+    assert!(chain.current().is_deactivated());
+  }
+
+  #[test]
+  fn test_backdated_updates() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let mut document: IotaDocument = IotaDocument::from_keypair(&keypair).unwrap();
+
+    document.set_updated(Timestamp::from_unix(200));
+    document.sign(keypair.secret()).unwrap();
+    document.set_message_id(MessageId::new([8; 32]));
+
+    let mut chain: DocumentChain = DocumentChain::new(IntegrationChain::new(document.clone()).unwrap());
+
+    // Integration chain updates can't be backdated
+    let mut update: IotaDocument = document.clone();
+    update.set_updated(Timestamp::from_unix(100));
+    update.set_previous_message_id(MessageId::new([8; 32]));
+    update.set_message_id(MessageId::new([9; 32]));
+    document.sign_data(&mut update, keypair.secret()).unwrap();
+
+    assert!(!chain.integration_chain().is_valid_addition(&update));
+    assert!(chain.try_push_integration(update).is_err());
+
+    // Diff chain updates can't be backdated
+    let mut update: IotaDocument = document.clone();
+    update.properties_mut().insert("foo".into(), 123.into());
+    update.set_updated(Timestamp::from_unix(100));
+
+    let mut diff: DocumentDiff = document
+      .diff(&update, MessageId::new([8; 32]), keypair.secret())
+      .unwrap();
+    diff.set_message_id(MessageId::new([10; 32]));
+
+    assert!(chain.try_push_diff(diff).is_err());
+    assert_eq!(chain.current().updated(), Timestamp::from_unix(200));
+    assert!(chain.current().properties().get("foo").is_none());
+  }
 }
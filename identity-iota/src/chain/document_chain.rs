@@ -15,6 +15,8 @@ use crate::did::IotaDID;
 use crate::did::IotaDocument;
 use crate::error::Result;
 use crate::tangle::MessageId;
+use crate::tangle::MessageIndex;
+use crate::tangle::TangleRead;
 
 /// Holds an [`IntegrationChain`] and its corresponding [`DiffChain`] that can be used to resolve the
 /// latest version of an [`IotaDocument`].
@@ -43,6 +45,33 @@ impl DocumentChain {
     Ok(this)
   }
 
+  /// Reads the [`DocumentChain`] of the given [`IotaDID`] from the Tangle.
+  ///
+  /// The diff chain is skipped if the DID has a `diff=false` query.
+  pub(crate) async fn read<R>(reader: &R, did: &IotaDID) -> Result<Self>
+  where
+    R: TangleRead,
+  {
+    trace!("Read Document Chain: {}", did);
+    trace!("Integration Chain Address: {}", did.tag());
+
+    let index: MessageIndex<IotaDocument> = reader.read_integration_index(did).await?;
+    let chain_i: IntegrationChain = IntegrationChain::try_from_index(index)?;
+
+    // Check if there is any query given and return
+    let skip_diff: bool = did.query_pairs().any(|(key, value)| key == "diff" && value == "false");
+
+    let chain_d: DiffChain = if skip_diff {
+      DiffChain::new()
+    } else {
+      let index: MessageIndex<DocumentDiff> = reader.read_diff_index(chain_i.current()).await?;
+
+      DiffChain::try_from_index(&chain_i, index)?
+    };
+
+    Self::new_with_diff_chain(chain_i, chain_d)
+  }
+
   /// Creates a new [`DocumentChain`] from the given [`IntegrationChain`].
   pub fn new(chain_i: IntegrationChain) -> Self {
     Self {
@@ -144,7 +173,7 @@ impl DocumentChain {
   pub fn try_push_diff(&mut self, diff: DocumentDiff) -> Result<()> {
     self.chain_d.check_valid_addition(&self.chain_i, &diff)?;
 
-    let mut document: IotaDocument = self.document.take().unwrap_or_else(|| self.chain_i.current().clone());
+    let mut document: IotaDocument = self.current().clone();
 
     document.merge(&diff)?;
 
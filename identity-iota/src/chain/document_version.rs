@@ -0,0 +1,210 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::iter::once;
+use identity_core::common::Timestamp;
+
+use crate::chain::DiffChain;
+use crate::chain::IntegrationChain;
+use crate::did::DocumentDiff;
+use crate::did::IotaDID;
+use crate::did::IotaDocument;
+use crate::error::Result;
+use crate::tangle::MessageId;
+use crate::tangle::MessageIndex;
+use crate::tangle::TangleRead;
+use crate::tangle::TangleRef;
+
+/// Selects a version of an [`IotaDocument`] from its integration and diff chains.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VersionQuery {
+  /// The version published in the Tangle message with the given id.
+  Id(MessageId),
+  /// The latest version updated at or before the given time.
+  Time(Timestamp),
+}
+
+/// A version of an [`IotaDocument`] rebuilt from its integration and diff chains.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DocumentVersion {
+  /// The DID Document as of this version.
+  pub document: IotaDocument,
+  /// The Tangle message id of the integration or diff message of this version.
+  pub version_id: MessageId,
+  /// The Tangle message id of the next version, if any.
+  pub next_version_id: Option<MessageId>,
+  /// The time of the next update of the DID Document, if any.
+  pub next_update: Option<Timestamp>,
+}
+
+impl DocumentVersion {
+  /// Reads the version of the DID Document identified by the given [`IotaDID`]
+  /// that matches `query` from the Tangle.
+  ///
+  /// The integration and diff chains are read in order until the matching
+  /// version and its successor have been found.
+  pub(crate) async fn read<R>(reader: &R, did: &IotaDID, query: VersionQuery) -> Result<Option<Self>>
+  where
+    R: TangleRead,
+  {
+    let index: MessageIndex<IotaDocument> = reader.read_integration_index(did).await?;
+    let integration_chain: IntegrationChain = IntegrationChain::try_from_index(index)?;
+    let history: &[IotaDocument] = integration_chain.history().unwrap_or_default();
+
+    let mut search: VersionSearch = VersionSearch::new(query);
+
+    for integration in history.iter().chain(once(integration_chain.current())) {
+      if search.push(integration.message_id(), integration) {
+        break;
+      }
+
+      // Tombstones deactivating the DID have no diff chain.
+      if integration.is_deactivated() {
+        break;
+      }
+
+      // Fetch the diff chain of each integration document, in order.
+      let index: MessageIndex<DocumentDiff> = reader.read_diff_index(integration).await?;
+      let diff: DiffChain = DiffChain::try_from_index_with_document(integration, index)?;
+
+      let mut document: IotaDocument = integration.clone();
+
+      for update in diff.iter() {
+        document.merge(update)?;
+
+        if search.push(update.message_id(), &document) {
+          return Ok(search.finish());
+        }
+      }
+    }
+
+    Ok(search.finish())
+  }
+}
+
+/// Finds the version matching a [`VersionQuery`] in a sequence of versions
+/// ordered from oldest to newest.
+///
+/// The `updated` timestamps of the integration and diff chains are validated
+/// to never decrease, so a time query stops at the first version updated
+/// after the given time without skipping any version.
+#[derive(Clone, Debug)]
+pub(crate) struct VersionSearch {
+  query: VersionQuery,
+  found: Option<DocumentVersion>,
+}
+
+impl VersionSearch {
+  pub(crate) fn new(query: VersionQuery) -> Self {
+    Self { query, found: None }
+  }
+
+  /// Adds the next version to the search, returning `true` once the matching
+  /// version and its successor have been found.
+  pub(crate) fn push(&mut self, version_id: &MessageId, document: &IotaDocument) -> bool {
+    let matches: bool = match self.query {
+      // Only the version with the given id matches.
+      VersionQuery::Id(id) => self.found.is_none() && id == *version_id,
+      // Every version up to the given time matches - the latest one wins.
+      VersionQuery::Time(time) => document.updated() <= time,
+    };
+
+    if matches {
+      self.found = Some(DocumentVersion {
+        document: document.clone(),
+        version_id: *version_id,
+        next_version_id: None,
+        next_update: None,
+      });
+
+      return false;
+    }
+
+    match (self.query, self.found.as_mut()) {
+      (_, Some(found)) => {
+        found.next_version_id = Some(*version_id);
+        found.next_update = Some(document.updated());
+        true
+      }
+      // The DID Document didn't exist at the given time.
+      (VersionQuery::Time(_), None) => true,
+      (VersionQuery::Id(_), None) => false,
+    }
+  }
+
+  /// Returns the matching version, if any.
+  pub(crate) fn finish(self) -> Option<DocumentVersion> {
+    self.found
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Timestamp;
+  use identity_core::crypto::KeyPair;
+
+  use crate::chain::document_version::VersionSearch;
+  use crate::chain::DocumentVersion;
+  use crate::chain::VersionQuery;
+  use crate::did::IotaDocument;
+  use crate::tangle::MessageId;
+
+  fn versions() -> Vec<(MessageId, IotaDocument)> {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let document: IotaDocument = IotaDocument::from_keypair(&keypair).unwrap();
+
+    (1..=3)
+      .map(|index| {
+        let mut document: IotaDocument = document.clone();
+        document.set_updated(Timestamp::from_unix(index * 100));
+        (MessageId::new([index as u8; 32]), document)
+      })
+      .collect()
+  }
+
+  fn search(query: VersionQuery) -> Option<DocumentVersion> {
+    let mut search: VersionSearch = VersionSearch::new(query);
+
+    for (version_id, document) in versions().iter() {
+      if search.push(version_id, document) {
+        break;
+      }
+    }
+
+    search.finish()
+  }
+
+  #[test]
+  fn test_version_id() {
+    let version: DocumentVersion = search(VersionQuery::Id(MessageId::new([2; 32]))).unwrap();
+
+    assert_eq!(version.version_id, MessageId::new([2; 32]));
+    assert_eq!(version.document.updated(), Timestamp::from_unix(200));
+    assert_eq!(version.next_version_id, Some(MessageId::new([3; 32])));
+    assert_eq!(version.next_update, Some(Timestamp::from_unix(300)));
+
+    let version: DocumentVersion = search(VersionQuery::Id(MessageId::new([3; 32]))).unwrap();
+
+    assert_eq!(version.next_version_id, None);
+    assert_eq!(version.next_update, None);
+
+    assert!(search(VersionQuery::Id(MessageId::new([4; 32]))).is_none());
+  }
+
+  #[test]
+  fn test_version_time() {
+    let version: DocumentVersion = search(VersionQuery::Time(Timestamp::from_unix(250))).unwrap();
+
+    assert_eq!(version.version_id, MessageId::new([2; 32]));
+    assert_eq!(version.next_version_id, Some(MessageId::new([3; 32])));
+    assert_eq!(version.next_update, Some(Timestamp::from_unix(300)));
+
+    let version: DocumentVersion = search(VersionQuery::Time(Timestamp::from_unix(300))).unwrap();
+
+    assert_eq!(version.version_id, MessageId::new([3; 32]));
+    assert_eq!(version.next_version_id, None);
+
+    // The DID Document didn't exist yet
+    assert!(search(VersionQuery::Time(Timestamp::from_unix(50))).is_none());
+  }
+}
@@ -137,15 +137,29 @@ impl IntegrationChain {
   ///
   /// # Errors
   ///
-  /// Fails if the document signature is invalid or the Tangle message
-  /// references within the [`IotaDocument`] are invalid.
+  /// Fails if the latest [`IotaDocument`] is deactivated, the document signature is
+  /// invalid, the document was updated before the latest [`IotaDocument`], or the
+  /// Tangle message references within the [`IotaDocument`] are invalid.
   pub fn check_valid_addition(&self, document: &IotaDocument) -> Result<()> {
+    if self.current.is_deactivated() {
+      return Err(Error::ChainError {
+        error: "Document Deactivated",
+      });
+    }
+
     if self.current.verify_data(document).is_err() {
       return Err(Error::ChainError {
         error: "Invalid Signature",
       });
     }
 
+    // A backdated update would be resolved as an earlier version of the DID Document.
+    if document.updated() < self.current.updated() {
+      return Err(Error::ChainError {
+        error: "Invalid Updated Timestamp",
+      });
+    }
+
     if document.message_id().is_null() {
       return Err(Error::ChainError {
         error: "Missing Message Id",
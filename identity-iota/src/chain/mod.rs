@@ -4,9 +4,13 @@
 pub use self::diff_chain::DiffChain;
 pub use self::document_chain::DocumentChain;
 pub use self::document_history::{ChainHistory, DocumentHistory};
+pub use self::document_version::DocumentVersion;
+pub use self::document_version::VersionQuery;
+pub(crate) use self::document_version::VersionSearch;
 pub use self::integration_chain::IntegrationChain;
 
 mod diff_chain;
 mod document_chain;
 mod document_history;
+mod document_version;
 mod integration_chain;
@@ -5,9 +5,11 @@ use identity_core::common::Object;
 use identity_core::convert::FromJson;
 use identity_credential::credential::Credential;
 use identity_credential::presentation::Presentation;
+use identity_did::error::Error as DocumentError;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::collections::BTreeMap;
+use std::iter::once;
 
 use crate::did::IotaDID;
 use crate::did::IotaDocument;
@@ -33,10 +35,12 @@ pub struct PresentationValidation<T = Object, U = Object> {
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
+#[non_exhaustive]
 pub struct DocumentValidation {
   pub did: IotaDID,
   pub document: IotaDocument,
   pub metadata: Object,
+  pub deactivated: bool,
   pub verified: bool,
 }
 
@@ -111,6 +115,84 @@ impl<'a, R: TangleResolve> CredentialValidator<'a, R> {
     })
   }
 
+  /// Validates a batch of `Credential` proofs and all relevant DID documents.
+  ///
+  /// Every DID document is resolved once and the proofs of credentials from
+  /// the same issuer are verified together. The result of each credential is
+  /// returned in order.
+  pub async fn validate_credentials<T>(&self, credentials: Vec<Credential<T>>) -> Result<Vec<CredentialValidation<T>>>
+  where
+    T: Serialize,
+  {
+    let mut documents: BTreeMap<String, DocumentValidation> = BTreeMap::new();
+
+    // Resolve the DID Documents of all issuers and subjects with `id`s.
+    for credential in credentials.iter() {
+      let subjects = credential
+        .credential_subject
+        .iter()
+        .filter_map(|subject| subject.id.as_ref())
+        .map(|id| id.as_str());
+
+      for did in once(credential.issuer.url().as_str()).chain(subjects) {
+        if !documents.contains_key(did) {
+          documents.insert(did.to_string(), self.validate_document(did).await?);
+        }
+      }
+    }
+
+    let mut issuers: BTreeMap<&str, Vec<usize>> = BTreeMap::new();
+
+    for (index, credential) in credentials.iter().enumerate() {
+      issuers.entry(credential.issuer.url().as_str()).or_default().push(index);
+    }
+
+    // Verify the credential signatures of each issuer as a batch
+    let mut verified: Vec<bool> = vec![false; credentials.len()];
+
+    for (issuer, indexes) in issuers {
+      let batch: Vec<&Credential<T>> = indexes.iter().map(|index| &credentials[*index]).collect();
+
+      let results: Vec<bool> = match documents[issuer].document.verifier().verify_batch(&batch) {
+        Ok(()) => vec![true; batch.len()],
+        Err(DocumentError::InvalidBatch(error)) => error.results().iter().map(|result| result.is_ok()).collect(),
+        Err(_) => vec![false; batch.len()],
+      };
+
+      for (index, result) in indexes.into_iter().zip(results) {
+        verified[index] = result;
+      }
+    }
+
+    let validations: Vec<CredentialValidation<T>> = credentials
+      .into_iter()
+      .zip(verified)
+      .map(|(credential, credential_verified)| {
+        let issuer: DocumentValidation = documents[credential.issuer.url().as_str()].clone();
+
+        let subjects: BTreeMap<String, DocumentValidation> = credential
+          .credential_subject
+          .iter()
+          .filter_map(|subject| subject.id.as_ref())
+          .map(|id| (id.to_string(), documents[id.as_str()].clone()))
+          .collect();
+
+        // The credential is truly verified if all associated documents are verified
+        let verified: bool =
+          issuer.verified && credential_verified && subjects.values().all(|subject| subject.verified);
+
+        CredentialValidation {
+          credential,
+          issuer,
+          subjects,
+          verified,
+        }
+      })
+      .collect();
+
+    Ok(validations)
+  }
+
   /// Validates the `Presentation` proof and all relevant DID documents.
   ///
   /// Note: The presentation holder is expected to be a valid DID.
@@ -159,13 +241,183 @@ impl<'a, R: TangleResolve> CredentialValidator<'a, R> {
   async fn validate_document(&self, did: &str) -> Result<DocumentValidation> {
     let did: IotaDID = did.parse()?;
     let document: IotaDocument = self.client.resolve(&did).await?;
-    let verified: bool = document.verify().is_ok();
+
+    // Deactivated DID Documents are never trusted.
+    let deactivated: bool = document.is_deactivated();
+    let verified: bool = !deactivated && document.verify().is_ok();
 
     Ok(DocumentValidation {
       did,
       document,
       metadata: Object::new(),
+      deactivated,
       verified,
     })
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Url;
+  use identity_core::crypto::KeyPair;
+  use identity_credential::credential::CredentialBuilder;
+  use identity_credential::credential::Subject;
+  use identity_credential::presentation::PresentationBuilder;
+
+  use super::*;
+  use crate::tangle::MessageId;
+  use crate::tangle::TangleRef;
+
+  struct StaticResolver(IotaDocument);
+
+  #[async_trait::async_trait(?Send)]
+  impl TangleResolve for StaticResolver {
+    async fn resolve(&self, _did: &IotaDID) -> Result<IotaDocument> {
+      Ok(self.0.clone())
+    }
+  }
+
+  struct MapResolver(BTreeMap<String, IotaDocument>);
+
+  #[async_trait::async_trait(?Send)]
+  impl TangleResolve for MapResolver {
+    async fn resolve(&self, did: &IotaDID) -> Result<IotaDocument> {
+      self
+        .0
+        .get(did.as_str())
+        .cloned()
+        .ok_or(Error::ChainError { error: "Not Found" })
+    }
+  }
+
+  fn signed_document(keypair: &KeyPair) -> IotaDocument {
+    let mut document: IotaDocument = IotaDocument::from_keypair(keypair).unwrap();
+    document.sign(keypair.secret()).unwrap();
+    document.set_message_id(MessageId::new([8; 32]));
+    document
+  }
+
+  fn signed_credential(issuer: &IotaDocument, subject: &Url, keypair: &KeyPair) -> Credential {
+    let mut credential: Credential = CredentialBuilder::default()
+      .issuer(Url::parse(issuer.id().as_str()).unwrap())
+      .type_("UniversityDegreeCredential")
+      .subject(Subject::with_id(subject.clone()))
+      .build()
+      .unwrap();
+
+    issuer.sign_data(&mut credential, keypair.secret()).unwrap();
+
+    credential
+  }
+
+  #[test]
+  fn test_deactivated_issuer() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let mut document: IotaDocument = IotaDocument::from_keypair(&keypair).unwrap();
+    document.sign(keypair.secret()).unwrap();
+    document.set_message_id(MessageId::new([8; 32]));
+
+    let issuer: Url = Url::parse(document.id().as_str()).unwrap();
+
+    let mut credential: Credential = CredentialBuilder::default()
+      .issuer(issuer.clone())
+      .type_("UniversityDegreeCredential")
+      .subject(Subject::with_id(issuer.clone()))
+      .build()
+      .unwrap();
+
+    document.sign_data(&mut credential, keypair.secret()).unwrap();
+
+    let mut presentation: Presentation = PresentationBuilder::default()
+      .holder(issuer)
+      .credential(credential.clone())
+      .build()
+      .unwrap();
+
+    document.sign_data(&mut presentation, keypair.secret()).unwrap();
+
+    // The signatures are valid while the issuer is active.
+    let resolver: StaticResolver = StaticResolver(document.clone());
+    let validator: CredentialValidator<'_, StaticResolver> = CredentialValidator::new(&resolver);
+    let validation: CredentialValidation =
+      futures::executor::block_on(validator.validate_credential(credential.clone())).unwrap();
+    assert!(validation.verified);
+
+    let mut tombstone: IotaDocument = document.deactivate(keypair.secret()).unwrap();
+    tombstone.set_message_id(MessageId::new([9; 32]));
+
+    // Credentials from a deactivated issuer are never verified.
+    let resolver: StaticResolver = StaticResolver(tombstone);
+    let validator: CredentialValidator<'_, StaticResolver> = CredentialValidator::new(&resolver);
+
+    let validation: CredentialValidation =
+      futures::executor::block_on(validator.validate_credential(credential)).unwrap();
+    assert!(validation.issuer.deactivated);
+    assert!(!validation.issuer.verified);
+    assert!(!validation.verified);
+
+    let validation: PresentationValidation =
+      futures::executor::block_on(validator.validate_presentation(presentation)).unwrap();
+    assert!(validation.holder.deactivated);
+    assert!(!validation.credentials[0].verified);
+    assert!(!validation.verified);
+  }
+
+  #[test]
+  fn test_validate_credentials() {
+    let keypair1: KeyPair = KeyPair::new_ed25519().unwrap();
+    let keypair2: KeyPair = KeyPair::new_ed25519().unwrap();
+
+    let active: IotaDocument = signed_document(&keypair1);
+    let retired: IotaDocument = signed_document(&keypair2);
+
+    let subject: Url = Url::parse(active.id().as_str()).unwrap();
+
+    let valid1: Credential = signed_credential(&active, &subject, &keypair1);
+    let valid2: Credential = signed_credential(&active, &subject, &keypair1);
+    let deactivated: Credential = signed_credential(&retired, &subject, &keypair2);
+
+    let mut invalid: Credential = signed_credential(&active, &subject, &keypair1);
+    invalid.properties.insert("tampered".into(), true.into());
+
+    let mut tombstone: IotaDocument = retired.deactivate(keypair2.secret()).unwrap();
+    tombstone.set_message_id(MessageId::new([9; 32]));
+
+    let documents: BTreeMap<String, IotaDocument> = vec![
+      (active.id().to_string(), active.clone()),
+      (tombstone.id().to_string(), tombstone),
+    ]
+    .into_iter()
+    .collect();
+
+    let resolver: MapResolver = MapResolver(documents);
+    let validator: CredentialValidator<'_, MapResolver> = CredentialValidator::new(&resolver);
+
+    let credentials: Vec<Credential> = vec![valid1, invalid, deactivated, valid2];
+    let validations: Vec<CredentialValidation> =
+      futures::executor::block_on(validator.validate_credentials(credentials.clone())).unwrap();
+
+    // The results are returned in the order of the credentials
+    assert_eq!(validations.len(), 4);
+
+    for (validation, credential) in validations.iter().zip(credentials.iter()) {
+      assert_eq!(&validation.credential, credential);
+    }
+
+    assert!(validations[0].verified);
+    assert!(!validations[1].verified);
+    assert!(validations[1].issuer.verified);
+    assert!(!validations[2].verified);
+    assert!(validations[2].issuer.deactivated);
+    assert!(!validations[2].issuer.verified);
+    assert!(validations[3].verified);
+
+    // Every credential has the same result as when validated on its own
+    for (validation, credential) in validations.iter().zip(credentials) {
+      let single: CredentialValidation =
+        futures::executor::block_on(validator.validate_credential(credential)).unwrap();
+
+      assert_eq!(validation.verified, single.verified);
+    }
+  }
+}
@@ -16,6 +16,7 @@ use identity_did::verification::TryMethod;
 
 use crate::did::IotaDID;
 use crate::did::IotaDocument;
+use crate::error::Error;
 use crate::error::Result;
 use crate::tangle::MessageId;
 use crate::tangle::MessageIdExt;
@@ -79,12 +80,31 @@ impl DocumentDiff {
 
   /// Returns a new DID Document which is the result of merging `self`
   /// with the given Document.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the merge operation fails, the diff deactivates the DID Document -
+  /// deactivation is only valid on the integration chain - or the diff sets an
+  /// `updated` timestamp before the one of the given Document.
   pub fn merge(&self, document: &IotaDocument) -> Result<IotaDocument> {
     let data: DiffDocument = DiffDocument::from_json(&self.diff)?;
     let core: CoreDocument = document.serde_into()?;
     let this: CoreDocument = Diff::merge(&core, data)?;
+    let this: IotaDocument = this.serde_into()?;
+
+    if this.is_deactivated() {
+      return Err(Error::ChainError {
+        error: "Invalid Diff Deactivation",
+      });
+    }
+
+    if this.updated() < document.updated() {
+      return Err(Error::ChainError {
+        error: "Invalid Updated Timestamp",
+      });
+    }
 
-    Ok(this.serde_into()?)
+    Ok(this)
   }
 }
 
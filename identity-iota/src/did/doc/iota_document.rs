@@ -13,7 +13,9 @@ use serde::Serialize;
 use identity_core::common::Object;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
+use identity_core::common::Value;
 use identity_core::convert::SerdeInto;
+use identity_core::crypto::ExternalSigner;
 use identity_core::crypto::KeyPair;
 use identity_core::crypto::SecretKey;
 use identity_core::crypto::SetSignature;
@@ -26,6 +28,7 @@ use identity_did::utils::DIDKey;
 use identity_did::utils::OrderedSet;
 use identity_did::verifiable::DocumentSigner;
 use identity_did::verifiable::DocumentVerifier;
+use identity_did::verifiable::ExternalDocumentSigner;
 use identity_did::verifiable::Properties as VerifiableProperties;
 use identity_did::verification::MethodQuery;
 use identity_did::verification::MethodRef;
@@ -129,7 +132,9 @@ impl IotaDocument {
   ///
   /// Returns `Err` if the document is not a valid IOTA DID Document.
   pub fn try_from_core(document: CoreDocument) -> Result<Self> {
-    IotaDocument::validate_core_document(&document)?;
+    let deactivated: bool = matches!(document.properties().get("deactivated"), Some(Value::Bool(true)));
+
+    IotaDocument::validate_core_document(&document, deactivated)?;
 
     Ok(Self {
       document: document.serde_into()?,
@@ -143,7 +148,7 @@ impl IotaDocument {
   ///
   /// Returns `Err` if the document is not a valid IOTA DID Document.
   pub fn try_from_base(document: BaseDocument) -> Result<Self> {
-    IotaDocument::validate_core_document(&document)?;
+    IotaDocument::validate_core_document(&document, document.properties().deactivated)?;
 
     Ok(Self {
       document: document.serde_into()?,
@@ -153,10 +158,13 @@ impl IotaDocument {
 
   /// Performs validation that a `CoreDocument` adheres to the IOTA spec.
   ///
+  /// A `deactivated` document must be a tombstone without any verification
+  /// methods, all other documents require a valid authentication method.
+  ///
   /// # Errors
   ///
   /// Returns `Err` if the document is not a valid IOTA DID Document.
-  fn validate_core_document<T, U, V>(document: &CoreDocument<T, U, V>) -> Result<()> {
+  fn validate_core_document<T, U, V>(document: &CoreDocument<T, U, V>, deactivated: bool) -> Result<()> {
     // Validate that the DID conforms to the IotaDID specification.
     // This check is required to ensure the correctness of the `IotaDocument::id()` method which
     // creates an `IotaDID::new_unchecked_ref()` from the underlying DID.
@@ -185,6 +193,14 @@ impl IotaDocument {
       }
     }
 
+    if deactivated {
+      if !document.verification_method().is_empty() || document.verification_relationships().next().is_some() {
+        return Err(Error::InvalidDocumentTombstone);
+      }
+
+      return Ok(());
+    }
+
     let method = document
       .authentication()
       .head()
@@ -206,7 +222,13 @@ impl IotaDocument {
     // Ensure the verification method type is supported
     match method.key_type() {
       MethodType::Ed25519VerificationKey2018 => {}
+      MethodType::Ed25519VerificationKey2020 => {}
+      MethodType::EcdsaSecp256k1VerificationKey2019 => {}
+      MethodType::EcdsaSecp256r1VerificationKey2019 => {}
       MethodType::MerkleKeyCollection2021 => return Err(Error::InvalidDocumentAuthType),
+      MethodType::Bls12381G2Key2020 => return Err(Error::InvalidDocumentAuthType),
+      MethodType::X25519KeyAgreementKey2019 => return Err(Error::InvalidDocumentAuthType),
+      MethodType::JsonWebKey2020 => return Err(Error::InvalidDocumentAuthType),
     }
 
     Ok(())
@@ -254,20 +276,37 @@ impl IotaDocument {
   }
 
   /// Returns the default authentication method of the DID document.
-  pub fn authentication(&self) -> &IotaVerificationMethod {
-    // This `unwrap` is "fine" - a valid document will
-    // always have a resolvable authentication method.
-    let method: &MethodRef = self.document.authentication().head().unwrap();
-    let method: &VerificationMethod = self.document.resolve_ref(method).unwrap();
+  ///
+  /// # Errors
+  ///
+  /// Fails if the DID document is [deactivated][IotaDocument::is_deactivated].
+  pub fn authentication(&self) -> Result<&IotaVerificationMethod> {
+    // Deactivated documents are the only valid documents without an
+    // authentication method.
+    let method: &MethodRef = self
+      .document
+      .authentication()
+      .head()
+      .ok_or(Error::DocumentDeactivated)?;
+
+    let method: &VerificationMethod = self
+      .document
+      .resolve_ref(method)
+      .ok_or(Error::MissingAuthenticationMethod)?;
 
     // SAFETY: We don't allow invalid authentication methods.
-    unsafe { IotaVerificationMethod::new_unchecked_ref(method) }
+    Ok(unsafe { IotaVerificationMethod::new_unchecked_ref(method) })
   }
 
-  fn authentication_id(&self) -> &str {
-    // This `unwrap` is "fine" - a valid document will
-    // always have a resolvable authentication method.
-    self.document.authentication().head().unwrap().id().as_str()
+  fn authentication_id(&self) -> Result<&str> {
+    // Deactivated documents are the only valid documents without an
+    // authentication method.
+    self
+      .document
+      .authentication()
+      .head()
+      .map(|method| method.id().as_str())
+      .ok_or(Error::DocumentDeactivated)
   }
 
   /// Returns the timestamp of when the DID document was created.
@@ -300,6 +339,13 @@ impl IotaDocument {
     self.document.properties_mut().previous_message_id = value.into();
   }
 
+  /// Returns `true` if the DID document is a tombstone deactivating the DID.
+  ///
+  /// See [`IotaDocument::deactivate`].
+  pub fn is_deactivated(&self) -> bool {
+    self.document.properties().deactivated
+  }
+
   /// Returns a reference to the custom DID Document properties.
   pub fn properties(&self) -> &Object {
     &self.document.properties().properties
@@ -358,7 +404,7 @@ impl IotaDocument {
 
   /// Removes all references to the specified Verification Method.
   pub fn remove_method(&mut self, did: &IotaDID) -> Result<()> {
-    if self.authentication_id() == did.as_str() {
+    if self.authentication_id()? == did.as_str() {
       return Err(Error::CannotRemoveAuthMethod);
     }
 
@@ -421,7 +467,7 @@ impl IotaDocument {
   /// Fails if an unsupported verification method is used, document
   /// serialization fails, or the signature operation fails.
   pub fn sign(&mut self, secret: &SecretKey) -> Result<()> {
-    let key: String = self.authentication_id().to_string();
+    let key: String = self.authentication_id()?.to_string();
 
     self.document.sign_this(&key, secret).map_err(Into::into)
   }
@@ -432,6 +478,29 @@ impl IotaDocument {
     self.document.signer(secret)
   }
 
+  /// Signs the DID document with the default authentication method,
+  /// delegating the signature operation to `signer`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if an unsupported verification method is used, document
+  /// serialization fails, or the external signer fails.
+  pub async fn sign_external(&mut self, signer: &dyn ExternalSigner) -> Result<()> {
+    let key: String = self.authentication_id()?.to_string();
+
+    self.document.sign_this_external(&key, signer).await.map_err(Into::into)
+  }
+
+  /// Creates a new [`ExternalDocumentSigner`] that can be used to create digital
+  /// signatures from verification methods in this DID Document with an
+  /// [`ExternalSigner`].
+  pub fn external_signer<'base>(
+    &'base self,
+    signer: &'base dyn ExternalSigner,
+  ) -> ExternalDocumentSigner<'base, 'base, Properties, Object, Object> {
+    self.document.external_signer(signer)
+  }
+
   /// Verifies the signature of the DID document.
   ///
   /// # Errors
@@ -461,11 +530,52 @@ impl IotaDocument {
     self
       .document
       .signer(secret)
-      .method(self.authentication_id())
+      .method(self.authentication_id()?)
       .sign(data)
       .map_err(Into::into)
   }
 
+  /// Signs the deterministic CBOR encoding of the provided data with the
+  /// default authentication method (`CborEd25519Signature2021`).
+  ///
+  /// # Errors
+  ///
+  /// Fails if the default authentication method is not an
+  /// `Ed25519VerificationKey2018` method, CBOR serialization fails, or the
+  /// signature operation fails.
+  pub fn sign_data_cbor<X>(&self, data: &mut X, secret: &SecretKey) -> Result<()>
+  where
+    X: Serialize + SetSignature + TryMethod,
+  {
+    self
+      .document
+      .signer(secret)
+      .method(self.authentication_id()?)
+      .cbor(true)
+      .sign(data)
+      .map_err(Into::into)
+  }
+
+  /// Signs the provided data with the default authentication method,
+  /// delegating the signature operation to `signer`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if an unsupported verification method is used, document
+  /// serialization fails, or the external signer fails.
+  pub async fn sign_data_external<X>(&self, data: &mut X, signer: &dyn ExternalSigner) -> Result<()>
+  where
+    X: Serialize + SetSignature + TryMethod + Send,
+  {
+    self
+      .document
+      .external_signer(signer)
+      .method(self.authentication_id()?)
+      .sign(data)
+      .await
+      .map_err(Into::into)
+  }
+
   /// Verifies the signature of the provided data.
   ///
   /// Note: It is assumed that the signature was created using a verification
@@ -518,6 +628,73 @@ impl IotaDocument {
     Ok(())
   }
 
+  // ===========================================================================
+  // Deactivation
+  // ===========================================================================
+
+  /// Creates a tombstone DID document that deactivates the DID of `self`.
+  ///
+  /// The tombstone has no verification methods or services and is signed
+  /// with the default authentication method of `self` and `secret`. Once it
+  /// is published as the next integration chain message no further updates
+  /// to the DID are accepted.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `self` has not been published, is already deactivated, or the
+  /// signature operation fails.
+  pub fn deactivate(&self, secret: &SecretKey) -> Result<Self> {
+    let mut tombstone: Self = self.tombstone()?;
+
+    self.sign_data(&mut tombstone, secret)?;
+
+    Ok(tombstone)
+  }
+
+  /// Creates a tombstone DID document that deactivates the DID of `self`,
+  /// delegating the signature operation to `signer`.
+  ///
+  /// See [`IotaDocument::deactivate`].
+  ///
+  /// # Errors
+  ///
+  /// Fails if `self` has not been published, is already deactivated, or the
+  /// external signer fails.
+  pub async fn deactivate_external(&self, signer: &dyn ExternalSigner) -> Result<Self> {
+    let mut tombstone: Self = self.tombstone()?;
+
+    self.sign_data_external(&mut tombstone, signer).await?;
+
+    Ok(tombstone)
+  }
+
+  fn tombstone(&self) -> Result<Self> {
+    if self.is_deactivated() {
+      return Err(Error::DocumentDeactivated);
+    }
+
+    if self.message_id.is_null() {
+      return Err(Error::InvalidDocumentMessageId);
+    }
+
+    let properties: BaseProperties = BaseProperties {
+      created: self.created(),
+      updated: Timestamp::now_utc(),
+      previous_message_id: self.message_id,
+      deactivated: true,
+      properties: Object::new(),
+    };
+
+    let document: BaseDocument = CoreDocument::builder(Properties::new(properties))
+      .id(self.id().clone().into())
+      .build()?;
+
+    Ok(Self {
+      document,
+      message_id: MessageId::null(),
+    })
+  }
+
   // ===========================================================================
   // Publishing
   // ===========================================================================
@@ -627,8 +804,17 @@ mod tests {
   use std::str::FromStr;
 
   use identity_core::common::Value;
+  use identity_core::convert::FromCbor;
   use identity_core::convert::FromJson;
   use identity_core::convert::SerdeInto;
+  use identity_core::convert::ToCbor;
+  use identity_core::convert::ToJson;
+  use identity_core::crypto::merkle_key::MerkleKey;
+  use identity_core::crypto::merkle_key::Sha256;
+  use identity_core::crypto::merkle_tree::Forest;
+  use identity_core::crypto::merkle_tree::Hash;
+  use identity_core::crypto::Ed25519;
+  use identity_core::crypto::KeyCollection;
   use identity_core::crypto::KeyPair;
   use identity_core::crypto::KeyType;
   use identity_core::crypto::PublicKey;
@@ -644,6 +830,7 @@ mod tests {
   use crate::did::doc::IotaDocument;
   use crate::did::doc::IotaVerificationMethod;
   use crate::did::url::IotaDID;
+  use crate::did::DocumentDiff;
   use crate::tangle::MessageId;
 
   const DID_ID: &str = "did:iota:HGE4tecHWL2YiZv5qAGtH7gaeQcaz2Z1CR15GWmMjY1M";
@@ -724,27 +911,27 @@ mod tests {
 
   fn compare_document(document: &IotaDocument) {
     assert_eq!(document.id().to_string(), DID_ID);
-    assert_eq!(document.authentication_id(), DID_AUTH);
+    assert_eq!(document.authentication_id().unwrap(), DID_AUTH);
     assert_eq!(
-      document.authentication().key_type(),
+      document.authentication().unwrap().key_type(),
       MethodType::Ed25519VerificationKey2018
     );
     assert_eq!(
-      document.authentication().key_data(),
-      &MethodData::PublicKeyBase58(String::from("FJsXMk9UqpJf3ZTKnfEQAhvBrVLKMSx9ZeYwQME6c6tT"))
+      document.authentication().unwrap().key_data(),
+      &MethodData::PublicKeyMultibase(String::from("z6Mktm8ZwzPvBMo8A4J2UECF1oUBg4cAmLCWFfTsEdC7XKfq"))
     );
   }
 
   fn compare_document_testnet(document: &IotaDocument) {
     assert_eq!(document.id().to_string(), DID_TESTNET_ID);
-    assert_eq!(document.authentication_id(), DID_TESTNET_AUTH);
+    assert_eq!(document.authentication_id().unwrap(), DID_TESTNET_AUTH);
     assert_eq!(
-      document.authentication().key_type(),
+      document.authentication().unwrap().key_type(),
       MethodType::Ed25519VerificationKey2018
     );
     assert_eq!(
-      document.authentication().key_data(),
-      &MethodData::PublicKeyBase58(String::from("FJsXMk9UqpJf3ZTKnfEQAhvBrVLKMSx9ZeYwQME6c6tT"))
+      document.authentication().unwrap().key_data(),
+      &MethodData::PublicKeyMultibase(String::from("z6Mktm8ZwzPvBMo8A4J2UECF1oUBg4cAmLCWFfTsEdC7XKfq"))
     );
   }
 
@@ -906,6 +1093,47 @@ mod tests {
     assert!(doc.is_err());
   }
 
+  #[test]
+  fn test_try_from_core_deactivated() {
+    let mut properties: BTreeMap<String, Value> = valid_properties();
+    properties.insert("deactivated".to_string(), true.into());
+
+    let doc: IotaDocument = IotaDocument::try_from_core(
+      CoreDocument::builder(properties.clone())
+        .id(valid_did())
+        .build()
+        .unwrap(),
+    )
+    .unwrap();
+
+    assert!(doc.is_deactivated());
+    assert!(doc.authentication_id().is_err());
+
+    let doc = IotaDocument::try_from_core(
+      CoreDocument::builder(properties)
+        .id(valid_did())
+        // INVALID - deactivated documents have no verification methods.
+        .authentication(core_verification_method(&valid_did(), "#auth-key"))
+        .build()
+        .unwrap(),
+    );
+
+    assert!(doc.is_err());
+  }
+
+  #[test]
+  fn test_invalid_try_from_core_no_authentication() {
+    let doc = IotaDocument::try_from_core(
+      CoreDocument::builder(valid_properties())
+        .id(valid_did())
+        // INVALID - missing authentication method.
+        .build()
+        .unwrap(),
+    );
+
+    assert!(doc.is_err());
+  }
+
   #[test]
   fn test_new() {
     //from keypair
@@ -914,7 +1142,7 @@ mod tests {
     compare_document(&document);
 
     //from authentication
-    let method = document.authentication().to_owned();
+    let method = document.authentication().unwrap().to_owned();
     let document: IotaDocument = IotaDocument::from_authentication(method).unwrap();
     compare_document(&document);
 
@@ -962,8 +1190,8 @@ mod tests {
         )
         .controller(valid_did())
         .key_type(MethodType::Ed25519VerificationKey2018)
-        .key_data(MethodData::PublicKeyBase58(
-          "FJsXMk9UqpJf3ZTKnfEQAhvBrVLKMSx9ZeYwQME6c6tT".into(),
+        .key_data(MethodData::PublicKeyMultibase(
+          "z6Mktm8ZwzPvBMo8A4J2UECF1oUBg4cAmLCWFfTsEdC7XKfq".into(),
         ))
         .build()
         .unwrap(),
@@ -1011,12 +1239,56 @@ mod tests {
     assert_eq!(document, document2);
   }
 
+  #[cfg(feature = "cbor")]
+  #[test]
+  fn test_cbor() {
+    let keypair: KeyPair = generate_testkey();
+    let mut document: IotaDocument = IotaDocument::from_keypair(&keypair).unwrap();
+
+    assert!(document.sign(keypair.secret()).is_ok());
+
+    let cbor_doc: Vec<u8> = document.to_cbor().unwrap();
+    let document2: IotaDocument = IotaDocument::from_cbor(&cbor_doc).unwrap();
+    assert_eq!(document, document2);
+    assert!(document2.verify().is_ok());
+
+    // The binary encoding is more compact than the JSON encoding
+    assert!(cbor_doc.len() < document.to_json_vec().unwrap().len());
+  }
+
+  #[cfg(feature = "cbor")]
+  #[test]
+  fn test_cbor_diff() {
+    let keypair: KeyPair = generate_testkey();
+    let document: IotaDocument = IotaDocument::from_keypair(&keypair).unwrap();
+    let mut updated: IotaDocument = document.clone();
+
+    updated.properties_mut().insert("foo".into(), 123.into());
+
+    let mut diff: DocumentDiff = DocumentDiff::new(&document, &updated, MessageId::null()).unwrap();
+
+    document.sign_data_cbor(&mut diff, keypair.secret()).unwrap();
+
+    assert_eq!(diff.proof().unwrap().type_(), "CborEd25519Signature2021");
+
+    let cbor_diff: Vec<u8> = diff.to_cbor().unwrap();
+    let diff2: DocumentDiff = DocumentDiff::from_cbor(&cbor_diff).unwrap();
+
+    assert_eq!(diff, diff2);
+    assert!(document.verify_data(&diff2).is_ok());
+
+    let mut merged: IotaDocument = document.clone();
+
+    assert!(merged.merge(&diff2).is_ok());
+    assert_eq!(merged.properties().get("foo"), Some(&123.into()));
+  }
+
   #[test]
   fn test_authentication() {
     let keypair: KeyPair = generate_testkey();
     let document: IotaDocument = IotaDocument::from_keypair(&keypair).unwrap();
 
-    assert!(IotaDocument::check_authentication(document.authentication()).is_ok());
+    assert!(IotaDocument::check_authentication(document.authentication().unwrap()).is_ok());
   }
 
   #[test]
@@ -1054,6 +1326,39 @@ mod tests {
     assert_eq!(document.proof().unwrap().verification_method(), "#authentication");
   }
 
+  #[test]
+  fn test_sign_external() {
+    let keypair: KeyPair = generate_testkey();
+    let mut document: IotaDocument = IotaDocument::from_keypair(&keypair).unwrap();
+
+    futures::executor::block_on(document.sign_external(&keypair)).unwrap();
+
+    assert!(document.verify().is_ok());
+    assert_eq!(document.proof().unwrap().verification_method(), "#authentication");
+  }
+
+  #[test]
+  fn test_create_merkle_forest_key() {
+    let keys: KeyCollection = KeyCollection::new_ed25519(6).unwrap();
+    let mut forest: Forest<Sha256> = Forest::new(2).unwrap();
+
+    assert_eq!(forest.push(&keys[..4]), Some(0));
+    assert_eq!(forest.push(&keys[4..]), Some(4));
+
+    let did: IotaDID = IotaDID::new(keys[0].as_ref()).unwrap();
+    let method: IotaVerificationMethod =
+      IotaVerificationMethod::create_merkle_forest_key::<Sha256, Ed25519, _>(did.clone(), &forest, "merkle").unwrap();
+
+    assert_eq!(method.id(), &did.join("#merkle").unwrap());
+    assert_eq!(method.key_type(), MethodType::MerkleKeyCollection2021);
+
+    let data: Vec<u8> = method.key_data().try_decode().unwrap();
+    let roots: Vec<Hash<Sha256>> = MerkleKey::extract_roots(&data).unwrap();
+
+    assert!(MerkleKey::is_padded(&data).unwrap());
+    assert_eq!(roots.as_slice(), forest.roots().as_slice());
+  }
+
   #[test]
   fn test_integration_index() {
     let keypair: KeyPair = generate_testkey();
@@ -12,6 +12,9 @@ use core::ops::Deref;
 use identity_core::common::BitSet;
 use identity_core::convert::ToJson;
 use identity_core::crypto::merkle_key::MerkleDigest;
+use identity_core::crypto::merkle_key::MerkleKey;
+use identity_core::crypto::merkle_key::MerkleSignature;
+use identity_core::crypto::merkle_tree::Forest;
 use identity_core::crypto::KeyCollection;
 use identity_core::crypto::KeyPair;
 use identity_core::crypto::KeyType;
@@ -50,8 +53,30 @@ impl IotaVerificationMethod {
       .id(key.into())
       .controller(did.into())
       .key_type(MethodType::MerkleKeyCollection2021)
-      // TODO: replace publicKeyBase58 with publicKeyMultibase
-      .key_data(MethodData::new_b58(&keys.encode_merkle_key::<D>()))
+      .key_data(MethodData::new_multibase(&keys.encode_merkle_key::<D>()))
+      .build()
+      .map_err(Into::into)
+      .map(Self)
+  }
+
+  /// Creates a new Merkle Key Collection Method from the roots of the given
+  /// `forest` of `S` public keys.
+  pub fn create_merkle_forest_key<'a, D, S, F>(did: IotaDID, forest: &Forest<D>, fragment: F) -> Result<Self>
+  where
+    F: Into<Option<&'a str>>,
+    D: MerkleDigest,
+    S: MerkleSignature,
+  {
+    let tag: String = format!("#{}", fragment.into().unwrap_or(Self::DEFAULT_TAG));
+    let key: IotaDID = did.join(tag)?;
+
+    MethodBuilder::default()
+      .id(key.into())
+      .controller(did.into())
+      .key_type(MethodType::MerkleKeyCollection2021)
+      .key_data(MethodData::new_multibase(&MerkleKey::encode_forest_key::<D, S>(
+        &forest.roots(),
+      )))
       .build()
       .map_err(Into::into)
       .map(Self)
@@ -99,7 +124,38 @@ impl IotaVerificationMethod {
     match keypair.type_() {
       KeyType::Ed25519 => {
         builder = builder.key_type(MethodType::Ed25519VerificationKey2018);
-        builder = builder.key_data(MethodData::new_b58(keypair.public()));
+        builder = builder.key_data(MethodData::new_multicodec(
+          MethodType::Ed25519VerificationKey2018,
+          keypair.public(),
+        ));
+      }
+      KeyType::Secp256k1 => {
+        builder = builder.key_type(MethodType::EcdsaSecp256k1VerificationKey2019);
+        builder = builder.key_data(MethodData::new_multicodec(
+          MethodType::EcdsaSecp256k1VerificationKey2019,
+          keypair.public(),
+        ));
+      }
+      KeyType::P256 => {
+        builder = builder.key_type(MethodType::EcdsaSecp256r1VerificationKey2019);
+        builder = builder.key_data(MethodData::new_multicodec(
+          MethodType::EcdsaSecp256r1VerificationKey2019,
+          keypair.public(),
+        ));
+      }
+      KeyType::Bls12381G2 => {
+        builder = builder.key_type(MethodType::Bls12381G2Key2020);
+        builder = builder.key_data(MethodData::new_multicodec(
+          MethodType::Bls12381G2Key2020,
+          keypair.public(),
+        ));
+      }
+      KeyType::X25519 => {
+        builder = builder.key_type(MethodType::X25519KeyAgreementKey2019);
+        builder = builder.key_data(MethodData::new_multicodec(
+          MethodType::X25519KeyAgreementKey2019,
+          keypair.public(),
+        ));
       }
     }
 
@@ -18,6 +18,8 @@ pub struct Properties {
     skip_serializing_if = "MessageIdExt::is_null"
   )]
   pub(crate) previous_message_id: MessageId,
+  #[serde(default, skip_serializing_if = "core::ops::Not::not")]
+  pub(crate) deactivated: bool,
   #[serde(flatten)]
   pub(crate) properties: Object,
 }
@@ -28,6 +30,7 @@ impl Properties {
       created: Timestamp::now_utc(),
       updated: Timestamp::now_utc(),
       previous_message_id: MessageId::null(),
+      deactivated: false,
       properties: Object::new(),
     }
   }
@@ -27,6 +27,8 @@ pub enum Error {
   InvalidDocumentAuthFragment,
   #[error("Invalid Document - Authentication Type Not Supported")]
   InvalidDocumentAuthType,
+  #[error("Invalid Document - Deactivated Document Has Verification Methods")]
+  InvalidDocumentTombstone,
   #[error("Invalid Network Name: {0}")]
   InvalidNetworkName(&'static str),
   #[error("Invalid Tryte Conversion")]
@@ -47,6 +49,8 @@ pub enum Error {
   MissingMethodFragment,
   #[error("Authentication Method Not Found")]
   MissingAuthenticationMethod,
+  #[error("Document Deactivated")]
+  DocumentDeactivated,
   #[error("Cannot Remove Authentication Method")]
   CannotRemoveAuthMethod,
   #[error("Cannot Revoke Verification Method")]
@@ -11,11 +11,14 @@ use identity_did::resolution::InputMetadata;
 use identity_did::resolution::MetaDocument;
 use identity_did::resolution::ResolverMethod;
 
+use crate::chain::DocumentChain;
+use crate::chain::DocumentVersion;
+use crate::chain::VersionQuery;
 use crate::did::IotaDID;
-use crate::did::IotaDocument;
+use crate::error::Error as IotaError;
 use crate::tangle::Client;
 use crate::tangle::ClientMap;
-use crate::tangle::TangleResolve;
+use crate::tangle::TangleRead;
 
 #[async_trait(?Send)]
 impl ResolverMethod for Client {
@@ -23,21 +26,78 @@ impl ResolverMethod for Client {
     IotaDID::try_from_borrowed(did).is_ok()
   }
 
-  async fn read(&self, did: &CoreDID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
-    let document: IotaDocument = IotaDID::try_from_borrowed(did)
-      .map_err(|_| Error::MissingResolutionDID)
-      .map(|did| self.resolve(did))?
-      .await
-      .map_err(|_| Error::MissingResolutionDocument)?;
+  /// Reads the DID Document of an IOTA DID.
+  ///
+  /// The `versionId` (a Tangle message id) and `versionTime` input properties
+  /// select a previous version of the DID Document, with `versionId` taking
+  /// precedence if both are given.
+  ///
+  /// The tombstone of a deactivated DID is resolved with the `deactivated`
+  /// document metadata property set.
+  async fn read(&self, did: &CoreDID, input: InputMetadata) -> Result<Option<MetaDocument>> {
+    read_meta_document(self, did, input).await
+  }
+}
+
+// Reads the version of the DID Document selected by `input` with its metadata.
+async fn read_meta_document<R>(reader: &R, did: &CoreDID, input: InputMetadata) -> Result<Option<MetaDocument>>
+where
+  R: TangleRead,
+{
+  let did: &IotaDID = IotaDID::try_from_borrowed(did).map_err(|_| Error::MissingResolutionDID)?;
+
+  let query: Option<VersionQuery> = match (input.version_id.as_deref(), input.version_time) {
+    (Some(version_id), _) => match version_id.parse() {
+      Ok(version_id) => Some(VersionQuery::Id(version_id)),
+      Err(_) => return Err(Error::InvalidResolutionInput),
+    },
+    (None, Some(version_time)) => Some(VersionQuery::Time(version_time)),
+    (None, None) => None,
+  };
 
-    let mut meta: DocumentMetadata = DocumentMetadata::new();
-    meta.created = Some(document.created());
-    meta.updated = Some(document.updated());
+  let version: DocumentVersion = match query {
+    Some(query) => match DocumentVersion::read(reader, did, query).await {
+      Ok(Some(version)) => version,
+      Ok(None) => return Ok(None),
+      Err(error) => return read_error(error),
+    },
+    None => {
+      let chain: DocumentChain = match DocumentChain::read(reader, did).await {
+        Ok(chain) => chain,
+        Err(error) => return read_error(error),
+      };
+
+      DocumentVersion {
+        document: chain.current().clone(),
+        version_id: *chain.diff_message_id(),
+        next_version_id: None,
+        next_update: None,
+      }
+    }
+  };
+
+  let mut meta: DocumentMetadata = DocumentMetadata::new();
+  meta.created = Some(version.document.created());
+  meta.updated = Some(version.document.updated());
+  meta.version_id = Some(version.version_id.to_string());
+  meta.next_version_id = version.next_version_id.as_ref().map(ToString::to_string);
+  meta.next_update = version.next_update;
+
+  if version.document.is_deactivated() {
+    meta.deactivated = Some(true);
+  }
+
+  Ok(Some(MetaDocument::new(version.document.serde_into()?, meta)))
+}
 
-    Ok(Some(MetaDocument {
-      data: document.serde_into()?,
-      meta,
-    }))
+// A DID without a valid root document on the Tangle doesn't exist - any other
+// failure to read the document chain is reported as an error.
+fn read_error<T>(error: IotaError) -> Result<Option<T>> {
+  match error {
+    IotaError::ChainError {
+      error: "Invalid Root Document",
+    } => Ok(None),
+    error => Err(Error::FetchFailed(error.to_string())),
   }
 }
 
@@ -54,8 +114,161 @@ impl ResolverMethod for ClientMap {
     self
       .client(network)
       .await
-      .map_err(|_| Error::MissingResolutionDocument)?
+      .map_err(|error| Error::FetchFailed(error.to_string()))?
       .read(did.as_ref(), input)
       .await
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Timestamp;
+  use identity_core::crypto::KeyPair;
+  use identity_did::error::Error as DIDError;
+  use identity_did::resolution::InputMetadata;
+  use identity_did::resolution::MetaDocument;
+
+  use crate::did::DocumentDiff;
+  use crate::did::IotaDID;
+  use crate::did::IotaDocument;
+  use crate::error::Error;
+  use crate::error::Result;
+  use crate::resolver::read_meta_document;
+  use crate::tangle::MessageId;
+  use crate::tangle::MessageIndex;
+  use crate::tangle::TangleRead;
+  use crate::tangle::TangleRef;
+
+  #[derive(Debug, Default)]
+  struct MockTangle {
+    documents: Vec<IotaDocument>,
+    diffs: Vec<DocumentDiff>,
+    offline: bool,
+  }
+
+  #[async_trait::async_trait(?Send)]
+  impl TangleRead for MockTangle {
+    async fn read_integration_index(&self, _did: &IotaDID) -> Result<MessageIndex<IotaDocument>> {
+      if self.offline {
+        return Err(Error::NoClientNodesProvided);
+      }
+
+      Ok(self.documents.iter().cloned().collect())
+    }
+
+    async fn read_diff_index(&self, _document: &IotaDocument) -> Result<MessageIndex<DocumentDiff>> {
+      Ok(self.diffs.iter().cloned().collect())
+    }
+  }
+
+  fn diff(document: &IotaDocument, keypair: &KeyPair, updated: i64, previous: u8, message: u8) -> DocumentDiff {
+    let mut update: IotaDocument = document.clone();
+    update
+      .properties_mut()
+      .insert(format!("foo-{}", message), message.into());
+    update.set_updated(Timestamp::from_unix(updated));
+
+    let mut diff: DocumentDiff = document
+      .diff(&update, MessageId::new([previous; 32]), keypair.secret())
+      .unwrap();
+    diff.set_message_id(MessageId::new([message; 32]));
+    diff
+  }
+
+  fn read(tangle: &MockTangle, did: &IotaDID, input: InputMetadata) -> Option<MetaDocument> {
+    futures::executor::block_on(read_meta_document(tangle, did.as_ref(), input)).unwrap()
+  }
+
+  #[test]
+  fn test_read_document_version() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let mut document: IotaDocument = IotaDocument::from_keypair(&keypair).unwrap();
+    document.set_updated(Timestamp::from_unix(100));
+    document.sign(keypair.secret()).unwrap();
+    document.set_message_id(MessageId::new([1; 32]));
+
+    let update: DocumentDiff = diff(&document, &keypair, 200, 1, 2);
+    let merged: IotaDocument = update.merge(&document).unwrap();
+
+    // A diff that backdates the DID Document is not part of the diff chain
+    let backdated: DocumentDiff = diff(&merged, &keypair, 50, 2, 3);
+
+    let did: IotaDID = document.id().clone();
+    let tangle: MockTangle = MockTangle {
+      documents: vec![document],
+      diffs: vec![update, backdated],
+      offline: false,
+    };
+
+    let latest: MetaDocument = read(&tangle, &did, InputMetadata::default()).unwrap();
+    assert_eq!(latest.meta.version_id, Some(MessageId::new([2; 32]).to_string()));
+    assert_eq!(latest.meta.updated, Some(Timestamp::from_unix(200)));
+
+    let input: InputMetadata = InputMetadata {
+      version_time: Some(Timestamp::from_unix(150)),
+      ..InputMetadata::default()
+    };
+
+    let version: MetaDocument = read(&tangle, &did, input).unwrap();
+    assert_eq!(version.meta.version_id, Some(MessageId::new([1; 32]).to_string()));
+    assert_eq!(version.meta.next_version_id, Some(MessageId::new([2; 32]).to_string()));
+    assert_eq!(version.meta.next_update, Some(Timestamp::from_unix(200)));
+    assert!(version.data.properties().get("foo-2").is_none());
+
+    let input: InputMetadata = InputMetadata {
+      version_id: Some(MessageId::new([2; 32]).to_string()),
+      ..InputMetadata::default()
+    };
+
+    let version: MetaDocument = read(&tangle, &did, input).unwrap();
+    assert_eq!(version.meta.updated, Some(Timestamp::from_unix(200)));
+    assert_eq!(version.meta.next_version_id, None);
+    assert!(version.data.properties().get("foo-2").is_some());
+
+    let input: InputMetadata = InputMetadata {
+      version_id: Some(MessageId::new([3; 32]).to_string()),
+      ..InputMetadata::default()
+    };
+
+    assert!(read(&tangle, &did, input).is_none());
+  }
+  #[test]
+  fn test_read_errors() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let did: IotaDID = IotaDID::new(keypair.public().as_ref()).unwrap();
+    let mut tangle: MockTangle = MockTangle::default();
+
+    let try_read = |tangle: &MockTangle, input: InputMetadata| {
+      futures::executor::block_on(read_meta_document(tangle, did.as_ref(), input))
+    };
+
+    // The DID has no integration chain
+    assert!(try_read(&tangle, InputMetadata::default()).unwrap().is_none());
+
+    // The version id is not a message id
+    let input: InputMetadata = InputMetadata {
+      version_id: Some("not-a-message-id".into()),
+      ..InputMetadata::default()
+    };
+
+    assert!(matches!(
+      try_read(&tangle, input),
+      Err(DIDError::InvalidResolutionInput)
+    ));
+
+    // Failures to read the Tangle are not reported as missing DID Documents
+    tangle.offline = true;
+
+    assert!(matches!(
+      try_read(&tangle, InputMetadata::default()),
+      Err(DIDError::FetchFailed(_))
+    ));
+
+    let input: InputMetadata = InputMetadata {
+      version_time: Some(Timestamp::from_unix(100)),
+      ..InputMetadata::default()
+    };
+
+    assert!(matches!(try_read(&tangle, input), Err(DIDError::FetchFailed(_))));
+  }
+}
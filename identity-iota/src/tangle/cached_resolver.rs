@@ -0,0 +1,138 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::time::Duration;
+use identity_did::resolution::ResolutionCache;
+
+use crate::did::IotaDID;
+use crate::did::IotaDocument;
+use crate::error::Result;
+use crate::tangle::TangleResolve;
+
+/// A [`TangleResolve`] implementation that caches the DID Documents resolved
+/// by another [`TangleResolve`], avoiding refetching the integration and diff
+/// chains on every resolution.
+///
+/// Use [`CachedResolver`][identity_did::resolution::CachedResolver] to cache
+/// DID Documents resolved with a [`ResolverMethod`][identity_did::resolution::ResolverMethod].
+#[derive(Debug)]
+pub struct CachedTangleResolver<R> {
+  resolver: R,
+  cache: ResolutionCache<IotaDocument>,
+}
+
+impl<R> CachedTangleResolver<R> {
+  /// Creates a new `CachedTangleResolver` wrapping `resolver` with the default
+  /// TTL and capacity.
+  pub fn new(resolver: R) -> Self {
+    Self {
+      resolver,
+      cache: ResolutionCache::new(),
+    }
+  }
+
+  /// Creates a new `CachedTangleResolver` wrapping `resolver` with the given
+  /// `ttl` and `capacity`.
+  pub fn with_config(resolver: R, ttl: Duration, capacity: usize) -> Self {
+    Self {
+      resolver,
+      cache: ResolutionCache::with_config(ttl, capacity),
+    }
+  }
+
+  /// Returns a reference to the wrapped [`TangleResolve`] implementation.
+  pub fn resolver(&self) -> &R {
+    &self.resolver
+  }
+
+  /// Returns a reference to the DID Document cache.
+  pub fn cache(&self) -> &ResolutionCache<IotaDocument> {
+    &self.cache
+  }
+
+  /// Removes the cached DID Document of `did`, returning `true` if it was
+  /// cached.
+  pub fn invalidate(&self, did: &IotaDID) -> bool {
+    self.cache.invalidate(did.as_str())
+  }
+
+  /// Removes all cached DID Documents.
+  pub fn clear(&self) {
+    self.cache.clear();
+  }
+}
+
+impl<R> CachedTangleResolver<R>
+where
+  R: TangleResolve,
+{
+  /// Resolves the DID Document of `did` without reading from the cache.
+  ///
+  /// The resolved DID Document replaces any cached DID Document of `did`.
+  pub async fn resolve_no_cache(&self, did: &IotaDID) -> Result<IotaDocument> {
+    let document: IotaDocument = self.resolver.resolve(did).await?;
+
+    self.cache.insert(did.as_str(), document.clone());
+
+    Ok(document)
+  }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<R> TangleResolve for CachedTangleResolver<R>
+where
+  R: TangleResolve,
+{
+  async fn resolve(&self, did: &IotaDID) -> Result<IotaDocument> {
+    match self.cache.get(did.as_str()) {
+      Some(document) => Ok(document),
+      None => self.resolve_no_cache(did).await,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use core::cell::Cell;
+  use identity_core::crypto::KeyPair;
+
+  use crate::did::IotaDID;
+  use crate::did::IotaDocument;
+  use crate::error::Result;
+  use crate::tangle::CachedTangleResolver;
+  use crate::tangle::TangleResolve;
+
+  #[derive(Debug, Default)]
+  struct CountingResolver(Cell<usize>);
+
+  #[async_trait::async_trait(?Send)]
+  impl TangleResolve for CountingResolver {
+    async fn resolve(&self, _did: &IotaDID) -> Result<IotaDocument> {
+      self.0.set(self.0.get() + 1);
+
+      IotaDocument::from_keypair(&KeyPair::new_ed25519()?)
+    }
+  }
+
+  #[test]
+  fn test_cache() {
+    let resolver: CachedTangleResolver<CountingResolver> = CachedTangleResolver::new(CountingResolver::default());
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let did: IotaDID = IotaDID::new(keypair.public().as_ref()).unwrap();
+
+    let document: IotaDocument = futures::executor::block_on(resolver.resolve(&did)).unwrap();
+    let cached: IotaDocument = futures::executor::block_on(resolver.resolve(&did)).unwrap();
+
+    assert_eq!(document, cached);
+    assert_eq!(resolver.resolver().0.get(), 1);
+
+    futures::executor::block_on(resolver.resolve_no_cache(&did)).unwrap();
+    assert_eq!(resolver.resolver().0.get(), 2);
+
+    assert!(resolver.invalidate(&did));
+    assert!(!resolver.invalidate(&did));
+
+    futures::executor::block_on(resolver.resolve(&did)).unwrap();
+    assert_eq!(resolver.resolver().0.get(), 3);
+  }
+}
@@ -5,20 +5,24 @@ use futures::stream::FuturesUnordered;
 use futures::stream::TryStreamExt;
 use iota_client::Client as IotaClient;
 
+use identity_core::convert::ToCbor;
 use identity_core::convert::ToJson;
 
 use crate::chain::DocumentChain;
-use crate::chain::IntegrationChain;
-use crate::chain::{ChainHistory, DiffChain, DocumentHistory};
+use crate::chain::{ChainHistory, DocumentHistory};
+use crate::chain::{DocumentVersion, VersionQuery};
 use crate::did::DocumentDiff;
 use crate::did::IotaDID;
 use crate::did::IotaDocument;
 use crate::error::Error;
 use crate::error::Result;
 use crate::tangle::Message;
+use crate::tangle::MessageExt;
 use crate::tangle::MessageId;
+use crate::tangle::MessageIndex;
 use crate::tangle::Network;
 use crate::tangle::Receipt;
+use crate::tangle::TangleRead;
 use crate::tangle::TangleResolve;
 use crate::tangle::{ClientBuilder, TangleRef};
 
@@ -94,6 +98,22 @@ impl Client {
       .map(|message| Receipt::new(self.network.clone(), message))
   }
 
+  /// Publishes arbitrary data to the specified index on the Tangle, using the
+  /// deterministic CBOR encoding.
+  ///
+  /// Documents and diffs published as CBOR are resolved like their JSON form.
+  pub async fn publish_cbor<T: ToCbor>(&self, index: &str, data: &T) -> Result<Receipt> {
+    self
+      .client
+      .message()
+      .with_index(index)
+      .with_data(data.to_cbor()?)
+      .finish()
+      .await
+      .map_err(Into::into)
+      .map(|message| Receipt::new(self.network.clone(), message))
+  }
+
   /// Fetch the [`IotaDocument`] specified by the given [`IotaDID`].
   pub async fn read_document(&self, did: &IotaDID) -> Result<IotaDocument> {
     self.read_document_chain(did).await.and_then(DocumentChain::fold)
@@ -101,29 +121,15 @@ impl Client {
 
   /// Fetches a [`DocumentChain`] given an [`IotaDID`].
   pub async fn read_document_chain(&self, did: &IotaDID) -> Result<DocumentChain> {
-    trace!("Read Document Chain: {}", did);
-    trace!("Integration Chain Address: {}", did.tag());
-
-    // Fetch all messages for the integration chain.
-    let messages: Vec<Message> = self.read_messages(did.tag()).await?;
-    let integration_chain: IntegrationChain = IntegrationChain::try_from_messages(did, &messages)?;
-
-    // Check if there is any query given and return
-    let skip_diff: bool = did.query_pairs().any(|(key, value)| key == "diff" && value == "false");
-
-    let diff: DiffChain = if skip_diff {
-      DiffChain::new()
-    } else {
-      // Fetch all messages for the diff chain.
-      let index: String = IotaDocument::diff_index(integration_chain.current_message_id())?;
-      let messages: Vec<Message> = self.read_messages(&index).await?;
-
-      trace!("Diff Messages: {:#?}", messages);
-
-      DiffChain::try_from_messages(&integration_chain, &messages)?
-    };
+    DocumentChain::read(self, did).await
+  }
 
-    DocumentChain::new_with_diff_chain(integration_chain, diff)
+  /// Fetches the version of the [`IotaDocument`] specified by the given [`IotaDID`]
+  /// that matches `query`, rebuilt from the integration and diff chains.
+  ///
+  /// Returns `None` if no version matches `query`.
+  pub async fn read_document_version(&self, did: &IotaDID, query: VersionQuery) -> Result<Option<DocumentVersion>> {
+    DocumentVersion::read(self, did, query).await
   }
 
   /// Returns the [`MessageHistory`] of the given [`IotaDID`].
@@ -171,6 +177,36 @@ impl Client {
   }
 }
 
+#[async_trait::async_trait(?Send)]
+impl TangleRead for Client {
+  async fn read_integration_index(&self, did: &IotaDID) -> Result<MessageIndex<IotaDocument>> {
+    let messages: Vec<Message> = self.read_messages(did.tag()).await?;
+
+    debug!("[Int] Messages = {}", messages.len());
+
+    Ok(
+      messages
+        .iter()
+        .flat_map(|message| message.try_extract_document(did))
+        .collect(),
+    )
+  }
+
+  async fn read_diff_index(&self, document: &IotaDocument) -> Result<MessageIndex<DocumentDiff>> {
+    let index: String = IotaDocument::diff_index(document.message_id())?;
+    let messages: Vec<Message> = self.read_messages(&index).await?;
+
+    trace!("Diff Messages: {:#?}", messages);
+
+    Ok(
+      messages
+        .iter()
+        .flat_map(|message| message.try_extract_diff(document.id()))
+        .collect(),
+    )
+  }
+}
+
 #[async_trait::async_trait(? Send)]
 impl TangleResolve for Client {
   async fn resolve(&self, did: &IotaDID) -> Result<IotaDocument> {
@@ -1,6 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::convert::FromCbor;
 use identity_core::convert::FromJson;
 use iota_client::bee_message::payload::transaction::Essence;
 use iota_client::bee_message::payload::Payload;
@@ -17,7 +18,7 @@ use crate::tangle::TangleRef;
 // TODO: Use MessageId when it has a const ctor
 static NULL: &[u8; MESSAGE_ID_LENGTH] = &[0; MESSAGE_ID_LENGTH];
 
-fn parse_message<T: FromJson + TangleRef>(message: &Message, did: &IotaDID) -> Option<T> {
+fn parse_message<T: FromJson + FromCbor + TangleRef>(message: &Message, did: &IotaDID) -> Option<T> {
   let message_id: MessageId = message.id().0;
   let payload: Option<&Payload> = message.payload().as_ref();
   let resource: T = parse_payload(message_id, payload)?;
@@ -29,7 +30,7 @@ fn parse_message<T: FromJson + TangleRef>(message: &Message, did: &IotaDID) -> O
   Some(resource)
 }
 
-fn parse_payload<T: FromJson + TangleRef>(message_id: MessageId, payload: Option<&Payload>) -> Option<T> {
+fn parse_payload<T: FromJson + FromCbor + TangleRef>(message_id: MessageId, payload: Option<&Payload>) -> Option<T> {
   match payload {
     Some(Payload::Indexation(indexation)) => parse_data(message_id, indexation.data()),
     Some(Payload::Transaction(transaction)) => match transaction.essence() {
@@ -42,8 +43,9 @@ fn parse_payload<T: FromJson + TangleRef>(message_id: MessageId, payload: Option
   }
 }
 
-fn parse_data<T: FromJson + TangleRef>(message_id: MessageId, data: &[u8]) -> Option<T> {
-  let mut resource: T = T::from_json_slice(data).ok()?;
+fn parse_data<T: FromJson + FromCbor + TangleRef>(message_id: MessageId, data: &[u8]) -> Option<T> {
+  // Resources are published as either JSON or CBOR
+  let mut resource: T = T::from_json_slice(data).or_else(|_| T::from_cbor(data)).ok()?;
 
   resource.set_message_id(message_id);
 
@@ -9,6 +9,7 @@ pub use iota_client::bee_message::Message;
 #[doc(inline)]
 pub use iota_client::bee_message::MessageId;
 
+pub use self::cached_resolver::CachedTangleResolver;
 pub use self::client::Client;
 pub use self::client_builder::ClientBuilder;
 pub use self::client_map::ClientMap;
@@ -18,9 +19,11 @@ pub use self::message_ext::TryFromMessage;
 pub use self::message_index::MessageIndex;
 pub use self::network::{Network, NetworkName};
 pub use self::receipt::Receipt;
+pub(crate) use self::traits::TangleRead;
 pub use self::traits::TangleRef;
 pub use self::traits::TangleResolve;
 
+mod cached_resolver;
 mod client;
 mod client_builder;
 mod client_map;
@@ -1,10 +1,12 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::did::DocumentDiff;
 use crate::did::IotaDID;
 use crate::did::IotaDocument;
 use crate::error::Result;
 use crate::tangle::MessageId;
+use crate::tangle::MessageIndex;
 
 pub trait TangleRef {
   fn did(&self) -> &IotaDID;
@@ -22,3 +24,13 @@ pub trait TangleRef {
 pub trait TangleResolve {
   async fn resolve(&self, did: &IotaDID) -> Result<IotaDocument>;
 }
+
+/// Reads the integration and diff chain resources of a DID from the Tangle.
+#[async_trait::async_trait(?Send)]
+pub(crate) trait TangleRead {
+  /// Returns the integration chain documents published for the given [`IotaDID`].
+  async fn read_integration_index(&self, did: &IotaDID) -> Result<MessageIndex<IotaDocument>>;
+
+  /// Returns the diffs published on the diff chain of the given integration [`IotaDocument`].
+  async fn read_diff_index(&self, document: &IotaDocument) -> Result<MessageIndex<DocumentDiff>>;
+}
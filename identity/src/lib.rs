@@ -63,6 +63,7 @@ pub mod did {
   pub use identity_did::did::Error as DIDError;
   pub use identity_did::did::DID;
 
+  pub use identity_did::methods;
   pub use identity_did::resolution;
   pub use identity_did::verifiable;
 }